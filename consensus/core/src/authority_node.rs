@@ -179,13 +179,16 @@ where
         );
         info!("Consensus parameters: {:?}", parameters);
         info!("Consensus committee: {:?}", committee);
+        let clock = Clock::new();
+        #[cfg(msim)]
+        let clock = clock.with_authority_key(&committee.authority(own_index).authority_key);
         let context = Arc::new(Context::new(
             own_index,
             committee,
             parameters,
             protocol_config,
             initialise_metrics(registry),
-            Arc::new(Clock::new()),
+            Arc::new(clock),
         ));
         let start_time = Instant::now();
 
@@ -4,6 +4,8 @@
 
 use std::{sync::Arc, time::SystemTime};
 
+#[cfg(msim)]
+use consensus_config::AuthorityPublicKey;
 use consensus_config::{AuthorityIndex, Committee, Parameters};
 #[cfg(test)]
 use consensus_config::{NetworkKeyPair, ProtocolKeyPair};
@@ -106,6 +108,10 @@ impl Context {
 pub(crate) struct Clock {
     initial_instant: Instant,
     initial_system_time: SystemTime,
+    /// Key of the authority owning the clock, used to look up the clock
+    /// offset set for it in simulation tests.
+    #[cfg(msim)]
+    authority_key: Option<Vec<u8>>,
 }
 
 impl Clock {
@@ -113,9 +119,17 @@ impl Clock {
         Self {
             initial_instant: Instant::now(),
             initial_system_time: SystemTime::now(),
+            #[cfg(msim)]
+            authority_key: None,
         }
     }
 
+    #[cfg(msim)]
+    pub(crate) fn with_authority_key(mut self, authority_key: &AuthorityPublicKey) -> Self {
+        self.authority_key = Some(authority_key.to_bytes().to_vec());
+        self
+    }
+
     // Returns the current time expressed as UNIX timestamp in milliseconds.
     // Calculated with Tokio Instant to ensure monotonicity,
     // and to allow testing with tokio clock.
@@ -133,6 +147,8 @@ impl Clock {
                     }),
             )
             .expect("Computing system time should not overflow");
+        #[cfg(msim)]
+        let monotonic_system_time = monotonic_system_time + self.simulated_offset();
         monotonic_system_time
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap_or_else(|_| {
@@ -145,3 +161,37 @@ impl Clock {
             .as_millis() as BlockTimestampMs
     }
 }
+
+#[cfg(msim)]
+thread_local! {
+    static CLOCK_OFFSETS: std::cell::RefCell<std::collections::HashMap<Vec<u8>, std::time::Duration>> =
+        Default::default();
+}
+
+/// Shifts the clock of the authority with the given authority key ahead by
+/// `offset`, to simulate clock skew between authorities.
+///
+/// The clock must stay monotonic, so the offset of a running authority
+/// should never be lowered.
+#[cfg(msim)]
+pub fn set_clock_offset(authority_key: &[u8], offset: std::time::Duration) {
+    CLOCK_OFFSETS.with(|offsets| {
+        offsets.borrow_mut().insert(authority_key.to_vec(), offset);
+    });
+}
+
+#[cfg(msim)]
+impl Clock {
+    fn simulated_offset(&self) -> std::time::Duration {
+        let Some(authority_key) = &self.authority_key else {
+            return std::time::Duration::ZERO;
+        };
+        CLOCK_OFFSETS.with(|offsets| {
+            offsets
+                .borrow()
+                .get(authority_key)
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+}
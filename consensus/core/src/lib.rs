@@ -61,6 +61,8 @@ pub use block::{BlockAPI, BlockRef, Round};
 pub use block::{TestBlock, Transaction, VerifiedBlock};
 pub use commit::{CommitDigest, CommitIndex, CommitRef, CommittedSubDag};
 pub use commit_consumer::{CommitConsumer, CommitConsumerMonitor};
+#[cfg(msim)]
+pub use context::set_clock_offset;
 pub use network::tonic_network::to_socket_addr;
 #[cfg(msim)]
 pub use transaction::NoopTransactionVerifier;
@@ -30,10 +30,13 @@ use iota_types::{
     },
     storage::WriteStore,
 };
-use prometheus::{IntCounterVec, Registry, register_int_counter_vec_with_registry};
+use prometheus::{
+    IntCounter, IntCounterVec, Registry, register_int_counter_vec_with_registry,
+    register_int_counter_with_registry,
+};
 use rand::seq::SliceRandom;
 use tokio::sync::{Mutex, oneshot, oneshot::Sender};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::{
     CHECKPOINT_FILE_MAGIC, FileMetadata, FileType, Manifest, SUMMARY_FILE_MAGIC, read_manifest,
@@ -43,6 +46,7 @@ use crate::{
 pub struct ArchiveReaderMetrics {
     pub archive_txns_read: IntCounterVec,
     pub archive_checkpoints_read: IntCounterVec,
+    pub archive_sources_unavailable: IntCounter,
 }
 
 impl ArchiveReaderMetrics {
@@ -62,6 +66,12 @@ impl ArchiveReaderMetrics {
                 registry
             )
             .unwrap(),
+            archive_sources_unavailable: register_int_counter_with_registry!(
+                "archive_sources_unavailable",
+                "Number of configured archive sources skipped because they could not be initialized",
+                registry
+            )
+            .unwrap(),
         };
         Arc::new(this)
     }
@@ -75,11 +85,31 @@ pub struct ArchiveReaderBalancer {
 }
 
 impl ArchiveReaderBalancer {
+    /// Creates a reader for every configured archive. Archives which cannot be
+    /// initialized are logged and skipped, so that a single unavailable store
+    /// does not prevent the node from starting. Archives used for the pruning
+    /// watermark are required, since skipping them would let the pruner remove
+    /// checkpoints which haven't been archived yet.
     pub fn new(configs: Vec<ArchiveReaderConfig>, registry: &Registry) -> Result<Self> {
         let mut readers = vec![];
         let metrics = ArchiveReaderMetrics::new(registry);
         for config in configs.into_iter() {
-            readers.push(Arc::new(ArchiveReader::new(config.clone(), &metrics)?));
+            match ArchiveReader::new(config.clone(), &metrics) {
+                Ok(reader) => readers.push(Arc::new(reader)),
+                Err(err) if config.use_for_pruning_watermark => {
+                    return Err(err.context(format!(
+                        "failed to initialize archive store {:?} used for the pruning watermark",
+                        config.remote_store_config.bucket
+                    )));
+                }
+                Err(err) => {
+                    warn!(
+                        "Skipping unavailable archive store {:?}: {err:?}",
+                        config.remote_store_config.bucket
+                    );
+                    metrics.archive_sources_unavailable.inc();
+                }
+            }
         }
         Ok(ArchiveReaderBalancer { readers })
     }
@@ -689,9 +719,15 @@ impl ArchiveReader {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
-                        let new_manifest = read_manifest(remote_store.clone()).await?;
-                        let mut locked = manifest.lock().await;
-                        *locked = new_manifest;
+                        // Keep retrying, an archive that is unreachable right now may
+                        // become available later on
+                        match read_manifest(remote_store.clone()).await {
+                            Ok(new_manifest) => {
+                                let mut locked = manifest.lock().await;
+                                *locked = new_manifest;
+                            }
+                            Err(err) => warn!("Failed to sync archive manifest: {err:?}"),
+                        }
                     }
                     _ = &mut recv => break,
                 }
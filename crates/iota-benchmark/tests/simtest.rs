@@ -686,6 +686,7 @@ mod test {
                     SupportedProtocolVersions::new_for_testing(starting_version, max_ver),
                 )
                 .with_objects(init_framework.into_iter().map(|p| p.genesis_object()))
+                .with_system_package_override()
                 .build()
                 .await,
         );
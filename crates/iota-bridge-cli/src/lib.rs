@@ -7,8 +7,9 @@ use std::{path::PathBuf, str::FromStr, sync::Arc};
 use anyhow::anyhow;
 use clap::*;
 use ethers::{
-    providers::Middleware,
-    types::{Address as EthAddress, U256},
+    contract::ContractCall,
+    providers::{Http, Middleware, Provider},
+    types::{Address as EthAddress, U256, transaction::eip2718::TypedTransaction},
 };
 use fastcrypto::{
     encoding::{Encoding, Hex},
@@ -16,27 +17,29 @@ use fastcrypto::{
 };
 use iota_bridge::{
     abi::{EthBridgeCommittee, EthIotaBridge, eth_iota_bridge},
-    crypto::BridgeAuthorityPublicKeyBytes,
-    error::BridgeResult,
+    crypto::{BridgeAuthorityPublicKeyBytes, BridgeAuthoritySignInfo},
+    error::{BridgeError, BridgeResult},
     iota_client::IotaBridgeClient,
     types::{
         AddTokensOnEvmAction, AddTokensOnIotaAction, AssetPriceUpdateAction,
-        BlocklistCommitteeAction, BlocklistType, BridgeAction, EmergencyAction,
-        EmergencyActionType, EvmContractUpgradeAction, LimitUpdateAction,
+        BlocklistCommitteeAction, BlocklistType, BridgeAction, BridgeCommittee,
+        CertifiedBridgeAction, EmergencyAction, EmergencyActionType, EvmContractUpgradeAction,
+        LimitUpdateAction, VerifiedCertifiedBridgeAction,
     },
     utils::{EthSigner, get_eth_signer_client},
 };
 use iota_config::Config;
-use iota_json_rpc_types::IotaObjectDataOptions;
+use iota_json_rpc_types::{IotaData, IotaObjectDataOptions};
 use iota_keys::keypair_file::read_key;
 use iota_sdk::IotaClientBuilder;
 use iota_types::{
     BRIDGE_PACKAGE_ID, TypeTag,
     base_types::{IotaAddress, ObjectID, ObjectRef},
     bridge::{BRIDGE_MODULE_NAME, BridgeChainId},
+    coin::Coin,
     crypto::{IotaKeyPair, Signature},
     programmable_transaction_builder::ProgrammableTransactionBuilder,
-    transaction::{ObjectArg, Transaction, TransactionData},
+    transaction::{Argument, Command, ObjectArg, Transaction, TransactionData},
 };
 use move_core_types::ident_str;
 use serde::{Deserialize, Serialize};
@@ -52,11 +55,72 @@ pub struct Args {
     pub command: BridgeCommand,
 }
 
+/// EIP-1559 fees of Eth transactions, estimated by the signer if not set.
+#[derive(clap::Args, Clone, Copy, Debug, Default)]
+pub struct EthFeeArgs {
+    /// Maximum total fee per gas in wei
+    #[arg(long)]
+    pub max_fee_per_gas: Option<u64>,
+    /// Maximum priority fee per gas in wei
+    #[arg(long)]
+    pub max_priority_fee_per_gas: Option<u64>,
+}
+
+impl EthFeeArgs {
+    /// Sets the configured fees on the transaction of `call`.
+    pub fn apply<M: Middleware, D>(&self, call: &mut ContractCall<M, D>) -> anyhow::Result<()> {
+        if self.max_fee_per_gas.is_none() && self.max_priority_fee_per_gas.is_none() {
+            return Ok(());
+        }
+        let TypedTransaction::Eip1559(tx) = &mut call.tx else {
+            return Err(anyhow!(
+                "EIP-1559 fees can't be set on a non EIP-1559 transaction"
+            ));
+        };
+        if let Some(max_fee_per_gas) = self.max_fee_per_gas {
+            tx.max_fee_per_gas = Some(max_fee_per_gas.into());
+        }
+        if let Some(max_priority_fee_per_gas) = self.max_priority_fee_per_gas {
+            tx.max_priority_fee_per_gas = Some(max_priority_fee_per_gas.into());
+        }
+        Ok(())
+    }
+}
+
 #[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
 pub enum Network {
     Testnet,
 }
 
+impl Network {
+    /// Address of the IotaBridge proxy contract deployed on the Eth chain
+    /// paired with this network.
+    pub fn eth_bridge_proxy_address(&self) -> EthAddress {
+        match self {
+            Network::Testnet => EthAddress::from_str(SEPOLIA_BRIDGE_PROXY_ADDR).unwrap(),
+        }
+    }
+}
+
+/// Returns the address of the IotaBridge proxy contract of the given network,
+/// or the explicitly given address if no network is given.
+pub fn resolve_eth_bridge_proxy_address(
+    network: Option<Network>,
+    bridge_proxy: Option<EthAddress>,
+) -> anyhow::Result<EthAddress> {
+    match network {
+        Some(network) => Ok(network.eth_bridge_proxy_address()),
+        None => bridge_proxy.ok_or(anyhow!("Network or bridge proxy address must be provided")),
+    }
+}
+
+/// Creates a provider for the Eth chain behind the given RPC url.
+pub fn eth_provider(eth_rpc_url: &str) -> anyhow::Result<Arc<Provider<Http>>> {
+    Ok(Arc::new(
+        Provider::<Http>::try_from(eth_rpc_url)?.interval(std::time::Duration::from_millis(2000)),
+    ))
+}
+
 #[derive(Parser)]
 pub enum BridgeCommand {
     CreateBridgeValidatorKey {
@@ -74,10 +138,18 @@ pub enum BridgeCommand {
         #[arg(long)]
         is_validator_key: bool,
     },
+    /// Generate a Bridge Node config template.
+    /// If `iota-rpc-url` and `bridge-authority-key-path` are provided, the
+    /// chain ids and the server port are pre-filled from the on chain bridge
+    /// committee registration of the key.
     CreateBridgeNodeConfigTemplate {
         path: PathBuf,
         #[arg(long)]
         run_client: bool,
+        #[arg(long, requires = "bridge_authority_key_path")]
+        iota_rpc_url: Option<String>,
+        #[arg(long, requires = "iota_rpc_url")]
+        bridge_authority_key_path: Option<PathBuf>,
     },
     /// Governance client to facilitate and execute Bridge governance actions
     Governance {
@@ -91,6 +163,47 @@ pub enum BridgeCommand {
         /// If true, only collect signatures but not execute on chain
         #[arg(long)]
         dry_run: bool,
+        /// If true, print the estimated gas cost of executing the action on
+        /// the target chain, without collecting signatures or executing it.
+        /// The estimate doesn't include the verification of the committee
+        /// signatures
+        #[arg(long, conflicts_with_all = ["dry_run", "output_path"])]
+        estimate_gas: bool,
+        /// If set, collect signatures and write the certified action to this
+        /// file instead of executing it, see `execute-certified`
+        #[arg(long)]
+        output_path: Option<PathBuf>,
+        #[command(flatten)]
+        eth_fees: EthFeeArgs,
+    },
+    /// Execute a certified action previously written by `governance
+    /// --output-path`
+    ExecuteCertified {
+        /// Path of BridgeCliConfig
+        #[arg(long)]
+        config_path: PathBuf,
+        /// Path of the certified action
+        path: PathBuf,
+        /// If true, print the estimated gas cost of executing the action on
+        /// the target chain, without executing it
+        #[arg(long)]
+        estimate_gas: bool,
+        #[command(flatten)]
+        eth_fees: EthFeeArgs,
+    },
+    /// Encode the call data of an `upgrade-evm-contract` action and decode it
+    /// back, to independently confirm its payload before signing
+    VerifyUpgradeCallData {
+        /// Function selector with params types, e.g. `foo(uint256,bool,string)`
+        #[arg(long)]
+        function_selector: String,
+        /// Params to be passed to the function, e.g. `420,false,hello`
+        #[arg(long, use_value_delimiter = true)]
+        params: Vec<String>,
+        /// Hex encoded call data of the action to check against the encoded
+        /// params. If set, this call data is decoded instead.
+        #[arg(long)]
+        call_data: Option<String>,
     },
     /// View current status of Eth bridge
     ViewEthBridge {
@@ -101,6 +214,30 @@ pub enum BridgeCommand {
         #[arg(long)]
         eth_rpc_url: String,
     },
+    /// Check that the bridge committee on IOTA matches the committee of the
+    /// Eth `BridgeCommittee` contract
+    CheckCommitteeSync {
+        #[arg(long)]
+        iota_rpc_url: String,
+        #[arg(long)]
+        network: Option<Network>,
+        #[arg(long)]
+        bridge_proxy: Option<EthAddress>,
+        #[arg(long)]
+        eth_rpc_url: String,
+    },
+    /// View the mapping between the bridge token ids, their IOTA coin types
+    /// and their Eth token addresses
+    ViewTokenMapping {
+        #[arg(long)]
+        iota_rpc_url: String,
+        #[arg(long)]
+        network: Option<Network>,
+        #[arg(long)]
+        bridge_proxy: Option<EthAddress>,
+        #[arg(long)]
+        eth_rpc_url: String,
+    },
     /// View current list of registered validators
     ViewBridgeRegistration {
         #[arg(long)]
@@ -120,6 +257,10 @@ pub enum BridgeCommand {
         /// Path of BridgeCliConfig
         #[arg(long)]
         config_path: PathBuf,
+        /// Network preset used to fill in the proxy addresses that are not
+        /// set in the config
+        #[arg(long)]
+        network: Option<Network>,
         #[command(subcommand)]
         cmd: BridgeClientCommands,
     },
@@ -299,21 +440,36 @@ pub fn make_action(chain_id: BridgeChainId, cmd: &GovernanceClientCommands) -> B
     }
 }
 
-fn encode_call_data(function_selector: &str, params: &[String]) -> Vec<u8> {
+/// The parameter types of a function selector like
+/// `foo(uint256,bool,string)`.
+fn param_types(function_selector: &str) -> Vec<&str> {
     let left = function_selector
         .find('(')
         .expect("Invalid function selector, no left parentheses");
     let right = function_selector
         .find(')')
         .expect("Invalid function selector, no right parentheses");
-    let param_types = function_selector[left + 1..right]
+    function_selector[left + 1..right]
         .split(',')
         .map(|x| x.trim())
-        .collect::<Vec<&str>>();
+        .filter(|x| !x.is_empty())
+        .collect()
+}
+
+/// The first 4 bytes of the Keccak256 hash of the function selector, which
+/// call data starts with.
+pub fn function_selector_hash(function_selector: &str) -> [u8; 4] {
+    Keccak256::digest(function_selector).digest[0..4]
+        .try_into()
+        .unwrap()
+}
+
+pub fn encode_call_data(function_selector: &str, params: &[String]) -> Vec<u8> {
+    let param_types = param_types(function_selector);
 
     assert_eq!(param_types.len(), params.len(), "Invalid number of params");
 
-    let mut call_data = Keccak256::digest(function_selector).digest[0..4].to_vec();
+    let mut call_data = function_selector_hash(function_selector).to_vec();
     let mut tokens = vec![];
     for (param, param_type) in params.iter().zip(param_types.iter()) {
         match param_type.to_lowercase().as_str() {
@@ -342,23 +498,84 @@ fn encode_call_data(function_selector: &str, params: &[String]) -> Vec<u8> {
     call_data
 }
 
+/// Decodes call data encoded by [`encode_call_data`] for the same function
+/// selector.
+pub fn decode_call_data(
+    function_selector: &str,
+    call_data: &[u8],
+) -> anyhow::Result<Vec<ethers::abi::Token>> {
+    let selector_hash = function_selector_hash(function_selector);
+    let encoded_params = call_data.strip_prefix(&selector_hash).ok_or_else(|| {
+        anyhow!(
+            "call data does not start with 0x{} of `{function_selector}`",
+            Hex::encode(selector_hash)
+        )
+    })?;
+    let param_types = param_types(function_selector)
+        .into_iter()
+        .map(|param_type| match param_type.to_lowercase().as_str() {
+            "uint256" => Ok(ethers::abi::ParamType::Uint(256)),
+            "bool" => Ok(ethers::abi::ParamType::Bool),
+            "string" => Ok(ethers::abi::ParamType::String),
+            _ => Err(anyhow!("unsupported param type {param_type}")),
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(ethers::abi::decode(&param_types, encoded_params)?)
+}
+
 pub fn select_contract_address(
     config: &LoadedBridgeCliConfig,
-    cmd: &GovernanceClientCommands,
+    action: &BridgeAction,
 ) -> EthAddress {
-    match cmd {
-        GovernanceClientCommands::EmergencyButton { .. } => config.eth_bridge_proxy_address,
-        GovernanceClientCommands::UpdateCommitteeBlocklist { .. } => {
-            config.eth_bridge_committee_proxy_address
+    match action {
+        BridgeAction::EmergencyAction(_) | BridgeAction::IotaToEthBridgeAction(_) => {
+            config.eth_bridge_proxy_address
+        }
+        BridgeAction::BlocklistCommitteeAction(_) => config.eth_bridge_committee_proxy_address,
+        BridgeAction::LimitUpdateAction(_) => config.eth_bridge_limiter_proxy_address,
+        BridgeAction::AssetPriceUpdateAction(_) | BridgeAction::AddTokensOnEvmAction(_) => {
+            config.eth_bridge_config_proxy_address
+        }
+        BridgeAction::EvmContractUpgradeAction(action) => action.proxy_address,
+        BridgeAction::EthToIotaBridgeAction(_) | BridgeAction::AddTokensOnIotaAction(_) => {
+            unreachable!()
         }
-        GovernanceClientCommands::UpdateLimit { .. } => config.eth_bridge_limiter_proxy_address,
-        GovernanceClientCommands::UpdateAssetPrice { .. } => config.eth_bridge_config_proxy_address,
-        GovernanceClientCommands::UpgradeEVMContract { proxy_address, .. } => *proxy_address,
-        GovernanceClientCommands::AddTokensOnIota { .. } => unreachable!(),
-        GovernanceClientCommands::AddTokensOnEvm { .. } => config.eth_bridge_config_proxy_address,
     }
 }
 
+/// Verifies the signatures of a certified action, e.g. one loaded from a file,
+/// against the given committee. Fails if any signature is invalid or not from
+/// an active committee member, or if the signers don't reach the approval
+/// threshold of the action.
+pub fn verify_certified_action(
+    certified_action: CertifiedBridgeAction,
+    committee: &BridgeCommittee,
+) -> anyhow::Result<VerifiedCertifiedBridgeAction> {
+    let action = certified_action.data();
+    let mut stake = 0;
+    for (signer, signature) in &certified_action.auth_sig().signatures {
+        let member = committee
+            .member(signer)
+            .ok_or_else(|| anyhow!("signer {signer:?} is not a bridge committee member"))?;
+        BridgeAuthoritySignInfo {
+            authority_pub_key: member.pubkey.clone(),
+            signature: signature.clone(),
+        }
+        .verify(action, committee)
+        .map_err(|e| anyhow!("invalid signature of {signer:?}: {e:?}"))?;
+        stake += member.voting_power;
+    }
+    let threshold = action.approval_threshold();
+    if stake < threshold {
+        anyhow::bail!(
+            "certified action is signed by {stake} voting power, below the approval threshold of {threshold}"
+        );
+    }
+    Ok(VerifiedCertifiedBridgeAction::new_from_verified(
+        certified_action,
+    ))
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -367,8 +584,10 @@ pub struct BridgeCliConfig {
     pub iota_rpc_url: String,
     /// Rpc url for Eth fullnode, used for query stuff.
     pub eth_rpc_url: String,
-    /// Proxy address for IotaBridge deployed on Eth
-    pub eth_bridge_proxy_address: EthAddress,
+    /// Proxy address for IotaBridge deployed on Eth. Can be omitted if it is
+    /// provided by a network preset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eth_bridge_proxy_address: Option<EthAddress>,
     /// Path of the file where private key is stored. The content could be any
     /// of the following:
     /// - Base64 encoded `flag || privkey` for ECDSA key
@@ -379,10 +598,29 @@ pub struct BridgeCliConfig {
     pub iota_key_path: Option<PathBuf>,
     /// See `iota_key_path`. Must be Secp256k1 key.
     pub eth_key_path: Option<PathBuf>,
+    /// Minimum balance in NANOS of the coin used to pay for IOTA
+    /// transactions. Defaults to 5 IOTA.
+    #[serde(default = "default_min_gas_balance")]
+    pub min_gas_balance: u64,
+}
+
+fn default_min_gas_balance() -> u64 {
+    5_000_000_000
 }
 
 impl Config for BridgeCliConfig {}
 
+impl BridgeCliConfig {
+    /// Fills in the proxy addresses of `network` that are not explicitly set
+    /// in the config.
+    pub fn with_network_defaults(mut self, network: &Network) -> Self {
+        if self.eth_bridge_proxy_address.is_none() {
+            self.eth_bridge_proxy_address = Some(network.eth_bridge_proxy_address());
+        }
+        self
+    }
+}
+
 pub struct LoadedBridgeCliConfig {
     /// Rpc url for IOTA fullnode, used for query stuff and submit transactions.
     pub iota_rpc_url: String,
@@ -396,6 +634,8 @@ pub struct LoadedBridgeCliConfig {
     pub eth_bridge_config_proxy_address: EthAddress,
     /// Proxy address for BridgeLimiter deployed on Eth
     pub eth_bridge_limiter_proxy_address: EthAddress,
+    /// Minimum balance in NANOS of the coin used to pay for IOTA transactions
+    pub min_gas_balance: u64,
     /// Key pair for IOTA operations
     iota_key: IotaKeyPair,
     /// Key pair for Eth operations, must be Secp256k1 key
@@ -409,6 +649,9 @@ impl LoadedBridgeCliConfig {
                 "At least one of `iota_key_path` or `eth_key_path` must be provided"
             ));
         }
+        let eth_bridge_proxy_address = cli_config.eth_bridge_proxy_address.ok_or(anyhow!(
+            "`eth_bridge_proxy_address` must be provided, either in the config or via a network preset"
+        ))?;
         let iota_key = if let Some(iota_key_path) = &cli_config.iota_key_path {
             Some(read_key(iota_key_path, false)?)
         } else {
@@ -435,14 +678,10 @@ impl LoadedBridgeCliConfig {
             }
         };
 
-        let provider = Arc::new(
-            ethers::prelude::Provider::<ethers::providers::Http>::try_from(&cli_config.eth_rpc_url)
-                .unwrap()
-                .interval(std::time::Duration::from_millis(2000)),
-        );
+        let provider = eth_provider(&cli_config.eth_rpc_url)?;
         let private_key = Hex::encode(eth_key.to_bytes_no_flag());
         let eth_signer = get_eth_signer_client(&cli_config.eth_rpc_url, &private_key).await?;
-        let iota_bridge = EthIotaBridge::new(cli_config.eth_bridge_proxy_address, provider.clone());
+        let iota_bridge = EthIotaBridge::new(eth_bridge_proxy_address, provider.clone());
         let eth_bridge_committee_proxy_address: EthAddress = iota_bridge.committee().call().await?;
         let eth_bridge_limiter_proxy_address: EthAddress = iota_bridge.limiter().call().await?;
         let eth_committee =
@@ -460,10 +699,11 @@ impl LoadedBridgeCliConfig {
         Ok(Self {
             iota_rpc_url: cli_config.iota_rpc_url,
             eth_rpc_url: cli_config.eth_rpc_url,
-            eth_bridge_proxy_address: cli_config.eth_bridge_proxy_address,
+            eth_bridge_proxy_address,
             eth_bridge_committee_proxy_address,
             eth_bridge_limiter_proxy_address,
             eth_bridge_config_proxy_address,
+            min_gas_balance: cli_config.min_gas_balance,
             iota_key,
             eth_signer,
         })
@@ -488,13 +728,15 @@ impl LoadedBridgeCliConfig {
             .get_coins(iota_client_address, None, None, None)
             .await?
             .data;
-        // TODO: is 5 IOTA a good number?
+        let largest_balance = gases.iter().map(|coin| coin.balance).max();
         let gas = gases
             .into_iter()
-            .find(|coin| coin.balance >= 5_000_000_000)
+            .find(|coin| coin.balance >= self.min_gas_balance)
             .ok_or(anyhow!(
-                "Did not find gas object with enough balance for {}",
-                iota_client_address
+                "Did not find gas object with enough balance for {}: required {} NANOS, largest coin: {}",
+                iota_client_address,
+                self.min_gas_balance,
+                largest_balance.map_or("none".to_string(), |b| format!("{b} NANOS"))
             ))?;
         println!("Using Gas object: {}", gas.coin_object_id);
         Ok((self.iota_key.copy(), iota_client_address, gas.object_ref()))
@@ -509,6 +751,12 @@ pub enum BridgeClientCommands {
         target_chain: u8,
         #[arg(long)]
         iota_recipient_address: IotaAddress,
+        /// Number of Eth block confirmations to wait for before the deposit
+        /// is considered final.
+        #[arg(long, default_value_t = 1)]
+        min_confirmations: usize,
+        #[command(flatten)]
+        eth_fees: EthFeeArgs,
     },
     DepositOnIota {
         #[arg(long)]
@@ -519,10 +767,24 @@ pub enum BridgeClientCommands {
         target_chain: u8,
         #[arg(long)]
         recipient_address: EthAddress,
+        /// Amount to bridge, split off the coin if smaller than its balance.
+        /// The whole coin is bridged if not set
+        #[arg(long)]
+        amount: Option<u64>,
+        /// If true, only dry run the deposit transaction and print its
+        /// effects, without executing it
+        #[arg(long)]
+        dry_run: bool,
     },
     ClaimOnEth {
         #[arg(long)]
         seq_num: u64,
+        /// Number of Eth block confirmations to wait for before the claim is
+        /// considered final.
+        #[arg(long, default_value_t = 1)]
+        min_confirmations: usize,
+        #[command(flatten)]
+        eth_fees: EthFeeArgs,
     },
 }
 
@@ -537,6 +799,8 @@ impl BridgeClientCommands {
                 ether_amount,
                 target_chain,
                 iota_recipient_address,
+                min_confirmations,
+                eth_fees,
             } => {
                 let eth_iota_bridge = EthIotaBridge::new(
                     config.eth_bridge_proxy_address,
@@ -549,27 +813,42 @@ impl BridgeClientCommands {
                 let int_wei = U256::from(int_part) * U256::exp10(18);
                 let frac_wei = U256::from((frac_part * 1_000_000_000_000_000_000f64) as u64);
                 let amount = int_wei + frac_wei;
-                let eth_tx = eth_iota_bridge
+                let mut eth_tx = eth_iota_bridge
                     .bridge_eth(iota_recipient_address.to_vec().into(), target_chain)
                     .value(amount);
+                eth_fees.apply(&mut eth_tx)?;
                 let pending_tx = eth_tx.send().await.unwrap();
-                let tx_receipt = pending_tx.await.unwrap().unwrap();
+                let tx_receipt = pending_tx
+                    .confirmations(min_confirmations)
+                    .await
+                    .unwrap()
+                    .unwrap();
                 info!(
                     "Deposited {ether_amount} Ethers to {:?} (target chain {target_chain}). Receipt: {:?}",
                     iota_recipient_address, tx_receipt,
                 );
                 Ok(())
             }
-            BridgeClientCommands::ClaimOnEth { seq_num } => {
-                claim_on_eth(seq_num, config, iota_bridge_client)
-                    .await
-                    .map_err(|e| anyhow!("{:?}", e))
-            }
+            BridgeClientCommands::ClaimOnEth {
+                seq_num,
+                min_confirmations,
+                eth_fees,
+            } => claim_on_eth(
+                seq_num,
+                min_confirmations,
+                eth_fees,
+                config,
+                iota_bridge_client,
+            )
+            .await
+            .map_err(|e| anyhow!("{:?}", e)),
             BridgeClientCommands::DepositOnIota {
                 coin_object_id,
                 coin_type,
                 target_chain,
                 recipient_address,
+                amount,
+                dry_run,
             } => {
                 let target_chain = BridgeChainId::try_from(target_chain).expect("Invalid chain id");
                 let coin_type = TypeTag::from_str(&coin_type).expect("Invalid coin type");
@@ -578,6 +857,8 @@ impl BridgeClientCommands {
                     coin_type,
                     target_chain,
                     recipient_address,
+                    amount,
+                    dry_run,
                     config,
                     iota_bridge_client,
                 )
@@ -592,6 +873,8 @@ async fn deposit_on_iota(
     coin_type: TypeTag,
     target_chain: BridgeChainId,
     recipient_address: EthAddress,
+    amount: Option<u64>,
+    dry_run: bool,
     config: &LoadedBridgeCliConfig,
     iota_bridge_client: IotaBridgeClient,
 ) -> anyhow::Result<()> {
@@ -613,13 +896,13 @@ async fn deposit_on_iota(
         .first()
         .ok_or(anyhow!("No coin found for address {}", sender))?
         .object_ref();
-    let coin_obj_ref = iota_client
+    let coin_data = iota_client
         .read_api()
-        .get_object_with_options(coin_object_id, IotaObjectDataOptions::default())
+        .get_object_with_options(coin_object_id, IotaObjectDataOptions::new().with_bcs())
         .await?
         .data
-        .unwrap()
-        .object_ref();
+        .unwrap();
+    let coin_obj_ref = coin_data.object_ref();
 
     let mut builder = ProgrammableTransactionBuilder::new();
     let arg_target_chain = builder.pure(target_chain).unwrap();
@@ -627,6 +910,35 @@ async fn deposit_on_iota(
     let arg_token = builder
         .obj(ObjectArg::ImmOrOwnedObject(coin_obj_ref))
         .unwrap();
+    // Split off the requested amount, the remainder stays in the sender's coin
+    let arg_token = match amount {
+        Some(amount) => {
+            let balance = coin_data
+                .bcs
+                .as_ref()
+                .and_then(|bcs| bcs.try_as_move())
+                .ok_or(anyhow!("Object {coin_object_id} is not a coin"))?
+                .deserialize::<Coin>()?
+                .value();
+            if amount > balance {
+                return Err(anyhow!(
+                    "Amount {amount} exceeds the balance {balance} of coin {coin_object_id}"
+                ));
+            }
+            if amount < balance {
+                let arg_amount = builder.pure(amount).unwrap();
+                let Argument::Result(split) =
+                    builder.command(Command::SplitCoins(arg_token, vec![arg_amount]))
+                else {
+                    return Err(anyhow!("splitting coin {coin_object_id} gave no result"));
+                };
+                Argument::NestedResult(split, 0)
+            } else {
+                arg_token
+            }
+        }
+        None => arg_token,
+    };
     let arg_bridge = builder.obj(bridge_object_arg).unwrap();
 
     builder.programmable_move_call(
@@ -639,6 +951,15 @@ async fn deposit_on_iota(
     let pt = builder.finish();
     let tx_data =
         TransactionData::new_programmable(sender, vec![gas_obj_ref], pt, 500_000_000, rgp);
+    if dry_run {
+        let dry_run_resp = iota_client
+            .read_api()
+            .dry_run_transaction_block(tx_data)
+            .await?;
+        println!("{}", dry_run_resp.effects);
+        println!("Events: {:?}", dry_run_resp.events);
+        return Ok(());
+    }
     let sig = Signature::new_secure(
         &IntentMessage::new(Intent::iota_transaction(), tx_data.clone()),
         &config.iota_key,
@@ -663,6 +984,8 @@ async fn deposit_on_iota(
 
 async fn claim_on_eth(
     seq_num: u64,
+    min_confirmations: usize,
+    eth_fees: EthFeeArgs,
     config: &LoadedBridgeCliConfig,
     iota_bridge_client: IotaBridgeClient,
 ) -> BridgeResult<()> {
@@ -693,8 +1016,18 @@ async fn claim_on_eth(
         Arc::new(config.eth_signer().clone()),
     );
     let message = eth_iota_bridge::Message::from(parsed_message);
-    let tx = eth_iota_bridge.transfer_bridged_tokens_with_signatures(signatures, message);
-    let _eth_claim_tx_receipt = tx.send().await.unwrap().await.unwrap().unwrap();
+    let mut tx = eth_iota_bridge.transfer_bridged_tokens_with_signatures(signatures, message);
+    eth_fees
+        .apply(&mut tx)
+        .map_err(|e| BridgeError::Generic(e.to_string()))?;
+    let _eth_claim_tx_receipt = tx
+        .send()
+        .await
+        .unwrap()
+        .confirmations(min_confirmations)
+        .await
+        .unwrap()
+        .unwrap();
     info!("IOTA to Eth bridge transfer claimed");
     Ok(())
 }
@@ -702,6 +1035,13 @@ async fn claim_on_eth(
 #[cfg(test)]
 mod tests {
     use ethers::abi::FunctionExt;
+    use iota_bridge::{
+        test_utils::{
+            get_certified_action_with_validator_secrets, get_test_authority_and_key,
+            get_test_iota_to_eth_bridge_action,
+        },
+        types::BridgeCommitteeValiditySignInfo,
+    };
 
     use super::*;
 
@@ -735,4 +1075,83 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_decode_call_data() {
+        let function_selector = "initializeV2Params(uint256,bool,string)";
+        let params = vec!["420".to_string(), "false".to_string(), "hello".to_string()];
+        let call_data = encode_call_data(function_selector, &params);
+
+        assert_eq!(
+            decode_call_data(function_selector, &call_data).unwrap(),
+            vec![
+                ethers::abi::Token::Uint(ethers::types::U256::from_dec_str("420").unwrap()),
+                ethers::abi::Token::Bool(false),
+                ethers::abi::Token::String("hello".to_string())
+            ]
+        );
+        assert!(decode_call_data("initializeV2Params(uint256,bool)", &call_data).is_err());
+
+        let call_data = encode_call_data("initializeV2()", &[]);
+        assert_eq!(call_data.len(), 4);
+        assert!(
+            decode_call_data("initializeV2()", &call_data)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_estimate_gas_conflicts_with_dry_run() {
+        let governance = |flags: &[&str]| {
+            let mut args = vec![
+                "iota-bridge-cli",
+                "governance",
+                "--config-path",
+                "config.yaml",
+                "--chain-id",
+                "0",
+            ];
+            args.extend_from_slice(flags);
+            args.extend(["emergency-button", "--nonce", "0", "--action-type", "pause"]);
+            Args::try_parse_from(args)
+        };
+
+        assert!(governance(&["--estimate-gas"]).is_ok());
+        assert!(governance(&["--dry-run"]).is_ok());
+        assert!(governance(&["--output-path", "action.bcs"]).is_ok());
+        assert!(governance(&["--estimate-gas", "--dry-run"]).is_err());
+        assert!(governance(&["--estimate-gas", "--output-path", "action.bcs"]).is_err());
+    }
+
+    #[test]
+    fn test_verify_certified_action() {
+        let (authorities, secrets): (Vec<_>, Vec<_>) = (0..4)
+            .map(|i| {
+                let (authority, _, secret) = get_test_authority_and_key(2500, 9000 + i);
+                (authority, secret)
+            })
+            .unzip();
+        let committee = BridgeCommittee::new(authorities).unwrap();
+        let action = get_test_iota_to_eth_bridge_action(None, None, None, None, None, None, None);
+        let certify = |secrets: &Vec<_>| {
+            get_certified_action_with_validator_secrets(action.clone(), secrets).into_inner()
+        };
+
+        verify_certified_action(certify(&secrets[..2].to_vec()), &committee).unwrap();
+
+        let err = verify_certified_action(certify(&secrets[..1].to_vec()), &committee).unwrap_err();
+        assert!(err.to_string().contains("approval threshold"));
+
+        // Signatures of another action must not be accepted.
+        let other_action =
+            get_test_iota_to_eth_bridge_action(None, None, Some(1), None, None, None, None);
+        let signatures = certify(&secrets[..2].to_vec()).into_sig().signatures;
+        let forged = CertifiedBridgeAction::new_from_data_and_sig(
+            other_action,
+            BridgeCommitteeValiditySignInfo { signatures },
+        );
+        let err = verify_certified_action(forged, &committee).unwrap_err();
+        assert!(err.to_string().contains("invalid signature"));
+    }
 }
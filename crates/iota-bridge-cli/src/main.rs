@@ -3,8 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    collections::HashMap,
-    str::{FromStr, from_utf8},
+    collections::{BTreeMap, HashMap},
+    str::from_utf8,
     sync::Arc,
     time::Duration,
 };
@@ -18,18 +18,24 @@ use iota_bridge::{
     eth_transaction_builder::build_eth_transaction,
     iota_client::IotaClient,
     iota_transaction_builder::build_iota_transaction,
-    types::BridgeActionType,
+    types::{
+        BridgeActionType, BridgeCommitteeValiditySignInfo, CertifiedBridgeAction,
+        VerifiedCertifiedBridgeAction,
+    },
     utils::{
         EthBridgeContracts, examine_key, generate_bridge_authority_key_and_write_to_file,
         generate_bridge_client_key_and_write_to_file,
-        generate_bridge_node_config_and_write_to_file, get_eth_contracts,
+        generate_bridge_node_config_and_write_to_file,
+        generate_bridge_node_config_from_chain_and_write_to_file, get_eth_contracts,
     },
 };
 use iota_bridge_cli::{
-    Args, BridgeCliConfig, BridgeCommand, LoadedBridgeCliConfig, Network,
-    SEPOLIA_BRIDGE_PROXY_ADDR, make_action, select_contract_address,
+    Args, BridgeCliConfig, BridgeCommand, EthFeeArgs, LoadedBridgeCliConfig, decode_call_data,
+    encode_call_data, eth_provider, function_selector_hash, make_action,
+    resolve_eth_bridge_proxy_address, select_contract_address, verify_certified_action,
 };
 use iota_config::Config;
+use iota_json_rpc_types::IotaTransactionBlockEffectsAPI;
 use iota_sdk::{IotaClient as IotaSdkClient, IotaClientBuilder};
 use iota_types::{
     base_types::IotaAddress,
@@ -63,8 +69,25 @@ async fn main() -> anyhow::Result<()> {
         } => {
             examine_key(&path, is_validator_key)?;
         }
-        BridgeCommand::CreateBridgeNodeConfigTemplate { path, run_client } => {
-            generate_bridge_node_config_and_write_to_file(&path, run_client)?;
+        BridgeCommand::CreateBridgeNodeConfigTemplate {
+            path,
+            run_client,
+            iota_rpc_url,
+            bridge_authority_key_path,
+        } => {
+            if let (Some(iota_rpc_url), Some(bridge_authority_key_path)) =
+                (iota_rpc_url, bridge_authority_key_path)
+            {
+                generate_bridge_node_config_from_chain_and_write_to_file(
+                    &path,
+                    run_client,
+                    iota_rpc_url,
+                    bridge_authority_key_path,
+                )
+                .await?;
+            } else {
+                generate_bridge_node_config_and_write_to_file(&path, run_client)?;
+            }
             println!(
                 "Bridge node config template generated at {}",
                 path.display()
@@ -76,6 +99,9 @@ async fn main() -> anyhow::Result<()> {
             chain_id,
             cmd,
             dry_run,
+            estimate_gas,
+            output_path,
+            eth_fees,
         } => {
             let chain_id = BridgeChainId::try_from(chain_id).expect("Invalid chain id");
             println!("Chain ID: {:?}", chain_id);
@@ -83,10 +109,6 @@ async fn main() -> anyhow::Result<()> {
             let config = LoadedBridgeCliConfig::load(config).await?;
             let iota_bridge_client = IotaClient::<IotaSdkClient>::new(&config.iota_rpc_url).await?;
 
-            let (iota_key, iota_address, gas_object_ref) = config
-                .get_iota_account_info()
-                .await
-                .expect("Failed to get iota account info");
             let bridge_summary = iota_bridge_client
                 .get_bridge_summary()
                 .await
@@ -99,117 +121,135 @@ async fn main() -> anyhow::Result<()> {
             );
             let agg = BridgeAuthorityAggregator::new(bridge_committee);
 
-            // Handle IOTA Side
-            if chain_id.is_iota_chain() {
+            // Create BridgeAction
+            let action = if chain_id.is_iota_chain() {
                 let iota_chain_id = BridgeChainId::try_from(bridge_summary.chain_id).unwrap();
                 assert_eq!(
                     iota_chain_id, chain_id,
                     "Chain ID mismatch, expected: {:?}, got from url: {:?}",
                     chain_id, iota_chain_id
                 );
-                // Create BridgeAction
                 let iota_action = make_action(iota_chain_id, &cmd);
                 println!("Action to execute on IOTA: {:?}", iota_action);
-                let certified_action = agg
-                    .request_committee_signatures(iota_action)
-                    .await
-                    .expect("Failed to request committee signatures");
-                if dry_run {
-                    println!("Dryrun succeeded.");
-                    return Ok(());
-                }
-                let bridge_arg = iota_bridge_client
-                    .get_mutable_bridge_object_arg_must_succeed()
-                    .await;
-                let rgp = iota_bridge_client
-                    .get_reference_gas_price_until_success()
-                    .await;
-                let id_token_map = iota_bridge_client.get_token_id_map().await.unwrap();
-                let tx = build_iota_transaction(
-                    iota_address,
-                    &gas_object_ref,
-                    certified_action,
-                    bridge_arg,
-                    &id_token_map,
-                    rgp,
-                )
-                .expect("Failed to build iota transaction");
-                let iota_sig = Signature::new_secure(
-                    &IntentMessage::new(Intent::iota_transaction(), tx.clone()),
-                    &iota_key,
+                iota_action
+            } else {
+                // TODO assert chain id returned from rpc matches chain_id
+                let eth_action = make_action(chain_id, &cmd);
+                println!("Action to execute on Eth: {:?}", eth_action);
+                eth_action
+            };
+            if estimate_gas {
+                // Estimate before asking the committee to sign, so the action carries no
+                // signatures yet.
+                let unsigned_action = VerifiedCertifiedBridgeAction::new_from_verified(
+                    CertifiedBridgeAction::new_from_data_and_sig(
+                        action,
+                        BridgeCommitteeValiditySignInfo {
+                            signatures: BTreeMap::new(),
+                        },
+                    ),
                 );
-                let tx = Transaction::from_data(tx, vec![iota_sig]);
-                let resp = iota_bridge_client
-                    .execute_transaction_block_with_effects(tx)
-                    .await
-                    .expect("Failed to execute transaction block with effects");
-                if resp.status_ok().unwrap() {
-                    println!("IOTA Transaction succeeded: {:?}", resp.digest);
-                } else {
-                    println!(
-                        "IOTA Transaction failed: {:?}. Effects: {:?}",
-                        resp.digest, resp.effects
-                    );
-                }
-                return Ok(());
+                return execute_certified_action(
+                    &config,
+                    &iota_bridge_client,
+                    unsigned_action,
+                    true,
+                    eth_fees,
+                )
+                .await;
             }
-
-            // Handle eth side
-            // TODO assert chain id returned from rpc matches chain_id
-            let eth_signer_client = config.eth_signer();
-            // Create BridgeAction
-            let eth_action = make_action(chain_id, &cmd);
-            println!("Action to execute on Eth: {:?}", eth_action);
-            // Create Eth Signer Client
             // TODO if a validator is blocklisted on eth, ignore their signatures?
             let certified_action = agg
-                .request_committee_signatures(eth_action)
+                .request_committee_signatures(action)
                 .await
                 .expect("Failed to request committee signatures");
             if dry_run {
                 println!("Dryrun succeeded.");
                 return Ok(());
             }
-            let contract_address = select_contract_address(&config, &cmd);
-            let tx = build_eth_transaction(
-                contract_address,
-                eth_signer_client.clone(),
+            if let Some(output_path) = output_path {
+                std::fs::write(&output_path, bcs::to_bytes(certified_action.inner())?)?;
+                println!("Certified action written to {}", output_path.display());
+                return Ok(());
+            }
+            return execute_certified_action(
+                &config,
+                &iota_bridge_client,
                 certified_action,
+                false,
+                eth_fees,
             )
-            .await
-            .expect("Failed to build eth transaction");
-            println!("sending Eth tx: {:?}", tx);
-            match tx.send().await {
-                Ok(tx_hash) => {
-                    println!("Transaction sent with hash: {:?}", tx_hash);
-                }
-                Err(err) => {
-                    let revert = err.as_revert();
-                    println!("Transaction reverted: {:?}", revert);
-                }
-            };
+            .await;
+        }
 
-            return Ok(());
+        BridgeCommand::ExecuteCertified {
+            config_path,
+            path,
+            estimate_gas,
+            eth_fees,
+        } => {
+            let config = BridgeCliConfig::load(config_path).expect("Couldn't load BridgeCliConfig");
+            let config = LoadedBridgeCliConfig::load(config).await?;
+            let iota_bridge_client = IotaClient::<IotaSdkClient>::new(&config.iota_rpc_url).await?;
+
+            let certified_action: CertifiedBridgeAction = bcs::from_bytes(&std::fs::read(&path)?)?;
+            println!("Action to execute: {:?}", certified_action.data());
+            let bridge_committee = iota_bridge_client
+                .get_bridge_committee()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to get bridge committee: {:?}", e))?;
+            let certified_action = verify_certified_action(certified_action, &bridge_committee)?;
+            return execute_certified_action(
+                &config,
+                &iota_bridge_client,
+                certified_action,
+                estimate_gas,
+                eth_fees,
+            )
+            .await;
         }
 
+        BridgeCommand::VerifyUpgradeCallData {
+            function_selector,
+            params,
+            call_data,
+        } => {
+            let encoded_call_data = encode_call_data(&function_selector, &params);
+            println!("Function selector: {function_selector}");
+            println!(
+                "Selector hash: 0x{}",
+                Hex::encode(function_selector_hash(&function_selector))
+            );
+            println!("Encoded call data: 0x{}", Hex::encode(&encoded_call_data));
+
+            let call_data = match call_data {
+                Some(call_data) => {
+                    let call_data = Hex::decode(call_data.trim_start_matches("0x"))
+                        .map_err(|e| anyhow::anyhow!("invalid hex call data: {e}"))?;
+                    if call_data != encoded_call_data {
+                        println!("Given call data does NOT match the encoded call data");
+                    } else {
+                        println!("Given call data matches the encoded call data");
+                    }
+                    call_data
+                }
+                None => encoded_call_data,
+            };
+            println!("Decoded params:");
+            for (i, token) in decode_call_data(&function_selector, &call_data)?
+                .into_iter()
+                .enumerate()
+            {
+                println!("  {i}: {token:?}");
+            }
+        }
         BridgeCommand::ViewEthBridge {
             network,
             bridge_proxy,
             eth_rpc_url,
         } => {
-            let bridge_proxy = match network {
-                Some(Network::Testnet) => {
-                    Ok(EthAddress::from_str(SEPOLIA_BRIDGE_PROXY_ADDR).unwrap())
-                }
-                None => bridge_proxy.ok_or(anyhow::anyhow!(
-                    "Network or bridge proxy address must be provided"
-                )),
-            }?;
-            let provider = Arc::new(
-                ethers::prelude::Provider::<ethers::providers::Http>::try_from(eth_rpc_url)
-                    .unwrap()
-                    .interval(std::time::Duration::from_millis(2000)),
-            );
+            let bridge_proxy = resolve_eth_bridge_proxy_address(network, bridge_proxy)?;
+            let provider = eth_provider(&eth_rpc_url)?;
             let chain_id = provider.get_chainid().await?;
             let EthBridgeContracts {
                 bridge,
@@ -273,6 +313,86 @@ async fn main() -> anyhow::Result<()> {
             return Ok(());
         }
 
+        BridgeCommand::CheckCommitteeSync {
+            iota_rpc_url,
+            network,
+            bridge_proxy,
+            eth_rpc_url,
+        } => {
+            let bridge_proxy = resolve_eth_bridge_proxy_address(network, bridge_proxy)?;
+            let provider = eth_provider(&eth_rpc_url)?;
+            let EthBridgeContracts { committee, .. } =
+                get_eth_contracts(bridge_proxy, &provider).await?;
+            let iota_bridge_client = IotaClient::<IotaSdkClient>::new(&iota_rpc_url).await?;
+            let iota_committee = iota_bridge_client
+                .get_bridge_committee()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to get bridge committee: {:?}", e))?;
+
+            // The Eth committee can't be enumerated, so every member of the IOTA
+            // committee is looked up by its Eth address instead
+            let mut output = OutputCommitteeSync {
+                in_sync: true,
+                out_of_sync_members: vec![],
+            };
+            for (pubkey, member) in iota_committee.members() {
+                let eth_address = pubkey.to_eth_address();
+                let eth_stake: u16 = committee.committee_stake(eth_address).call().await?;
+                let eth_blocklisted: bool = committee.blocklist(eth_address).call().await?;
+                if eth_stake as u64 != member.voting_power
+                    || eth_blocklisted != member.is_blocklisted
+                {
+                    output.in_sync = false;
+                    output.out_of_sync_members.push(OutputCommitteeMemberDiff {
+                        pubkey: Hex::encode(pubkey.as_bytes()),
+                        eth_address,
+                        iota_stake: member.voting_power,
+                        eth_stake,
+                        iota_blocklisted: member.is_blocklisted,
+                        eth_blocklisted,
+                    });
+                }
+            }
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+            return Ok(());
+        }
+
+        BridgeCommand::ViewTokenMapping {
+            iota_rpc_url,
+            network,
+            bridge_proxy,
+            eth_rpc_url,
+        } => {
+            let bridge_proxy = resolve_eth_bridge_proxy_address(network, bridge_proxy)?;
+            let provider = eth_provider(&eth_rpc_url)?;
+            let EthBridgeContracts { config, .. } =
+                get_eth_contracts(bridge_proxy, &provider).await?;
+            let iota_bridge_client = IotaClient::<IotaSdkClient>::new(&iota_rpc_url).await?;
+            let token_id_map = iota_bridge_client
+                .get_token_id_map()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to get token id map: {:?}", e))?;
+
+            let mut output_wrapper = Output::<Vec<OutputToken>>::default();
+            let mut token_ids = token_id_map.keys().copied().collect::<Vec<_>>();
+            token_ids.sort();
+            for token_id in token_ids {
+                let eth_address = if config.is_token_supported(token_id).call().await? {
+                    Some(config.token_address_of(token_id).call().await?)
+                } else {
+                    output_wrapper.add_error(format!("Token {token_id} is not supported on Eth"));
+                    None
+                };
+                output_wrapper.inner.push(OutputToken {
+                    token_id,
+                    iota_type: token_id_map[&token_id].to_canonical_string(true),
+                    eth_address,
+                });
+            }
+            println!("{}", serde_json::to_string_pretty(&output_wrapper).unwrap());
+            return Ok(());
+        }
+
         BridgeCommand::ViewBridgeRegistration { iota_rpc_url } => {
             let iota_bridge_client = IotaClient::<IotaSdkClient>::new(&iota_rpc_url).await?;
             let bridge_summary = iota_bridge_client
@@ -506,8 +626,16 @@ async fn main() -> anyhow::Result<()> {
             output_wrapper.inner = output;
             println!("{}", serde_json::to_string_pretty(&output_wrapper).unwrap());
         }
-        BridgeCommand::Client { config_path, cmd } => {
-            let config = BridgeCliConfig::load(config_path).expect("Couldn't load BridgeCliConfig");
+        BridgeCommand::Client {
+            config_path,
+            network,
+            cmd,
+        } => {
+            let mut config =
+                BridgeCliConfig::load(config_path).expect("Couldn't load BridgeCliConfig");
+            if let Some(network) = &network {
+                config = config.with_network_defaults(network);
+            }
             let config = LoadedBridgeCliConfig::load(config).await?;
             let iota_bridge_client = IotaClient::<IotaSdkClient>::new(&config.iota_rpc_url).await?;
             cmd.handle(&config, iota_bridge_client).await?;
@@ -518,6 +646,108 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Executes `certified_action` on the chain it targets.
+async fn execute_certified_action(
+    config: &LoadedBridgeCliConfig,
+    iota_bridge_client: &IotaClient<IotaSdkClient>,
+    certified_action: VerifiedCertifiedBridgeAction,
+    estimate_gas: bool,
+    eth_fees: EthFeeArgs,
+) -> anyhow::Result<()> {
+    // Handle IOTA Side
+    if certified_action.data().chain_id().is_iota_chain() {
+        let (iota_key, iota_address, gas_object_ref) = config
+            .get_iota_account_info()
+            .await
+            .expect("Failed to get iota account info");
+        let bridge_arg = iota_bridge_client
+            .get_mutable_bridge_object_arg_must_succeed()
+            .await;
+        let rgp = iota_bridge_client
+            .get_reference_gas_price_until_success()
+            .await;
+        let id_token_map = iota_bridge_client.get_token_id_map().await.unwrap();
+        let tx = build_iota_transaction(
+            iota_address,
+            &gas_object_ref,
+            certified_action,
+            bridge_arg,
+            &id_token_map,
+            rgp,
+        )
+        .expect("Failed to build iota transaction");
+        if estimate_gas {
+            let dry_run_resp = iota_bridge_client
+                .iota_client()
+                .read_api()
+                .dry_run_transaction_block(tx)
+                .await?;
+            let gas_summary = dry_run_resp.effects.gas_cost_summary();
+            println!(
+                "Estimated IOTA gas cost: {} NANOS (reference gas price: {rgp}). Summary: {:?}. Dry run status: {:?}",
+                gas_summary.net_gas_usage(),
+                gas_summary,
+                dry_run_resp.effects.status()
+            );
+            return Ok(());
+        }
+        let iota_sig = Signature::new_secure(
+            &IntentMessage::new(Intent::iota_transaction(), tx.clone()),
+            &iota_key,
+        );
+        let tx = Transaction::from_data(tx, vec![iota_sig]);
+        let resp = iota_bridge_client
+            .execute_transaction_block_with_effects(tx)
+            .await
+            .expect("Failed to execute transaction block with effects");
+        if resp.status_ok().unwrap() {
+            println!("IOTA Transaction succeeded: {:?}", resp.digest);
+        } else {
+            println!(
+                "IOTA Transaction failed: {:?}. Effects: {:?}",
+                resp.digest, resp.effects
+            );
+        }
+        return Ok(());
+    }
+
+    // Handle eth side
+    let eth_signer_client = config.eth_signer();
+    let contract_address = select_contract_address(config, certified_action.data());
+    let mut tx = build_eth_transaction(
+        contract_address,
+        eth_signer_client.clone(),
+        certified_action,
+    )
+    .await
+    .expect("Failed to build eth transaction");
+    eth_fees.apply(&mut tx)?;
+    if estimate_gas {
+        println!(
+            "Eth call data: 0x{}",
+            Hex::encode(tx.calldata().unwrap_or_default())
+        );
+        let gas_units = tx.estimate_gas().await?;
+        let gas_price = eth_signer_client.get_gas_price().await?;
+        println!(
+            "Estimated Eth gas: {gas_units} units at gas price {gas_price} wei, total {} wei",
+            gas_units * gas_price
+        );
+        return Ok(());
+    }
+    println!("sending Eth tx: {:?}", tx);
+    match tx.send().await {
+        Ok(tx_hash) => {
+            println!("Transaction sent with hash: {:?}", tx_hash);
+        }
+        Err(err) => {
+            let revert = err.as_revert();
+            println!("Transaction reverted: {:?}", revert);
+        }
+    };
+    Ok(())
+}
+
 #[derive(serde::Serialize, Default)]
 struct OutputEthBridge {
     chain_id: u64,
@@ -582,6 +812,32 @@ struct OutputMember {
     status: Option<String>,
 }
 
+#[derive(serde::Serialize)]
+struct OutputCommitteeSync {
+    in_sync: bool,
+    out_of_sync_members: Vec<OutputCommitteeMemberDiff>,
+}
+
+/// A member whose stake or blocklist status differs between the chains. A
+/// member unknown to the Eth committee has an Eth stake of zero.
+#[derive(serde::Serialize)]
+struct OutputCommitteeMemberDiff {
+    pubkey: String,
+    eth_address: EthAddress,
+    iota_stake: u64,
+    eth_stake: u16,
+    iota_blocklisted: bool,
+    eth_blocklisted: bool,
+}
+
+#[derive(serde::Serialize)]
+struct OutputToken {
+    token_id: u8,
+    iota_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eth_address: Option<EthAddress>,
+}
+
 #[derive(serde::Serialize, Default)]
 struct OutputIotaBridgeRegistration {
     total_registered_stake: f32,
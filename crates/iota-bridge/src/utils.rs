@@ -40,6 +40,7 @@ use crate::{
     abi::{EthBridgeCommittee, EthBridgeConfig, EthBridgeLimiter, EthBridgeVault, EthIotaBridge},
     config::{BridgeNodeConfig, EthConfig, IotaConfig},
     crypto::{BridgeAuthorityKeyPair, BridgeAuthorityPublicKeyBytes},
+    iota_client::IotaClient,
     server::APPLICATION_JSON,
     types::{AddTokensOnIotaAction, BridgeAction},
 };
@@ -150,7 +151,19 @@ pub async fn get_eth_contracts<P: ethers::providers::JsonRpcClient + 'static>(
 /// Read bridge key from a file and print the corresponding information.
 /// If `is_validator_key` is true, the key must be a Secp256k1 key.
 pub fn examine_key(path: &PathBuf, is_validator_key: bool) -> Result<(), anyhow::Error> {
-    let key = read_key(path, is_validator_key)?;
+    let key = read_key(path, false)?;
+    match (&key, is_validator_key) {
+        (IotaKeyPair::Secp256k1(_), false) => println!(
+            "Warning: Secp256k1 key examined as client key, use `--is-validator-key` if it is meant to be a bridge validator key"
+        ),
+        (IotaKeyPair::Secp256k1(_), true) | (_, false) => {}
+        (_, true) => {
+            return Err(anyhow!(
+                "Bridge validator key must be a Secp256k1 key, got {} key",
+                key.public().scheme()
+            ));
+        }
+    }
     let iota_address = IotaAddress::from(&key.public());
     let pubkey = match key {
         IotaKeyPair::Secp256k1(kp) => {
@@ -178,6 +191,74 @@ pub fn generate_bridge_node_config_and_write_to_file(
     path: &PathBuf,
     run_client: bool,
 ) -> Result<(), anyhow::Error> {
+    bridge_node_config_template(run_client).save(path)
+}
+
+/// Generate a Bridge Node config like
+/// [`generate_bridge_node_config_and_write_to_file`], but pre-fill the IOTA rpc
+/// url, the bridge chain ids and the server port from the on chain bridge state
+/// and the committee registration of the given bridge authority key.
+pub async fn generate_bridge_node_config_from_chain_and_write_to_file(
+    path: &PathBuf,
+    run_client: bool,
+    iota_rpc_url: String,
+    bridge_authority_key_path: PathBuf,
+) -> Result<(), anyhow::Error> {
+    let IotaKeyPair::Secp256k1(key) = read_key(&bridge_authority_key_path, true)? else {
+        return Err(anyhow!("Bridge authority key must be a Secp256k1 key"));
+    };
+    let pubkey_bytes = BridgeAuthorityPublicKeyBytes::from(&key.public);
+    let iota_client = IotaClient::<iota_sdk::IotaClient>::new(&iota_rpc_url).await?;
+    let bridge_summary = iota_client
+        .get_bridge_summary()
+        .await
+        .map_err(|e| anyhow!("Failed to get bridge summary: {:?}", e))?;
+
+    // The key is either part of the committee already or only registered
+    let registered_url = bridge_summary
+        .committee
+        .members
+        .iter()
+        .map(|(_, member)| (&member.bridge_pubkey_bytes, &member.http_rest_url))
+        .chain(
+            bridge_summary
+                .committee
+                .member_registration
+                .iter()
+                .map(|(_, registration)| {
+                    (
+                        &registration.bridge_pubkey_bytes,
+                        &registration.http_rest_url,
+                    )
+                }),
+        )
+        .find(|(pubkey, _)| pubkey.as_slice() == pubkey_bytes.as_bytes())
+        .map(|(_, url)| String::from_utf8(url.clone()))
+        .transpose()?
+        .ok_or(anyhow!(
+            "Bridge authority key is not registered in the bridge committee"
+        ))?;
+    let server_listen_port = url::Url::parse(&registered_url)?
+        .port_or_known_default()
+        .ok_or(anyhow!("No port in registered url {registered_url}"))?;
+
+    let iota_chain_id = BridgeChainId::try_from(bridge_summary.chain_id)?;
+    let eth_chain_id = match iota_chain_id {
+        BridgeChainId::IotaMainnet => BridgeChainId::EthMainnet,
+        BridgeChainId::IotaTestnet => BridgeChainId::EthSepolia,
+        _ => BridgeChainId::EthCustom,
+    };
+
+    let mut config = bridge_node_config_template(run_client);
+    config.server_listen_port = server_listen_port;
+    config.bridge_authority_key_path = bridge_authority_key_path;
+    config.iota.iota_rpc_url = iota_rpc_url;
+    config.iota.iota_bridge_chain_id = iota_chain_id as u8;
+    config.eth.eth_bridge_chain_id = eth_chain_id as u8;
+    config.save(path)
+}
+
+fn bridge_node_config_template(run_client: bool) -> BridgeNodeConfig {
     let mut config = BridgeNodeConfig {
         server_listen_port: 9191,
         metrics_port: 9184,
@@ -204,7 +285,7 @@ pub fn generate_bridge_node_config_and_write_to_file(
         config.iota.bridge_client_key_path = Some(PathBuf::from("/path/to/your/bridge_client_key"));
         config.db_path = Some(PathBuf::from("/path/to/your/client_db"));
     }
-    config.save(path)
+    config
 }
 
 pub async fn get_eth_signer_client(url: &str, private_key_hex: &str) -> anyhow::Result<EthSigner> {
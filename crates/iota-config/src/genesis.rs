@@ -27,6 +27,7 @@ use iota_types::{
     iota_system_state::{
         IotaSystemState, IotaSystemStateTrait, IotaSystemStateWrapper, IotaValidatorGenesis,
         get_iota_system_state, get_iota_system_state_wrapper,
+        iota_system_state_summary::IotaSystemStateSummary,
     },
     messages_checkpoint::{
         CertifiedCheckpointSummary, CheckpointContents, CheckpointSummary, VerifiedCheckpoint,
@@ -170,6 +171,15 @@ impl Genesis {
         get_iota_system_state(&self.objects()).expect("IOTA System State object must always exist")
     }
 
+    /// Returns the total IOTA supply recorded in the genesis system state.
+    pub fn iota_total_supply(&self) -> Result<u64> {
+        match self.iota_system_object().into_iota_system_state_summary() {
+            IotaSystemStateSummary::V1(summary) => Ok(summary.iota_total_supply),
+            IotaSystemStateSummary::V2(summary) => Ok(summary.iota_total_supply),
+            _ => anyhow::bail!("unsupported IOTA system state summary version"),
+        }
+    }
+
     pub fn clock(&self) -> Clock {
         let clock = self
             .objects()
@@ -379,9 +389,40 @@ pub struct GenesisCeremonyParameters {
     #[serde(default = "GenesisCeremonyParameters::default_allow_insertion_of_extra_objects")]
     pub allow_insertion_of_extra_objects: bool,
 
+    /// Allows extra objects to replace the bytes of system packages. Only
+    /// meant for tests which mock system packages.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_system_package_override: bool,
+
     /// The duration of an epoch, in milliseconds.
     #[serde(default = "GenesisCeremonyParameters::default_epoch_duration_ms")]
     pub epoch_duration_ms: u64,
+
+    /// Overrides the maximum amount of transactions per checkpoint used to
+    /// split the migration objects into migration transactions. If not set,
+    /// the protocol config value is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_migration_transactions_per_checkpoint: Option<u64>,
+
+    /// Pins the exact number of migration transactions the migration objects
+    /// are split into, instead of deriving it from the amount of objects. It
+    /// must not exceed the amount of migration transactions that fit in the
+    /// genesis checkpoint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migration_transaction_count: Option<u64>,
+
+    /// Skip objects whose ID was already loaded from a previous migration
+    /// source, instead of failing.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_duplicate_migration_objects: bool,
+
+    /// Maximum number of objects the migration sources may contain in total.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_migration_objects: Option<usize>,
+
+    /// Maximum serialized size of the migration sources in total, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_migration_bytes: Option<u64>,
 }
 
 impl GenesisCeremonyParameters {
@@ -390,7 +431,13 @@ impl GenesisCeremonyParameters {
             chain_start_timestamp_ms: Self::default_timestamp_ms(),
             protocol_version: ProtocolVersion::MAX,
             allow_insertion_of_extra_objects: true,
+            allow_system_package_override: false,
             epoch_duration_ms: Self::default_epoch_duration_ms(),
+            max_migration_transactions_per_checkpoint: None,
+            migration_transaction_count: None,
+            allow_duplicate_migration_objects: false,
+            max_migration_objects: None,
+            max_migration_bytes: None,
         }
     }
 
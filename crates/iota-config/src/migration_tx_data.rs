@@ -162,8 +162,33 @@ impl MigrationTxData {
     /// Validates the total supply of the migration data adding up the amount of
     /// gas coins found in migrated objects.
     pub fn validate_total_supply(&self, expected_total_supply: u64) -> anyhow::Result<()> {
-        let total_supply: u64 = self
-            .get_objects()
+        let total_supply = self.total_supply();
+        anyhow::ensure!(
+            total_supply == expected_total_supply,
+            "the migration data total supply of {total_supply} does not match the expected total supply of {expected_total_supply}"
+        );
+        Ok(())
+    }
+
+    /// Validates the migration data against a `Genesis` before it gets
+    /// executed. On top of [`Self::validate_from_genesis`], the supply of the
+    /// migrated objects must be covered by the total supply of the genesis
+    /// system state. The exact pre-minted supply is not part of the `Genesis`,
+    /// so this is the closest equivalent of [`Self::validate_total_supply`].
+    pub fn validate_for_execution(&self, genesis: &Genesis) -> anyhow::Result<()> {
+        self.validate_from_genesis(genesis)?;
+        let total_supply = self.total_supply();
+        let genesis_total_supply = genesis.iota_total_supply()?;
+        anyhow::ensure!(
+            total_supply <= genesis_total_supply,
+            "the migration data total supply of {total_supply} exceeds the genesis total supply of {genesis_total_supply}"
+        );
+        Ok(())
+    }
+
+    /// Adds up the amount of gas coins found in migrated objects.
+    fn total_supply(&self) -> u64 {
+        self.get_objects()
             .map(|object| match &object.data {
                 Data::Move(_) => GasCoin::try_from(&object)
                     .map(|gas| gas.value())
@@ -181,13 +206,7 @@ impl MigrationTxData {
                     .unwrap_or(0),
                 Data::Package(_) => 0,
             })
-            .sum();
-
-        anyhow::ensure!(
-            total_supply == expected_total_supply,
-            "the migration data total supply of {total_supply} does not match the expected total supply of {expected_total_supply}"
-        );
-        Ok(())
+            .sum()
     }
 
     /// Loads a `MigrationTxData` in memory from a file found in `path`.
@@ -208,6 +227,14 @@ impl MigrationTxData {
         })
     }
 
+    /// Loads a `MigrationTxData` from a file found in `path` and validates it
+    /// with [`Self::validate_for_execution`].
+    pub fn load_and_validate<P: AsRef<Path>>(path: P, genesis: &Genesis) -> Result<Self> {
+        let migration_tx_data = Self::load(path)?;
+        migration_tx_data.validate_for_execution(genesis)?;
+        Ok(migration_tx_data)
+    }
+
     /// Saves a `MigrationTxData` from memory into a file in `path`.
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), anyhow::Error> {
         let path = path.as_ref();
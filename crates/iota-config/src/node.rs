@@ -79,6 +79,12 @@ pub struct NodeConfig {
     #[serde(default)]
     pub enable_rest_api: bool,
 
+    /// Skip building the index backing the REST API. Endpoints which need the
+    /// index, e.g. listing the objects owned by an address, respond with
+    /// `501 Not Implemented`, while all other endpoints keep working.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disable_rest_index: bool,
+
     /// The address for Prometheus metrics.
     #[serde(default = "default_metrics_address")]
     pub metrics_address: SocketAddr,
@@ -93,6 +99,23 @@ pub struct NodeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consensus_config: Option<ConsensusConfig>,
 
+    /// If set, a validator does not resubmit the transactions it had pending
+    /// for consensus when it is started. Only meant for bringing up a
+    /// validator whose pending submission queue is suspect.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_submit_recovered_on_startup: bool,
+
+    /// If set, a full node only syncs checkpoints and uploads them to the
+    /// state archive. Checkpoints are not executed and neither the json-rpc
+    /// nor the rest server is started. Such a node is run by an
+    /// `iota_node::ArchivalNode` instead of an `IotaNode`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archival_only: bool,
+
+    /// If set, a full node does not serve the governance read json-rpc API.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disable_governance_read_api: bool,
+
     /// Flag to enable index processing for a full node.
     ///
     /// If set to true, node creates `IndexStore` for transaction
@@ -111,6 +134,17 @@ pub struct NodeConfig {
     /// - 'both' for both a websocket and http based service (deprecated)
     pub jsonrpc_server_type: Option<ServerType>,
 
+    /// Maximum size in bytes of a single JSON-RPC response. Responses
+    /// exceeding it are replaced by an error. Defaults to 2 GiB.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jsonrpc_max_response_body_size: Option<u32>,
+
+    /// Maximum number of JSON-RPC requests served concurrently. An open
+    /// websocket connection counts as a single request for as long as it is
+    /// open. Unlimited by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jsonrpc_max_concurrent_requests: Option<usize>,
+
     /// Flag to enable gRPC load shedding to manage and
     /// mitigate overload conditions by shedding excess
     /// load with `LoadShedLayer` middleware.
@@ -120,6 +154,18 @@ pub struct NodeConfig {
     #[serde(default = "default_concurrency_limit")]
     pub grpc_concurrency_limit: Option<usize>,
 
+    /// If set, a validator constructs its gRPC service but does not bind the
+    /// network address until activated through the admin interface. This
+    /// lets a warm standby validator run consensus and the checkpoint service
+    /// without serving traffic until it is promoted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub grpc_deferred_bind: bool,
+
+    /// Timeout and retries for binding the network address of the validator
+    /// gRPC service.
+    #[serde(default)]
+    pub grpc_bind_config: GrpcBindConfig,
+
     /// Configuration struct for P2P.
     #[serde(default)]
     pub p2p_config: P2pConfig,
@@ -175,6 +221,14 @@ pub struct NodeConfig {
     #[serde(default)]
     pub expensive_safety_check_config: ExpensiveSafetyCheckConfig,
 
+    /// Maximum number of signature batches verified concurrently. This covers
+    /// the batches of certificates submitted to this node and the checkpoints
+    /// verified by state sync, which dominate verification during catch-up.
+    /// Consensus blocks are not limited. Lowering it leaves CPU headroom for
+    /// other work, at the cost of a slower catch-up. Unbounded if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_verification_concurrency_limit: Option<NonZeroUsize>,
+
     /// Configuration to specify rules for denying transactions
     /// based on `objectsIDs`, `addresses`, or enable/disable many
     /// features such as publishing new packages or using shared objects.
@@ -210,6 +264,11 @@ pub struct NodeConfig {
     #[serde(default)]
     pub indexer_max_subscriptions: Option<usize>,
 
+    /// The maximum number of json-rpc subscriptions of a single connection.
+    /// Not limited if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexer_max_subscriptions_per_connection: Option<usize>,
+
     #[serde(default)]
     pub transaction_kv_store_read_config: TransactionKeyValueStoreReadConfig,
 
@@ -220,9 +279,29 @@ pub struct NodeConfig {
     #[serde(default = "default_jwk_fetch_interval_seconds")]
     pub jwk_fetch_interval_seconds: u64,
 
+    /// Minimum delay between two consecutive submissions of fetched JWKs to
+    /// consensus. Each JWK requires its own consensus transaction, so this
+    /// spreads the submissions of a key rotation returning several keys at
+    /// once. If not set, all keys are submitted immediately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwk_submission_interval_ms: Option<u64>,
+
+    /// Maximum expected absolute change of the total IOTA supply in NANOS
+    /// during a single epoch. A larger change is logged as an error at
+    /// reconfiguration, since it hints at a protocol bug. Not checked if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_epoch_supply_change_nanos: Option<u64>,
+
     #[serde(default = "default_zklogin_oauth_providers")]
     pub zklogin_oauth_providers: BTreeMap<Chain, BTreeSet<String>>,
 
+    /// Restricts the key ids of the JWKs accepted from a zklogin OAuth
+    /// provider, keyed by the provider name as in `zklogin_oauth_providers`.
+    /// JWKs of providers without a policy are accepted regardless of their
+    /// key id.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub zklogin_jwk_kid_policies: BTreeMap<String, JwkKidPolicy>,
+
     /// Configuration for defining thresholds and settings
     /// for managing system overload conditions in a node.
     #[serde(default = "default_authority_overload_config")]
@@ -252,6 +331,12 @@ pub struct NodeConfig {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iota_names_config: Option<IotaNamesConfig>,
+
+    /// Determines what a validator does when it is not part of the committee
+    /// of the next epoch, although it was part of the committee of the
+    /// current one.
+    #[serde(default)]
+    pub committee_removal_policy: CommitteeRemovalPolicy,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
@@ -264,6 +349,20 @@ pub enum ExecutionCacheConfig {
     },
 }
 
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommitteeRemovalPolicy {
+    /// Silently continue as a fullnode.
+    #[default]
+    Demote,
+    /// Log an error and continue as a fullnode.
+    Alert,
+    /// Log an error and shut the node down instead of continuing as a
+    /// fullnode. Should not be used when the removal of the validator was
+    /// requested on purpose.
+    Halt,
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ServerType {
@@ -272,10 +371,48 @@ pub enum ServerType {
     Both,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct TransactionKeyValueStoreReadConfig {
     pub base_url: String,
+
+    /// How often a request to the http kv store is retried after it failed or
+    /// the server responded with an error.
+    ///
+    /// If unspecified, this will default to `3`.
+    #[serde(default = "default_kv_store_max_retries")]
+    pub max_retries: u32,
+
+    /// Backoff before the first retry, in milliseconds. The backoff is doubled
+    /// for every further retry, up to at most 10 seconds.
+    ///
+    /// If unspecified, this will default to `100`.
+    #[serde(default = "default_kv_store_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+
+    /// Timeout of a single request to the http kv store, in milliseconds.
+    /// Requests don't time out if unspecified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout_ms: Option<u64>,
+}
+
+fn default_kv_store_max_retries() -> u32 {
+    3
+}
+
+fn default_kv_store_retry_backoff_ms() -> u64 {
+    100
+}
+
+impl Default for TransactionKeyValueStoreReadConfig {
+    fn default() -> Self {
+        Self {
+            base_url: String::new(),
+            max_retries: default_kv_store_max_retries(),
+            retry_backoff_ms: default_kv_store_retry_backoff_ms(),
+            request_timeout_ms: None,
+        }
+    }
 }
 
 fn default_jwk_fetch_interval_seconds() -> u64 {
@@ -314,6 +451,24 @@ pub fn default_zklogin_oauth_providers() -> BTreeMap<Chain, BTreeSet<String>> {
     map
 }
 
+/// Key ids (`kid`) of the JWKs accepted from a zklogin OAuth provider.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct JwkKidPolicy {
+    /// If set, only JWKs with one of these key ids are accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<BTreeSet<String>>,
+    /// JWKs with one of these key ids are rejected, even if they are allowed.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub deny: BTreeSet<String>,
+}
+
+impl JwkKidPolicy {
+    pub fn accepts(&self, kid: &str) -> bool {
+        !self.deny.contains(kid) && self.allow.as_ref().is_none_or(|allow| allow.contains(kid))
+    }
+}
+
 fn default_authority_store_pruning_config() -> AuthorityStorePruningConfig {
     AuthorityStorePruningConfig::default()
 }
@@ -431,12 +586,8 @@ impl NodeConfig {
             anyhow::bail!("no file location set");
         };
 
-        // Load from file
-        let migration_tx_data = MigrationTxData::load(location)?;
-
         // Validate migration content in order to avoid corrupted or malicious data
-        migration_tx_data.validate_from_genesis(self.genesis.genesis()?)?;
-        Ok(migration_tx_data)
+        MigrationTxData::load_and_validate(location, self.genesis.genesis()?)
     }
 
     pub fn iota_address(&self) -> IotaAddress {
@@ -559,6 +710,13 @@ pub struct CheckpointExecutorConfig {
     /// directory for post-processing
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_ingestion_dir: Option<PathBuf>,
+    /// Optional resident memory threshold of the process, in bytes.
+    ///
+    /// When specified, only a single checkpoint is executed at a time while
+    /// the memory used by the process exceeds the threshold, trading
+    /// throughput for stability when catching up on memory constrained hosts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_backpressure_threshold_bytes: Option<u64>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -593,6 +751,19 @@ pub struct ExpensiveSafetyCheckConfig {
 
     #[serde(default)]
     enable_secondary_index_checks: bool,
+
+    /// If enabled, the IOTA conservation check done when a node starts from
+    /// genesis runs in a background task instead of blocking startup. Since
+    /// the node may already execute transactions while the check scans the
+    /// live object set, a failure reported this way can be spurious.
+    #[serde(default)]
+    run_genesis_iota_conservation_check_in_background: bool,
+
+    /// If enabled, the node shuts down when the background genesis IOTA
+    /// conservation check fails. Otherwise the failure is only logged and
+    /// reported as a metric.
+    #[serde(default)]
+    shutdown_on_genesis_iota_conservation_check_failure: bool,
     // TODO: Add more expensive checks here
 }
 
@@ -605,6 +776,8 @@ impl ExpensiveSafetyCheckConfig {
             enable_state_consistency_check: true,
             force_disable_state_consistency_check: false,
             enable_secondary_index_checks: false, // Disable by default for now
+            run_genesis_iota_conservation_check_in_background: false,
+            shutdown_on_genesis_iota_conservation_check_failure: false,
         }
     }
 
@@ -616,6 +789,8 @@ impl ExpensiveSafetyCheckConfig {
             enable_state_consistency_check: false,
             force_disable_state_consistency_check: true,
             enable_secondary_index_checks: false,
+            run_genesis_iota_conservation_check_in_background: false,
+            shutdown_on_genesis_iota_conservation_check_failure: false,
         }
     }
 
@@ -644,6 +819,14 @@ impl ExpensiveSafetyCheckConfig {
     pub fn enable_secondary_index_checks(&self) -> bool {
         self.enable_secondary_index_checks
     }
+
+    pub fn run_genesis_iota_conservation_check_in_background(&self) -> bool {
+        self.run_genesis_iota_conservation_check_in_background
+    }
+
+    pub fn shutdown_on_genesis_iota_conservation_check_failure(&self) -> bool {
+        self.shutdown_on_genesis_iota_conservation_check_failure
+    }
 }
 
 fn default_checkpoint_execution_max_concurrency() -> usize {
@@ -660,6 +843,63 @@ impl Default for CheckpointExecutorConfig {
             checkpoint_execution_max_concurrency: default_checkpoint_execution_max_concurrency(),
             local_execution_timeout_sec: default_local_execution_timeout_sec(),
             data_ingestion_dir: None,
+            memory_backpressure_threshold_bytes: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct GrpcBindConfig {
+    /// Number of seconds a single attempt to bind the network address may
+    /// take before it is abandoned.
+    ///
+    /// If unspecified, this will default to `10`.
+    #[serde(default = "default_grpc_bind_timeout_sec")]
+    pub timeout_sec: u64,
+
+    /// Number of attempts to bind the network address before giving up, e.g.,
+    /// because the port was not yet released after a restart.
+    ///
+    /// If unspecified, this will default to `5`.
+    #[serde(default = "default_grpc_bind_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Number of milliseconds to wait between two attempts.
+    ///
+    /// If unspecified, this will default to `1000`.
+    #[serde(default = "default_grpc_bind_retry_interval_ms")]
+    pub retry_interval_ms: u64,
+}
+
+impl GrpcBindConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_sec)
+    }
+
+    pub fn retry_interval(&self) -> Duration {
+        Duration::from_millis(self.retry_interval_ms)
+    }
+}
+
+fn default_grpc_bind_timeout_sec() -> u64 {
+    10
+}
+
+fn default_grpc_bind_max_attempts() -> u32 {
+    5
+}
+
+fn default_grpc_bind_retry_interval_ms() -> u64 {
+    1_000
+}
+
+impl Default for GrpcBindConfig {
+    fn default() -> Self {
+        Self {
+            timeout_sec: default_grpc_bind_timeout_sec(),
+            max_attempts: default_grpc_bind_max_attempts(),
+            retry_interval_ms: default_grpc_bind_retry_interval_ms(),
         }
     }
 }
@@ -804,7 +1044,12 @@ pub struct StateArchiveConfig {
 pub struct StateSnapshotConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub object_store_config: Option<ObjectStoreConfig>,
+    /// Number of snapshot files uploaded in parallel. Defaults to 20 if 0.
     pub concurrency: usize,
+    /// Number of parts of a single snapshot file uploaded in parallel. If not
+    /// set, each file is uploaded with a single request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_file_concurrency: Option<NonZeroUsize>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Serialize)]
@@ -1063,6 +1308,19 @@ impl KeyPairWithPath {
             })
             .as_ref()
     }
+
+    /// Like [`Self::keypair`], but returns an error instead of panicking if the
+    /// keypair file cannot be read.
+    pub fn try_keypair(&self) -> Result<&IotaKeyPair> {
+        self.keypair
+            .get_or_try_init(|| match &self.location {
+                KeyPairLocation::InPlace { value } => Ok(value.clone()),
+                KeyPairLocation::File { path } => read_keypair_from_file(path)
+                    .map(Arc::new)
+                    .map_err(|e| anyhow::anyhow!("invalid keypair file at path {path:?}: {e}")),
+            })
+            .map(AsRef::as_ref)
+    }
 }
 
 /// Wrapper struct for AuthorityKeyPair that can be deserialized from a file
@@ -1128,6 +1386,19 @@ impl AuthorityKeyPairWithPath {
             })
             .as_ref()
     }
+
+    /// Like [`Self::authority_keypair`], but returns an error instead of
+    /// panicking if the keypair file cannot be read.
+    pub fn try_authority_keypair(&self) -> Result<&AuthorityKeyPair> {
+        self.keypair
+            .get_or_try_init(|| match &self.location {
+                AuthorityKeyPairLocation::InPlace { value } => Ok(value.clone()),
+                AuthorityKeyPairLocation::File { path } => read_authority_keypair_from_file(path)
+                    .map(Arc::new)
+                    .map_err(|e| anyhow::anyhow!("invalid authority keypair file {path:?}: {e}")),
+            })
+            .map(AsRef::as_ref)
+    }
 }
 
 /// Configurations which determine how we dump state debug info.
@@ -1150,7 +1421,7 @@ mod tests {
     };
     use rand::{SeedableRng, rngs::StdRng};
 
-    use super::Genesis;
+    use super::{Genesis, JwkKidPolicy};
     use crate::NodeConfig;
 
     #[test]
@@ -1207,6 +1478,30 @@ mod tests {
             protocol_key_pair.public()
         );
     }
+
+    #[test]
+    fn jwk_kid_policy_accepts() {
+        let any = JwkKidPolicy::default();
+        assert!(any.accepts("a"));
+
+        let policy = JwkKidPolicy {
+            allow: Some(["a".to_string(), "b".to_string()].into()),
+            deny: ["b".to_string(), "c".to_string()].into(),
+        };
+        assert!(policy.accepts("a"));
+        // Denied key ids are rejected even if they are allowed.
+        assert!(!policy.accepts("b"));
+        assert!(!policy.accepts("c"));
+        // Key ids missing from the allow list are rejected.
+        assert!(!policy.accepts("d"));
+
+        let deny_only = JwkKidPolicy {
+            allow: None,
+            deny: ["c".to_string()].into(),
+        };
+        assert!(deny_only.accepts("a"));
+        assert!(!deny_only.accepts("c"));
+    }
 }
 
 // RunWithRange is used to specify the ending epoch/checkpoint to process.
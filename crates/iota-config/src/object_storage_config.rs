@@ -2,14 +2,26 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{env, fs, path::PathBuf, sync::Arc};
+use std::{
+    env, fs,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
 use clap::*;
-use object_store::{ClientOptions, DynObjectStore, aws::AmazonS3Builder};
+use object_store::{
+    ClientOptions, CredentialProvider, DynObjectStore,
+    aws::{AmazonS3Builder, AwsCredential},
+};
+use once_cell::sync::OnceCell;
+use prometheus::{IntCounterVec, Registry, register_int_counter_vec_with_registry};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
 
 /// Object-store type.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
@@ -98,12 +110,144 @@ pub struct ObjectStoreConfig {
     #[serde(default)]
     #[arg(long, default_value_t = false)]
     pub no_sign_request: bool,
+    /// When using Amazon S3 as the object store, set this to the path of a
+    /// file holding short-lived credentials (e.g. obtained via STS or OIDC)
+    /// that is rewritten by an external agent whenever they are rotated. The
+    /// file is re-read periodically, so credentials can change without a
+    /// restart. Takes precedence over the static access key settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub aws_credentials_file: Option<PathBuf>,
+    /// How often the credentials in `aws-credentials-file` are re-read.
+    /// Defaults to 300 seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub credentials_refresh_interval_secs: Option<u64>,
 }
 
 fn default_object_store_connection_limit() -> usize {
     20
 }
 
+const DEFAULT_CREDENTIALS_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+static CREDENTIAL_METRICS: OnceCell<Arc<ObjectStoreCredentialMetrics>> = OnceCell::new();
+
+/// Metrics of the credential refreshes done by object stores that are
+/// configured with a credentials file.
+#[derive(Debug)]
+pub struct ObjectStoreCredentialMetrics {
+    pub credential_refreshes: IntCounterVec,
+    pub credential_refresh_failures: IntCounterVec,
+}
+
+impl ObjectStoreCredentialMetrics {
+    fn new(registry: &Registry) -> Self {
+        Self {
+            credential_refreshes: register_int_counter_vec_with_registry!(
+                "object_store_credential_refreshes",
+                "Number of successful object store credential refreshes",
+                &["bucket"],
+                registry,
+            )
+            .unwrap(),
+            credential_refresh_failures: register_int_counter_vec_with_registry!(
+                "object_store_credential_refresh_failures",
+                "Number of failed object store credential refreshes",
+                &["bucket"],
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Registers the metrics with `registry`. Object stores are created deep
+    /// inside the archival and snapshot components, so the metrics are kept in
+    /// a global which is initialized once per process.
+    pub fn init(registry: &Registry) -> &'static Arc<ObjectStoreCredentialMetrics> {
+        CREDENTIAL_METRICS.get_or_init(|| Arc::new(ObjectStoreCredentialMetrics::new(registry)))
+    }
+}
+
+/// Content of the file referenced by `aws-credentials-file`.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct AwsCredentialsFile {
+    access_key_id: String,
+    secret_access_key: String,
+    #[serde(default)]
+    session_token: Option<String>,
+}
+
+/// Provides AWS credentials from a file which is re-read once the refresh
+/// interval elapsed. If a refresh fails, the previously loaded credentials are
+/// returned until a later refresh succeeds.
+#[derive(Debug)]
+struct FileAwsCredentialProvider {
+    path: PathBuf,
+    bucket: String,
+    refresh_interval: Duration,
+    cached: Mutex<Option<(Instant, Arc<AwsCredential>)>>,
+}
+
+impl FileAwsCredentialProvider {
+    async fn load(&self) -> Result<Arc<AwsCredential>> {
+        let content = tokio::fs::read_to_string(&self.path)
+            .await
+            .with_context(|| format!("failed to read credentials file: {}", self.path.display()))?;
+        let credentials: AwsCredentialsFile =
+            serde_yaml::from_str(&content).context("invalid credentials file")?;
+        Ok(Arc::new(AwsCredential {
+            key_id: credentials.access_key_id,
+            secret_key: credentials.secret_access_key,
+            token: credentials.session_token,
+        }))
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for FileAwsCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<AwsCredential>> {
+        let mut cached = self.cached.lock().await;
+        if let Some((loaded_at, credential)) = cached.as_ref() {
+            if loaded_at.elapsed() < self.refresh_interval {
+                return Ok(credential.clone());
+            }
+        }
+        let metrics = CREDENTIAL_METRICS.get();
+        match self.load().await {
+            Ok(credential) => {
+                if let Some(metrics) = metrics {
+                    metrics
+                        .credential_refreshes
+                        .with_label_values(&[&self.bucket])
+                        .inc();
+                }
+                *cached = Some((Instant::now(), credential.clone()));
+                Ok(credential)
+            }
+            Err(err) => {
+                warn!(bucket = %self.bucket, "Failed to refresh object store credentials: {err:?}");
+                if let Some(metrics) = metrics {
+                    metrics
+                        .credential_refresh_failures
+                        .with_label_values(&[&self.bucket])
+                        .inc();
+                }
+                match cached.as_ref() {
+                    Some((_, credential)) => Ok(credential.clone()),
+                    None => Err(object_store::Error::Generic {
+                        store: "S3",
+                        source: err.into(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
 fn no_timeout_options() -> ClientOptions {
     ClientOptions::new()
         .with_timeout_disabled()
@@ -148,7 +292,17 @@ impl ObjectStoreConfig {
             builder = builder.with_bucket_name(bucket);
         }
 
-        if let Some(key_id) = &self.aws_access_key_id {
+        if let Some(path) = &self.aws_credentials_file {
+            builder = builder.with_credentials(Arc::new(FileAwsCredentialProvider {
+                path: path.clone(),
+                bucket: self.bucket.clone().unwrap_or_default(),
+                refresh_interval: self
+                    .credentials_refresh_interval_secs
+                    .map(Duration::from_secs)
+                    .unwrap_or(DEFAULT_CREDENTIALS_REFRESH_INTERVAL),
+                cached: Mutex::new(None),
+            }));
+        } else if let Some(key_id) = &self.aws_access_key_id {
             builder = builder.with_access_key_id(key_id);
         } else if let Ok(secret) = env::var("ARCHIVE_READ_AWS_ACCESS_KEY_ID") {
             builder = builder.with_access_key_id(secret);
@@ -2,7 +2,11 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{net::SocketAddr, num::NonZeroU32, time::Duration};
+use std::{
+    net::SocketAddr,
+    num::{NonZeroU32, NonZeroU64},
+    time::Duration,
+};
 
 use iota_types::{
     messages_checkpoint::{CheckpointDigest, CheckpointSequenceNumber},
@@ -197,6 +201,16 @@ pub struct StateSyncConfig {
     /// content from. If unspecified, this will set to default value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wait_interval_when_no_peer_to_sync_content_ms: Option<u64>,
+
+    /// Set the upper bound on how many checkpoints the contents sync may get
+    /// ahead of the highest executed checkpoint. Syncing new checkpoint
+    /// contents pauses while the bound is reached and resumes once
+    /// execution catches up. Not supported in archival-only mode, where
+    /// checkpoints aren't executed.
+    ///
+    /// If unspecified, this will default to no limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_checkpoints_ahead_of_execution: Option<NonZeroU64>,
 }
 
 impl StateSyncConfig {
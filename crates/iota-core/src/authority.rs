@@ -298,6 +298,10 @@ pub struct AuthorityMetrics {
 
     pub authenticator_state_update_failed: IntCounter,
 
+    /// Randomness metrics
+    pub(crate) randomness_rounds_dropped: IntCounter,
+    pub(crate) randomness_state_update_execution_latency: Histogram,
+
     /// Count of zklogin signatures
     pub zklogin_sig_count: IntCounter,
     /// Count of multisig signatures
@@ -721,6 +725,19 @@ impl AuthorityMetrics {
                 registry,
             )
             .unwrap(),
+            randomness_rounds_dropped: register_int_counter_with_registry!(
+                "randomness_rounds_dropped",
+                "Number of randomness rounds dropped because they were generated for a different epoch",
+                registry,
+            )
+            .unwrap(),
+            randomness_state_update_execution_latency: register_histogram_with_registry!(
+                "randomness_state_update_execution_latency",
+                "Latency from receiving a round of randomness to the execution of its randomness state update transaction",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             zklogin_sig_count: register_int_counter_with_registry!(
                 "zklogin_sig_count",
                 "Count of zkLogin signatures",
@@ -3054,8 +3071,11 @@ impl AuthorityState {
             cur_epoch_store.check_all_executed_transactions_in_checkpoint();
         }
 
-        self.get_reconfig_api()
-            .expensive_check_iota_conservation(cur_epoch_store, Some(epoch_supply_change))?;
+        self.get_reconfig_api().expensive_check_iota_conservation(
+            cur_epoch_store,
+            Some(epoch_supply_change),
+            false,
+        )?;
 
         // check for root state hash consistency with live object set
         if expensive_safety_check_config.enable_state_consistency_check() {
@@ -4896,8 +4916,13 @@ impl RandomnessRoundReceiver {
                 "dropping randomness for epoch {epoch}, round {round}, because we are in epoch {}",
                 epoch_store.epoch()
             );
+            self.authority_state.metrics.randomness_rounds_dropped.inc();
             return;
         }
+        epoch_store
+            .metrics
+            .epoch_random_beacon_rounds_received
+            .inc();
         let transaction = VerifiedTransaction::new_randomness_state_update(
             epoch,
             round,
@@ -4919,6 +4944,12 @@ impl RandomnessRoundReceiver {
             .enqueue(vec![transaction], &epoch_store);
 
         let authority_state = self.authority_state.clone();
+        let epoch_metrics = epoch_store.metrics.clone();
+        let execution_timer = self
+            .authority_state
+            .metrics
+            .randomness_state_update_execution_latency
+            .start_timer();
         spawn_monitored_task!(async move {
             // Wait for transaction execution in a separate task, to avoid deadlock in case
             // of out-of-order randomness generation. (Each
@@ -4963,6 +4994,8 @@ impl RandomnessRoundReceiver {
                     "failed to execute randomness state update transaction at epoch {epoch}, round {round}: {effects:?}"
                 );
             }
+            execution_timer.observe_duration();
+            epoch_metrics.epoch_random_beacon_rounds_executed.inc();
             debug!(
                 "successfully executed randomness state update transaction at epoch {epoch}, round {round}"
             );
@@ -7,6 +7,7 @@ use std::{
     future::Future,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 use arc_swap::ArcSwapOption;
@@ -66,7 +67,10 @@ use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use prometheus::IntCounter;
 use serde::{Deserialize, Serialize};
 use tap::TapOptional;
-use tokio::{sync::OnceCell, time::Instant};
+use tokio::{
+    sync::{OnceCell, Semaphore},
+    time::Instant,
+};
 use tracing::{debug, error, info, instrument, trace, warn};
 use typed_store::{
     DBMapUtils, Map, TypedStoreError, retry_transaction_forever,
@@ -619,6 +623,10 @@ pub struct AuthorityEpochTables {
     /// able to scan to find all Jwks for a given round
     active_jwks: DBMap<(u64, (JwkId, JWK)), ()>,
 
+    /// JWKs this authority fetched and submitted to consensus, so that they
+    /// are not submitted again after a restart.
+    submitted_jwks: DBMap<(JwkId, JWK), ()>,
+
     /// Transactions that are being deferred until some future time
     deferred_transactions: DBMap<DeferralKey, Vec<VerifiedSequencedConsensusTransaction>>,
 
@@ -822,6 +830,7 @@ impl AuthorityPerEpochStore {
         object_store: Arc<dyn ObjectStore + Send + Sync>,
         cache_metrics: Arc<ResolverMetrics>,
         signature_verifier_metrics: Arc<SignatureVerifierMetrics>,
+        signature_verification_concurrency_limit: Option<Arc<Semaphore>>,
         expensive_safety_check_config: &ExpensiveSafetyCheckConfig,
         chain_identifier: ChainIdentifier,
     ) -> Arc<Self> {
@@ -882,7 +891,8 @@ impl AuthorityPerEpochStore {
             zklogin_env,
             protocol_config.accept_zklogin_in_multisig(),
             protocol_config.zklogin_max_epoch_upper_bound_delta(),
-        );
+        )
+        .with_concurrency_limit(signature_verification_concurrency_limit);
 
         let authenticator_state_exists = epoch_start_configuration
             .authenticator_obj_initial_shared_version()
@@ -1056,6 +1066,7 @@ impl AuthorityPerEpochStore {
             object_store,
             self.execution_component.metrics(),
             self.signature_verifier.metrics.clone(),
+            self.signature_verifier.concurrency_limit(),
             expensive_safety_check_config,
             chain_identifier,
         )
@@ -2311,6 +2322,32 @@ impl AuthorityPerEpochStore {
             .collect::<Result<Vec<_>, _>>()?)
     }
 
+    /// Returns the JWKs this authority submitted to consensus in this epoch.
+    pub fn get_submitted_jwks(&self) -> IotaResult<HashSet<(JwkId, JWK)>> {
+        Ok(self
+            .tables()?
+            .submitted_jwks
+            .safe_iter()
+            .map_ok(|(key, _)| key)
+            .collect::<Result<HashSet<_>, _>>()?)
+    }
+
+    /// Records that this authority submitted `jwks` to consensus in this
+    /// epoch.
+    pub fn insert_submitted_jwks(
+        &self,
+        jwks: impl IntoIterator<Item = (JwkId, JWK)>,
+    ) -> IotaResult {
+        let tables = self.tables()?;
+        let mut batch = tables.submitted_jwks.batch();
+        batch.insert_batch(
+            &tables.submitted_jwks,
+            jwks.into_iter().map(|key| (key, ())),
+        )?;
+        batch.write()?;
+        Ok(())
+    }
+
     pub fn jwk_active_in_current_epoch(&self, jwk_id: &JwkId, jwk: &JWK) -> bool {
         let jwk_aggregator = self.jwk_aggregator.lock();
         jwk_aggregator.has_quorum_for_key(&(jwk_id.clone(), jwk.clone()))
@@ -3927,6 +3964,13 @@ impl AuthorityPerEpochStore {
         }
     }
 
+    pub fn record_reconfiguration_phase_duration_metric(&self, phase: &str, duration: Duration) {
+        self.metrics
+            .epoch_reconfiguration_duration_seconds
+            .with_label_values(&[phase])
+            .observe(duration.as_secs_f64());
+    }
+
     fn record_reconfig_halt_duration_metric(&self) {
         if let Some(epoch_close_time) = *self.epoch_close_time.read() {
             self.metrics
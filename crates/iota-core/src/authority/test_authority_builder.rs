@@ -254,6 +254,7 @@ impl<'a> TestAuthorityBuilder<'a> {
             cache_traits.object_store.clone(),
             cache_metrics,
             signature_verifier_metrics,
+            None,
             &expensive_safety_checks,
             ChainIdentifier::from(*genesis.checkpoint().digest()),
         );
@@ -364,6 +364,11 @@ impl ValidatorService {
         &self.state
     }
 
+    /// Returns the traffic controller, if a policy config was provided.
+    pub fn traffic_controller(&self) -> Option<Arc<TrafficController>> {
+        self.traffic_controller.clone()
+    }
+
     /// Executes a `CertifiedTransaction` for testing.
     pub async fn execute_certificate_for_testing(
         &self,
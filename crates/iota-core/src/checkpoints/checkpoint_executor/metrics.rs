@@ -17,6 +17,7 @@ pub struct CheckpointExecutorMetrics {
     pub checkpoint_exec_errors: IntCounter,
     pub checkpoint_exec_epoch: IntGauge,
     pub checkpoint_exec_inflight: IntGauge,
+    pub checkpoint_exec_memory_backpressure: IntGauge,
     pub checkpoint_exec_latency_us: Histogram,
     pub checkpoint_prepare_latency_us: Histogram,
     pub checkpoint_transaction_count: Histogram,
@@ -63,6 +64,12 @@ impl CheckpointExecutorMetrics {
                 registry
             )
             .unwrap(),
+            checkpoint_exec_memory_backpressure: register_int_gauge_with_registry!(
+                "checkpoint_exec_memory_backpressure",
+                "Whether checkpoint execution is throttled because of the process memory usage",
+                registry
+            )
+            .unwrap(),
             checkpoint_exec_latency_us: Histogram::new_in_registry(
                 "checkpoint_exec_latency_us",
                 "Latency of executing a checkpoint from enqueue to all effects available, in microseconds",
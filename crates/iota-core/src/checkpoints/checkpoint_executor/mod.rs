@@ -46,9 +46,13 @@ use iota_types::{
     transaction::{TransactionDataAPI, TransactionKind, VerifiedTransaction},
 };
 use itertools::izip;
+use parking_lot::Mutex;
 use tap::{TapFallible, TapOptional};
 use tokio::{
-    sync::broadcast::{self, error::RecvError},
+    sync::{
+        broadcast::{self, error::RecvError},
+        watch,
+    },
     task::JoinHandle,
     time::timeout,
 };
@@ -59,10 +63,7 @@ use self::metrics::CheckpointExecutorMetrics;
 use crate::{
     authority::{AuthorityState, authority_per_epoch_store::AuthorityPerEpochStore},
     checkpoints::{
-        CheckpointStore,
-        checkpoint_executor::data_ingestion_handler::{
-            load_checkpoint_data, store_checkpoint_locally,
-        },
+        CheckpointStore, checkpoint_executor::data_ingestion_handler::store_checkpoint_locally,
     },
     execution_cache::{ObjectCacheRead, TransactionCacheRead},
     state_accumulator::StateAccumulator,
@@ -72,6 +73,8 @@ use crate::{
 mod data_ingestion_handler;
 pub mod metrics;
 
+pub(crate) use data_ingestion_handler::load_checkpoint_data;
+
 #[cfg(test)]
 pub(crate) mod tests;
 
@@ -150,8 +153,17 @@ pub struct CheckpointExecutor {
     accumulator: Arc<StateAccumulator>,
     config: CheckpointExecutorConfig,
     metrics: Arc<CheckpointExecutorMetrics>,
+    /// Time of the last memory sample and whether the memory threshold was
+    /// exceeded at that time.
+    memory_backpressure: Mutex<Option<(Instant, bool)>>,
+    /// Notified of every executed checkpoint, if set.
+    highest_executed_checkpoint_sender: Option<watch::Sender<CheckpointSequenceNumber>>,
 }
 
+/// How often the process memory is sampled when memory backpressure is
+/// enabled.
+const MEMORY_BACKPRESSURE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 impl CheckpointExecutor {
     pub fn new(
         mailbox: broadcast::Receiver<VerifiedCheckpoint>,
@@ -171,9 +183,21 @@ impl CheckpointExecutor {
             accumulator,
             config,
             metrics,
+            memory_backpressure: Mutex::new(None),
+            highest_executed_checkpoint_sender: None,
         }
     }
 
+    /// Publishes the sequence number of every executed checkpoint to
+    /// `sender`, e.g. for state sync to follow the progress of execution.
+    pub fn with_highest_executed_checkpoint_sender(
+        mut self,
+        sender: watch::Sender<CheckpointSequenceNumber>,
+    ) -> Self {
+        self.highest_executed_checkpoint_sender = Some(sender);
+        self
+    }
+
     pub fn new_for_tests(
         mailbox: broadcast::Receiver<VerifiedCheckpoint>,
         checkpoint_store: Arc<CheckpointStore>,
@@ -409,6 +433,9 @@ impl CheckpointExecutor {
         self.checkpoint_store
             .update_highest_executed_checkpoint(checkpoint)
             .unwrap();
+        if let Some(sender) = &self.highest_executed_checkpoint_sender {
+            sender.send_replace(seq);
+        }
         self.metrics.last_executed_checkpoint.set(seq as i64);
 
         self.metrics
@@ -467,7 +494,7 @@ impl CheckpointExecutor {
         };
 
         while *next_to_schedule <= *latest_synced_checkpoint.sequence_number()
-            && pending.len() < self.config.checkpoint_execution_max_concurrency
+            && pending.len() < self.max_concurrency()
         {
             let checkpoint = self
                 .checkpoint_store
@@ -498,6 +525,49 @@ impl CheckpointExecutor {
         }
     }
 
+    /// Returns the number of checkpoints which may be executed concurrently.
+    /// This is reduced to one while the process memory exceeds the configured
+    /// backpressure threshold.
+    fn max_concurrency(&self) -> usize {
+        let Some(threshold) = self.config.memory_backpressure_threshold_bytes else {
+            return self.config.checkpoint_execution_max_concurrency;
+        };
+        let mut memory_backpressure = self.memory_backpressure.lock();
+        let throttled = match *memory_backpressure {
+            Some((sampled_at, throttled))
+                if sampled_at.elapsed() < MEMORY_BACKPRESSURE_CHECK_INTERVAL =>
+            {
+                throttled
+            }
+            previous => {
+                let was_throttled = previous.is_some_and(|(_, throttled)| throttled);
+                let throttled = iota_metrics::hardware_metrics::process_resident_memory_bytes()
+                    .is_some_and(|memory| memory > threshold);
+                if throttled != was_throttled {
+                    if throttled {
+                        warn!(
+                            "Process memory exceeds {threshold} bytes, throttling checkpoint execution"
+                        );
+                    } else {
+                        info!(
+                            "Process memory below {threshold} bytes, resuming checkpoint execution"
+                        );
+                    }
+                }
+                self.metrics
+                    .checkpoint_exec_memory_backpressure
+                    .set(throttled as i64);
+                *memory_backpressure = Some((Instant::now(), throttled));
+                throttled
+            }
+        };
+        if throttled {
+            1
+        } else {
+            self.config.checkpoint_execution_max_concurrency
+        }
+    }
+
     #[instrument(level = "error", skip_all, fields(seq = ?checkpoint.sequence_number(), epoch = ?epoch_store.epoch()))]
     fn schedule_checkpoint(
         &self,
@@ -298,6 +298,21 @@ pub struct ConsensusAdapter {
     /// Semaphore limiting parallel submissions to consensus
     submit_semaphore: Semaphore,
     latency_observer: LatencyObserver,
+    /// Submission behavior injected per transaction by tests.
+    #[cfg(msim)]
+    submission_overrides: DashMap<TransactionDigest, SubmissionOverride>,
+}
+
+/// Behavior injected into the consensus submission of a transaction, to
+/// exercise the retry and timeout paths in tests.
+#[cfg(msim)]
+#[derive(Clone, Copy, Debug)]
+pub enum SubmissionOverride {
+    /// Delays every submission attempt by the given duration.
+    Delay(Duration),
+    /// Fails the given number of submission attempts, the attempts after
+    /// that are submitted normally.
+    Fail(u32),
 }
 
 pub trait CheckConnection: Send + Sync {
@@ -345,7 +360,64 @@ impl ConsensusAdapter {
             metrics,
             submit_semaphore: Semaphore::new(max_pending_local_submissions),
             latency_observer: LatencyObserver::new(),
+            #[cfg(msim)]
+            submission_overrides: DashMap::new(),
+        }
+    }
+
+    /// Sets the behavior of the consensus submissions of the transaction
+    /// `digest`, `None` restores the normal behavior.
+    #[cfg(msim)]
+    pub fn set_submission_override(
+        &self,
+        digest: TransactionDigest,
+        submission_override: Option<SubmissionOverride>,
+    ) {
+        match submission_override {
+            Some(submission_override) => {
+                self.submission_overrides
+                    .insert(digest, submission_override);
+            }
+            None => {
+                self.submission_overrides.remove(&digest);
+            }
+        }
+    }
+
+    /// Applies the overrides of the user transactions in `transactions` to a
+    /// submission attempt.
+    #[cfg(msim)]
+    async fn apply_submission_overrides(
+        &self,
+        transactions: &[ConsensusTransaction],
+    ) -> IotaResult {
+        let mut delay = Duration::ZERO;
+        let mut fail = false;
+        for transaction in transactions {
+            let ConsensusTransactionKind::UserTransaction(certificate) = &transaction.kind else {
+                continue;
+            };
+            let digest = certificate.digest();
+            let Some(mut submission_override) = self.submission_overrides.get_mut(digest) else {
+                continue;
+            };
+            match &mut *submission_override {
+                SubmissionOverride::Delay(d) => delay = delay.max(*d),
+                SubmissionOverride::Fail(0) => {}
+                SubmissionOverride::Fail(remaining) => {
+                    *remaining -= 1;
+                    fail = true;
+                }
+            }
+        }
+        if !delay.is_zero() {
+            time::sleep(delay).await;
         }
+        fp_ensure!(
+            !fail,
+            IotaError::FailedToSubmitToConsensus("injected submission failure".to_string())
+        );
+        Ok(())
     }
 
     pub fn swap_low_scoring_authorities(
@@ -922,11 +994,21 @@ impl ConsensusAdapter {
         let mut retries: u32 = 0;
 
         let status_waiter = loop {
-            match self
+            #[cfg(msim)]
+            let result = match self.apply_submission_overrides(transactions).await {
+                Ok(()) => {
+                    self.consensus_client
+                        .submit(transactions, epoch_store)
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+            #[cfg(not(msim))]
+            let result = self
                 .consensus_client
                 .submit(transactions, epoch_store)
-                .await
-            {
+                .await;
+            match result {
                 Err(err) => {
                     // This can happen during reconfig, or when consensus has full internal buffers
                     // and needs to back pressure, so retry a few times before logging warnings.
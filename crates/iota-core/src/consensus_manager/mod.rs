@@ -34,6 +34,9 @@ use crate::{
 
 pub mod mysticeti_manager;
 
+#[cfg(msim)]
+pub use consensus_core::set_clock_offset;
+
 #[derive(PartialEq)]
 pub(crate) enum Running {
     True(EpochId, ProtocolVersion),
@@ -122,6 +125,12 @@ impl ConsensusManager {
     pub fn get_storage_base_path(&self) -> PathBuf {
         self.consensus_config.db_path().to_path_buf()
     }
+
+    /// Waits until consensus has been started and replayed its locally
+    /// available commits.
+    pub async fn wait_until_ready(&self) {
+        self.mysticeti_client.wait_until_ready().await;
+    }
 }
 
 #[async_trait]
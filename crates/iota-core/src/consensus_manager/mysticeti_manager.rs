@@ -217,6 +217,7 @@ impl ConsensusManagerTrait for MysticetiManager {
 
         // Wait until all locally available commits have been processed
         registered_authority.0.replay_complete().await;
+        self.client.set_ready();
     }
 
     async fn shutdown(&self) {
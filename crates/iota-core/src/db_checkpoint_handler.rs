@@ -2,7 +2,13 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fs, num::NonZeroUsize, path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use anyhow::Result;
 use bytes::Bytes;
@@ -18,6 +24,7 @@ use iota_storage::{
         path_to_filesystem, put, run_manifest_update_loop, write_snapshot_manifest,
     },
 };
+use iota_types::committee::EpochId;
 use object_store::{DynObjectStore, path::Path};
 use prometheus::{IntGauge, Registry, register_int_gauge_with_registry};
 use tracing::{debug, error, info};
@@ -79,6 +86,9 @@ pub struct DBCheckpointHandler {
     /// Boolean flag to enable/disable object pruning and manual compaction
     /// before upload
     prune_and_compact_before_upload: bool,
+    /// Runtime override of `prune_and_compact_before_upload` for the db
+    /// checkpoint of a single epoch
+    prune_and_compact_before_upload_override: Mutex<Option<(EpochId, bool)>>,
     /// Indirect object config for pruner
     indirect_objects_threshold: usize,
     /// If true, upload will block on state snapshot upload completed marker
@@ -116,6 +126,7 @@ impl DBCheckpointHandler {
             interval: Duration::from_secs(interval_s),
             gc_markers,
             prune_and_compact_before_upload,
+            prune_and_compact_before_upload_override: Mutex::new(None),
             indirect_objects_threshold,
             state_snapshot_enabled,
             pruning_config,
@@ -141,6 +152,7 @@ impl DBCheckpointHandler {
             interval: Duration::from_secs(interval_s),
             gc_markers: vec![UPLOAD_COMPLETED_MARKER.to_string(), TEST_MARKER.to_string()],
             prune_and_compact_before_upload,
+            prune_and_compact_before_upload_override: Mutex::new(None),
             indirect_objects_threshold: 0,
             state_snapshot_enabled,
             pruning_config: AuthorityStorePruningConfig::default(),
@@ -148,6 +160,33 @@ impl DBCheckpointHandler {
         }))
     }
 
+    /// Whether the db checkpoint of `epoch` is pruned and compacted before
+    /// being uploaded.
+    pub fn prune_and_compact_before_upload(&self, epoch: EpochId) -> bool {
+        match *self
+            .prune_and_compact_before_upload_override
+            .lock()
+            .unwrap()
+        {
+            Some((override_epoch, enabled)) if override_epoch == epoch => enabled,
+            _ => self.prune_and_compact_before_upload,
+        }
+    }
+
+    /// Overrides whether the db checkpoint taken at the end of `epoch` is
+    /// pruned and compacted before being uploaded, e.g. to keep a full db
+    /// checkpoint around a migration. The override is cleared once that db
+    /// checkpoint is uploaded.
+    pub fn set_prune_and_compact_before_upload(&self, epoch: EpochId, enabled: bool) {
+        info!(
+            "Setting prune and compact before upload of the db checkpoint of epoch {epoch} to {enabled}"
+        );
+        *self
+            .prune_and_compact_before_upload_override
+            .lock()
+            .unwrap() = Some((epoch, enabled));
+    }
+
     /// Starts the db checkpoint uploader and manifest update loops if a remote
     /// store is specified. If no remote store is specified, it starts a
     /// loop that adds an UPLOAD_COMPLETED_MARKER to the epoch directory.
@@ -348,7 +387,7 @@ impl DBCheckpointHandler {
                     }
                 }
 
-                if self.prune_and_compact_before_upload {
+                if self.prune_and_compact_before_upload(*epoch) {
                     // Invoke pruning and compaction on the db checkpoint
                     self.prune_and_compact(local_db_path, *epoch, EPOCH_DURATION_MS_FOR_TESTING)
                         .await?;
@@ -371,6 +410,16 @@ impl DBCheckpointHandler {
                 let bytes = Bytes::from_static(b"success");
                 let success_marker = db_path.child(SUCCESS_MARKER);
                 put(&object_store, &success_marker, bytes.clone()).await?;
+
+                let mut prune_and_compact_override = self
+                    .prune_and_compact_before_upload_override
+                    .lock()
+                    .unwrap();
+                if prune_and_compact_override
+                    .is_some_and(|(override_epoch, _)| override_epoch == *epoch)
+                {
+                    *prune_and_compact_override = None;
+                }
             }
             let bytes = Bytes::from_static(b"success");
             let upload_completed_marker = db_path.child(UPLOAD_COMPLETED_MARKER);
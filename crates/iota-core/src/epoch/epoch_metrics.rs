@@ -4,7 +4,14 @@
 
 use std::sync::Arc;
 
-use prometheus::{IntGauge, Registry, register_int_gauge_with_registry};
+use prometheus::{
+    HistogramVec, IntGauge, Registry, register_histogram_vec_with_registry,
+    register_int_gauge_with_registry,
+};
+
+const RECONFIGURATION_DURATION_SEC_BUCKETS: &[f64] = &[
+    0.1, 0.25, 0.5, 1., 2.5, 5., 10., 20., 30., 60., 120., 300., 600.,
+];
 
 pub struct EpochMetrics {
     /// The current epoch ID. This is updated only when the AuthorityState
@@ -68,6 +75,13 @@ pub struct EpochMetrics {
     /// reconfiguration delay for each validator.
     pub epoch_validator_halt_duration_ms: IntGauge,
 
+    /// Wall-clock duration of the reconfiguration performed by the node once
+    /// the last checkpoint of the epoch was executed, i.e. (6) above,
+    /// labelled by phase: `checkpoint_service_shutdown`,
+    /// `consensus_shutdown`, `epoch_store_reconfigure`,
+    /// `new_components_start` and `total`.
+    pub epoch_reconfiguration_duration_seconds: HistogramVec,
+
     /// The interval from when the epoch begins (i.e. right after state
     /// reconfigure, when the new epoch_store is created), to when the first
     /// checkpoint of the epoch is ready for creation locally. This is (7)
@@ -115,6 +129,14 @@ pub struct EpochMetrics {
     /// DKG protocol, at which point the node has submitted a DKG
     /// Confirmation, for the most recent epoch.
     pub epoch_random_beacon_dkg_confirmation_time_ms: IntGauge,
+
+    /// The number of rounds of randomness received from the random beacon in
+    /// the current epoch.
+    pub epoch_random_beacon_rounds_received: IntGauge,
+
+    /// The number of rounds of randomness whose randomness state update
+    /// transaction was executed in the current epoch.
+    pub epoch_random_beacon_rounds_executed: IntGauge,
 }
 
 impl EpochMetrics {
@@ -177,6 +199,13 @@ impl EpochMetrics {
                 "Total time duration when the validator was halted (i.e. epoch closed)",
                 registry
             ).unwrap(),
+            epoch_reconfiguration_duration_seconds: register_histogram_vec_with_registry!(
+                "epoch_reconfiguration_duration_seconds",
+                "Duration of the phases of the reconfiguration to the next epoch",
+                &["phase"],
+                RECONFIGURATION_DURATION_SEC_BUCKETS.to_vec(),
+                registry
+            ).unwrap(),
             epoch_first_checkpoint_created_time_since_epoch_begin_ms: register_int_gauge_with_registry!(
                 "epoch_first_checkpoint_created_time_since_epoch_begin_ms",
                 "Time interval from when the epoch opens at new epoch to the first checkpoint is created locally",
@@ -233,6 +262,18 @@ impl EpochMetrics {
                 registry
             )
             .unwrap(),
+            epoch_random_beacon_rounds_received: register_int_gauge_with_registry!(
+                "epoch_random_beacon_rounds_received",
+                "The number of rounds of randomness received from the random beacon in the current epoch",
+                registry
+            )
+            .unwrap(),
+            epoch_random_beacon_rounds_executed: register_int_gauge_with_registry!(
+                "epoch_random_beacon_rounds_executed",
+                "The number of rounds of randomness whose randomness state update transaction was executed in the current epoch",
+                registry
+            )
+            .unwrap(),
         };
         Arc::new(this)
     }
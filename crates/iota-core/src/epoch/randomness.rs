@@ -201,6 +201,16 @@ impl RandomnessManager {
         };
         let protocol_config = epoch_store.protocol_config();
 
+        // The randomness round counts are reported per epoch
+        epoch_store
+            .metrics
+            .epoch_random_beacon_rounds_received
+            .set(0);
+        epoch_store
+            .metrics
+            .epoch_random_beacon_rounds_executed
+            .set(0);
+
         let name: AuthorityName = authority_key_pair.public().into();
         let committee = epoch_store.committee();
         let info = RandomnessManager::randomness_dkg_info_from_committee(committee);
@@ -711,6 +711,7 @@ pub trait ExecutionCacheReconfigAPI: Send + Sync {
         &self,
         old_epoch_store: &AuthorityPerEpochStore,
         epoch_supply_change: Option<i64>,
+        read_only: bool,
     ) -> IotaResult;
 
     fn checkpoint_db(&self, path: &Path) -> IotaResult;
@@ -897,11 +898,13 @@ macro_rules! implement_passthrough_traits {
                 &self,
                 old_epoch_store: &AuthorityPerEpochStore,
                 epoch_supply_change: Option<i64>,
+                read_only: bool,
             ) -> IotaResult {
                 self.store.expensive_check_iota_conservation(
                     self,
                     old_epoch_store,
                     epoch_supply_change,
+                    read_only,
                 )
             }
 
@@ -376,10 +376,13 @@ impl ExecutionCacheReconfigAPI for ProxyCache {
         &self,
         old_epoch_store: &AuthorityPerEpochStore,
         epoch_supply_change: Option<i64>,
+        read_only: bool,
     ) -> IotaResult {
-        delegate_method!(
-            self.expensive_check_iota_conservation(old_epoch_store, epoch_supply_change)
-        )
+        delegate_method!(self.expensive_check_iota_conservation(
+            old_epoch_store,
+            epoch_supply_change,
+            read_only
+        ))
     }
 
     fn checkpoint_db(&self, path: &std::path::Path) -> IotaResult {
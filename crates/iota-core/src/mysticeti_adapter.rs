@@ -11,7 +11,10 @@ use iota_types::{
     messages_consensus::{ConsensusTransaction, ConsensusTransactionKind},
 };
 use tap::prelude::*;
-use tokio::time::{Instant, sleep};
+use tokio::{
+    sync::watch,
+    time::{Instant, sleep},
+};
 use tracing::{error, info, warn};
 
 use crate::{
@@ -23,15 +26,25 @@ use crate::{
 /// Gets a client to submit transactions to Mysticeti, or waits for one to be
 /// available. This hides the complexities of async consensus initialization and
 /// submitting to different instances of consensus across epochs.
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct LazyMysticetiClient {
     client: Arc<ArcSwapOption<TransactionClient>>,
+    /// Whether the consensus instance of the client finished replaying its
+    /// locally available commits.
+    ready: Arc<watch::Sender<bool>>,
+}
+
+impl Default for LazyMysticetiClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl LazyMysticetiClient {
     pub fn new() -> Self {
         Self {
             client: Arc::new(ArcSwapOption::empty()),
+            ready: Arc::new(watch::Sender::new(false)),
         }
     }
 
@@ -65,11 +78,28 @@ impl LazyMysticetiClient {
         }
     }
 
+    /// Waits until the running consensus instance finished replaying its
+    /// locally available commits, see [`Self::set_ready`].
+    pub async fn wait_until_ready(&self) {
+        let mut ready = self.ready.subscribe();
+        ready
+            .wait_for(|ready| *ready)
+            .await
+            .expect("sender is owned by self");
+    }
+
     pub fn set(&self, client: Arc<TransactionClient>) {
         self.client.store(Some(client));
     }
 
+    /// Marks the consensus instance of the current client as ready, once it
+    /// replayed its locally available commits.
+    pub fn set_ready(&self) {
+        self.ready.send_replace(true);
+    }
+
     pub fn clear(&self) {
+        self.ready.send_replace(false);
         self.client.store(None);
     }
 }
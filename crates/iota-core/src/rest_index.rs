@@ -4,18 +4,20 @@
 
 use std::{
     collections::HashMap,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
     time::Instant,
 };
 
+use arc_swap::ArcSwap;
 use iota_types::{
     base_types::{IotaAddress, MoveObjectType, ObjectID, SequenceNumber},
     digests::TransactionDigest,
     dynamic_field::visitor as DFV,
     full_checkpoint_content::CheckpointData,
+    inner_temporary_store::PackageStoreWithFallback,
     layout_resolver::LayoutResolver,
-    messages_checkpoint::CheckpointContents,
+    messages_checkpoint::{CheckpointContents, CheckpointSequenceNumber},
     object::{Object, Owner},
     storage::{
         BackingPackageStore, DynamicFieldIndexInfo, DynamicFieldKey, error::Error as StorageError,
@@ -23,8 +25,8 @@ use iota_types::{
 };
 use move_core_types::language_storage::StructTag;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use serde::{Deserialize, Serialize};
-use tracing::{debug, info};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{debug, info, warn};
 use typed_store::{
     DBMapUtils, TypedStoreError,
     rocks::{DBMap, MetricConf},
@@ -36,11 +38,16 @@ use crate::{
         AuthorityStore, authority_per_epoch_store::AuthorityPerEpochStore,
         authority_store_tables::LiveObject,
     },
-    checkpoints::CheckpointStore,
+    checkpoints::{CheckpointStore, checkpoint_executor::load_checkpoint_data},
+    execution_cache::{ObjectCacheRead, TransactionCacheRead},
 };
 
 const CURRENT_DB_VERSION: u64 = 0;
 
+/// The number of checkpoints which a rebuild of the index may still have to
+/// catch up with while writes to the current index are blocked.
+const MAX_CHECKPOINTS_TO_INDEX_WHILE_LOCKED: u64 = 8;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 struct MetadataInfo {
     /// Version of the Database
@@ -510,40 +517,35 @@ impl IndexStoreTables {
     }
 
     fn owner_iter(
-        &self,
+        self: &Arc<Self>,
         owner: IotaAddress,
         cursor: Option<ObjectID>,
-    ) -> Result<impl Iterator<Item = (OwnerIndexKey, OwnerIndexInfo)> + '_, TypedStoreError> {
-        let lower_bound = OwnerIndexKey::new(owner, ObjectID::ZERO);
+    ) -> RangeIter<OwnerIndexKey, OwnerIndexInfo> {
+        let lower_bound = OwnerIndexKey::new(owner, cursor.unwrap_or(ObjectID::ZERO));
         let upper_bound = OwnerIndexKey::new(owner, ObjectID::MAX);
-        let mut iter = self
-            .owner
-            .iter_with_bounds(Some(lower_bound), Some(upper_bound));
 
-        if let Some(cursor) = cursor {
-            iter = iter.skip_to(&OwnerIndexKey::new(owner, cursor))?;
-        }
-
-        Ok(iter)
+        RangeIter::new(
+            self.clone(),
+            |tables| &tables.owner,
+            lower_bound,
+            upper_bound,
+        )
     }
 
     fn dynamic_field_iter(
-        &self,
+        self: &Arc<Self>,
         parent: ObjectID,
         cursor: Option<ObjectID>,
-    ) -> Result<impl Iterator<Item = (DynamicFieldKey, DynamicFieldIndexInfo)> + '_, TypedStoreError>
-    {
-        let lower_bound = DynamicFieldKey::new(parent, ObjectID::ZERO);
+    ) -> RangeIter<DynamicFieldKey, DynamicFieldIndexInfo> {
+        let lower_bound = DynamicFieldKey::new(parent, cursor.unwrap_or(ObjectID::ZERO));
         let upper_bound = DynamicFieldKey::new(parent, ObjectID::MAX);
-        let mut iter = self
-            .dynamic_field
-            .iter_with_bounds(Some(lower_bound), Some(upper_bound));
-
-        if let Some(cursor) = cursor {
-            iter = iter.skip_to(&DynamicFieldKey::new(parent, cursor))?;
-        }
 
-        Ok(iter)
+        RangeIter::new(
+            self.clone(),
+            |tables| &tables.dynamic_field,
+            lower_bound,
+            upper_bound,
+        )
     }
 
     fn get_coin_info(
@@ -557,8 +559,87 @@ impl IndexStoreTables {
     }
 }
 
+/// An iterator over a key range of one of the index tables.
+///
+/// Unlike a [`DBMap`] iterator it does not borrow the tables but keeps them
+/// alive on its own, so that a rebuild of the index can swap in new tables
+/// while it is still in use. Entries are read in batches of
+/// `RANGE_ITER_BATCH_SIZE`.
+struct RangeIter<K, V> {
+    tables: Arc<IndexStoreTables>,
+    table: fn(&IndexStoreTables) -> &DBMap<K, V>,
+    /// The inclusive lower bound of the next batch, `None` once the range is
+    /// exhausted.
+    next_key: Option<K>,
+    upper_bound: K,
+    batch: std::vec::IntoIter<(K, V)>,
+}
+
+const RANGE_ITER_BATCH_SIZE: usize = 256;
+
+impl<K, V> RangeIter<K, V> {
+    fn new(
+        tables: Arc<IndexStoreTables>,
+        table: fn(&IndexStoreTables) -> &DBMap<K, V>,
+        lower_bound: K,
+        upper_bound: K,
+    ) -> Self {
+        Self {
+            tables,
+            table,
+            next_key: Some(lower_bound),
+            upper_bound,
+            batch: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<K, V> Iterator for RangeIter<K, V>
+where
+    K: Clone + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(entry) = self.batch.next() {
+            return Some(entry);
+        }
+
+        let lower_bound = self.next_key.take()?;
+        // Read one entry more than needed, its key is where the next batch starts
+        let mut entries = (self.table)(&self.tables)
+            .iter_with_bounds(Some(lower_bound), Some(self.upper_bound.clone()))
+            .take(RANGE_ITER_BATCH_SIZE + 1)
+            .collect::<Vec<_>>();
+        if entries.len() > RANGE_ITER_BATCH_SIZE {
+            self.next_key = entries.pop().map(|(key, _)| key);
+        }
+
+        self.batch = entries.into_iter();
+        self.batch.next()
+    }
+}
+
 pub struct RestIndexStore {
-    tables: IndexStoreTables,
+    tables: ArcSwap<IndexStoreTables>,
+    /// The configured location of the index. Rebuilt indexes are stored next
+    /// to it, in directories suffixed with their generation.
+    path: PathBuf,
+    /// The lock is held by everything writing to the index, so that no
+    /// write can get lost when a rebuilt index is swapped in.
+    state: parking_lot::Mutex<IndexState>,
+}
+
+struct IndexState {
+    /// The generation of the tables in use, `0` being the ones at `path`.
+    generation: u64,
+    /// The highest checkpoint indexed since startup.
+    highest_indexed_checkpoint: Option<CheckpointSequenceNumber>,
+    /// The checkpoints pruned from the index while it is rebuilt, which have
+    /// to be pruned from the rebuilt index as well. `None` if no rebuild is in
+    /// progress.
+    pruned_during_rebuild: Option<Vec<CheckpointContents>>,
 }
 
 impl RestIndexStore {
@@ -569,17 +650,17 @@ impl RestIndexStore {
         epoch_store: &AuthorityPerEpochStore,
         package_store: &Arc<dyn BackingPackageStore + Send + Sync>,
     ) -> Self {
-        let tables = {
-            let tables = IndexStoreTables::open(&path);
+        let (generation, tables) = Self::open_latest_generation(&path, true);
 
+        let tables = {
             // If the index tables are uninitialized or on an older version then we need to
             // populate them
             if tables.needs_to_do_initialization() {
                 let mut tables = if tables.needs_to_delete_old_db() {
                     drop(tables);
-                    typed_store::rocks::safe_drop_db(path.clone())
+                    typed_store::rocks::safe_drop_db(generation_path(&path, generation))
                         .expect("unable to destroy old rest-index db");
-                    IndexStoreTables::open(path)
+                    IndexStoreTables::open(generation_path(&path, generation))
                 } else {
                     tables
                 };
@@ -598,20 +679,222 @@ impl RestIndexStore {
             }
         };
 
-        Self { tables }
+        Self::from_tables(path, generation, tables)
     }
 
     pub fn new_without_init(path: PathBuf) -> Self {
-        let tables = IndexStoreTables::open(path);
+        let (generation, tables) = Self::open_latest_generation(&path, false);
+
+        Self::from_tables(path, generation, tables)
+    }
 
-        Self { tables }
+    fn from_tables(path: PathBuf, generation: u64, tables: IndexStoreTables) -> Self {
+        Self {
+            tables: ArcSwap::from_pointee(tables),
+            path,
+            state: parking_lot::Mutex::new(IndexState {
+                generation,
+                highest_indexed_checkpoint: None,
+                pruned_during_rebuild: None,
+            }),
+        }
+    }
+
+    /// Opens the tables of the most recent rebuild which was completed, or
+    /// the ones at `path` if there is none.
+    ///
+    /// With `cleanup` set, the databases of incomplete rebuilds and the ones
+    /// superseded by the opened tables are removed.
+    fn open_latest_generation(path: &Path, cleanup: bool) -> (u64, IndexStoreTables) {
+        let mut generations = rebuilt_generations(path);
+        generations.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut latest = None;
+        for generation in generations {
+            let generation_path = generation_path(path, generation);
+            if latest.is_none() {
+                let tables = IndexStoreTables::open(&generation_path);
+                // The metadata is written last, so it is only missing if the rebuild was
+                // interrupted
+                if !tables.needs_to_do_initialization() {
+                    latest = Some((generation, tables));
+                    continue;
+                }
+            }
+            if cleanup {
+                info!(
+                    "Removing stale rest index db at {}",
+                    generation_path.display()
+                );
+                if let Err(e) = typed_store::rocks::safe_drop_db(generation_path) {
+                    warn!("Unable to remove stale rest index db: {e}");
+                }
+            }
+        }
+
+        match latest {
+            Some((generation, tables)) => {
+                if cleanup && path.exists() {
+                    info!("Removing stale rest index db at {}", path.display());
+                    if let Err(e) = typed_store::rocks::safe_drop_db(path.to_owned()) {
+                        warn!("Unable to remove stale rest index db: {e}");
+                    }
+                }
+                (generation, tables)
+            }
+            None => (0, IndexStoreTables::open(path)),
+        }
+    }
+
+    /// Rebuilds the index from the available checkpoints and the live object
+    /// set into a new database, and swaps it in once it is complete.
+    ///
+    /// The current index keeps serving reads and being written to during the
+    /// rebuild. The checkpoints executed after the rebuild started are indexed
+    /// into the new database before it is swapped in, only the last few of
+    /// them while writes to the index are blocked.
+    pub fn rebuild(
+        &self,
+        authority_store: &AuthorityStore,
+        checkpoint_store: &Arc<CheckpointStore>,
+        epoch_store: &AuthorityPerEpochStore,
+        package_store: &Arc<dyn BackingPackageStore + Send + Sync>,
+        object_cache_reader: &dyn ObjectCacheRead,
+        transaction_cache_reader: &dyn TransactionCacheRead,
+    ) -> Result<(), StorageError> {
+        let generation = {
+            let mut state = self.state.lock();
+            if state.pruned_during_rebuild.is_some() {
+                return Err(StorageError::custom("rest index is already being rebuilt"));
+            }
+            state.pruned_during_rebuild = Some(vec![]);
+            state.generation
+        };
+
+        let result = self.rebuild_generation(
+            generation,
+            authority_store,
+            checkpoint_store,
+            epoch_store,
+            package_store,
+            object_cache_reader,
+            transaction_cache_reader,
+        );
+        if result.is_err() {
+            self.state.lock().pruned_during_rebuild = None;
+        }
+        result
+    }
+
+    fn rebuild_generation(
+        &self,
+        generation: u64,
+        authority_store: &AuthorityStore,
+        checkpoint_store: &Arc<CheckpointStore>,
+        epoch_store: &AuthorityPerEpochStore,
+        package_store: &Arc<dyn BackingPackageStore + Send + Sync>,
+        object_cache_reader: &dyn ObjectCacheRead,
+        transaction_cache_reader: &dyn TransactionCacheRead,
+    ) -> Result<(), StorageError> {
+        let new_generation = generation + 1;
+        let new_path = generation_path(&self.path, new_generation);
+
+        // All checkpoints up to the watermark are reflected in the live object set
+        // which is indexed below, the later ones are indexed afterwards
+        let watermark = checkpoint_store.get_highest_executed_checkpoint_seq_number()?;
+
+        info!("Rebuilding rest index into {}", new_path.display());
+        if new_path.exists() {
+            typed_store::rocks::safe_drop_db(new_path.clone()).map_err(StorageError::custom)?;
+        }
+        let mut tables = IndexStoreTables::open(&new_path);
+        tables.init(
+            authority_store,
+            checkpoint_store,
+            epoch_store,
+            package_store,
+        )?;
+
+        let mut next_checkpoint = watermark.map_or(0, |watermark| watermark + 1);
+        let catch_up = |tables: &IndexStoreTables,
+                        next_checkpoint: &mut CheckpointSequenceNumber,
+                        highest_indexed_checkpoint: Option<CheckpointSequenceNumber>|
+         -> Result<(), StorageError> {
+            let Some(highest_indexed_checkpoint) = highest_indexed_checkpoint else {
+                return Ok(());
+            };
+            for seq in *next_checkpoint..=highest_indexed_checkpoint {
+                let checkpoint_data = load_indexed_checkpoint_data(
+                    seq,
+                    checkpoint_store,
+                    object_cache_reader,
+                    transaction_cache_reader,
+                )?;
+                let mut layout_resolver = epoch_store.executor().type_layout_resolver(Box::new(
+                    PackageStoreWithFallback::new(package_store, &checkpoint_data),
+                ));
+                tables.index_checkpoint(&checkpoint_data, layout_resolver.as_mut())?;
+                *next_checkpoint = seq + 1;
+            }
+            Ok(())
+        };
+
+        // Index the checkpoints executed in the meantime without blocking writes to
+        // the current index, until only a few of them are left
+        loop {
+            let highest_indexed_checkpoint = self.state.lock().highest_indexed_checkpoint;
+            let remaining = highest_indexed_checkpoint
+                .map_or(0, |highest| (highest + 1).saturating_sub(next_checkpoint));
+            if remaining <= MAX_CHECKPOINTS_TO_INDEX_WHILE_LOCKED {
+                break;
+            }
+            catch_up(&tables, &mut next_checkpoint, highest_indexed_checkpoint)?;
+        }
+
+        let mut state = self.state.lock();
+        catch_up(
+            &tables,
+            &mut next_checkpoint,
+            state.highest_indexed_checkpoint,
+        )?;
+        if let Some(pruned) = state.pruned_during_rebuild.take() {
+            tables.prune(&pruned)?;
+        }
+
+        let old_tables = self.tables.swap(Arc::new(tables));
+        let old_path = generation_path(&self.path, generation);
+        state.generation = new_generation;
+        drop(state);
+        info!("Rebuilt rest index is in use");
+
+        // Iterators which are still in use keep the old tables open, in which case
+        // they are only removed on the next startup
+        match Arc::into_inner(old_tables) {
+            Some(old_tables) => {
+                drop(old_tables);
+                if let Err(e) = typed_store::rocks::safe_drop_db(old_path) {
+                    warn!("Unable to remove the replaced rest index db: {e}");
+                }
+            }
+            None => warn!(
+                "The replaced rest index db at {} is still in use, it is removed on the next startup",
+                old_path.display()
+            ),
+        }
+
+        Ok(())
     }
 
     pub fn prune(
         &self,
         checkpoint_contents_to_prune: &[CheckpointContents],
     ) -> Result<(), TypedStoreError> {
-        self.tables.prune(checkpoint_contents_to_prune)
+        let mut state = self.state.lock();
+        self.tables.load().prune(checkpoint_contents_to_prune)?;
+        if let Some(pruned) = &mut state.pruned_during_rebuild {
+            pruned.extend_from_slice(checkpoint_contents_to_prune);
+        }
+        Ok(())
     }
 
     pub fn index_checkpoint(
@@ -619,14 +902,22 @@ impl RestIndexStore {
         checkpoint: &CheckpointData,
         resolver: &mut dyn LayoutResolver,
     ) -> Result<(), StorageError> {
-        self.tables.index_checkpoint(checkpoint, resolver)
+        let mut state = self.state.lock();
+        self.tables.load().index_checkpoint(checkpoint, resolver)?;
+        let seq = checkpoint.checkpoint_summary.sequence_number;
+        state.highest_indexed_checkpoint = Some(
+            state
+                .highest_indexed_checkpoint
+                .map_or(seq, |highest| highest.max(seq)),
+        );
+        Ok(())
     }
 
     pub fn get_transaction_info(
         &self,
         digest: &TransactionDigest,
     ) -> Result<Option<TransactionInfo>, TypedStoreError> {
-        self.tables.get_transaction_info(digest)
+        self.tables.load().get_transaction_info(digest)
     }
 
     pub fn owner_iter(
@@ -634,7 +925,7 @@ impl RestIndexStore {
         owner: IotaAddress,
         cursor: Option<ObjectID>,
     ) -> Result<impl Iterator<Item = (OwnerIndexKey, OwnerIndexInfo)> + '_, TypedStoreError> {
-        self.tables.owner_iter(owner, cursor)
+        Ok(self.tables.load().owner_iter(owner, cursor))
     }
 
     pub fn dynamic_field_iter(
@@ -643,15 +934,72 @@ impl RestIndexStore {
         cursor: Option<ObjectID>,
     ) -> Result<impl Iterator<Item = (DynamicFieldKey, DynamicFieldIndexInfo)> + '_, TypedStoreError>
     {
-        self.tables.dynamic_field_iter(parent, cursor)
+        Ok(self.tables.load().dynamic_field_iter(parent, cursor))
     }
 
     pub fn get_coin_info(
         &self,
         coin_type: &StructTag,
     ) -> Result<Option<CoinIndexInfo>, TypedStoreError> {
-        self.tables.get_coin_info(coin_type)
+        self.tables.load().get_coin_info(coin_type)
+    }
+}
+
+/// Loads the data of an executed checkpoint in order to index it.
+fn load_indexed_checkpoint_data(
+    seq: CheckpointSequenceNumber,
+    checkpoint_store: &Arc<CheckpointStore>,
+    object_cache_reader: &dyn ObjectCacheRead,
+    transaction_cache_reader: &dyn TransactionCacheRead,
+) -> Result<CheckpointData, StorageError> {
+    let checkpoint = checkpoint_store
+        .get_checkpoint_by_sequence_number(seq)?
+        .ok_or_else(|| StorageError::missing(format!("missing checkpoint {seq}")))?;
+    let contents = checkpoint_store
+        .get_checkpoint_contents(&checkpoint.content_digest)?
+        .ok_or_else(|| StorageError::missing(format!("missing checkpoint {seq}")))?;
+    let transaction_digests = contents
+        .iter()
+        .map(|digests| digests.transaction)
+        .collect::<Vec<_>>();
+
+    load_checkpoint_data(
+        checkpoint,
+        object_cache_reader,
+        transaction_cache_reader,
+        checkpoint_store.clone(),
+        &transaction_digests,
+    )
+    .map_err(StorageError::custom)
+}
+
+/// The location of the tables of the given generation of the index at `path`.
+fn generation_path(path: &Path, generation: u64) -> PathBuf {
+    if generation == 0 {
+        return path.to_owned();
     }
+    let mut file_name = path.file_name().unwrap_or_default().to_owned();
+    file_name.push(format!("-{generation}"));
+    path.with_file_name(file_name)
+}
+
+/// The generations of the rebuilt indexes found next to `path`.
+fn rebuilt_generations(path: &Path) -> Vec<u64> {
+    let (Some(parent), Some(file_name)) = (path.parent(), path.file_name()) else {
+        return vec![];
+    };
+    let Ok(entries) = std::fs::read_dir(parent) else {
+        return vec![];
+    };
+    let prefix = format!("{}-", file_name.to_string_lossy());
+
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            name.to_str()?.strip_prefix(&prefix)?.parse().ok()
+        })
+        .filter(|generation| *generation > 0)
+        .collect()
 }
 
 fn try_create_dynamic_field_info(
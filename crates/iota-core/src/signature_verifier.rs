@@ -25,12 +25,15 @@ use iota_types::{
 };
 use itertools::izip;
 use parking_lot::{Mutex, MutexGuard, RwLock};
-use prometheus::{IntCounter, Registry, register_int_counter_with_registry};
+use prometheus::{
+    IntCounter, IntGauge, Registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry,
+};
 use shared_crypto::intent::Intent;
 use tap::TapFallible;
 use tokio::{
     runtime::Handle,
-    sync::oneshot,
+    sync::{Semaphore, oneshot},
     time::{Duration, timeout},
 };
 use tracing::debug;
@@ -111,6 +114,9 @@ pub struct SignatureVerifier {
     zk_login_params: ZkLoginParams,
 
     queue: Mutex<CertBuffer>,
+    /// Bounds the number of certificate batches verified concurrently, if set.
+    /// The permits are shared with the checkpoint verification of state sync.
+    concurrency_limit: Option<Arc<Semaphore>>,
     pub metrics: Arc<SignatureVerifierMetrics>,
 }
 
@@ -154,6 +160,7 @@ impl SignatureVerifier {
             )),
             jwks: Default::default(),
             queue: Mutex::new(CertBuffer::new(batch_size)),
+            concurrency_limit: None,
             metrics,
             zk_login_params: ZkLoginParams {
                 env,
@@ -180,6 +187,18 @@ impl SignatureVerifier {
         )
     }
 
+    /// Limits the number of certificate batches which are verified
+    /// concurrently to the permits of `concurrency_limit`. `None` means no
+    /// limit.
+    pub fn with_concurrency_limit(mut self, concurrency_limit: Option<Arc<Semaphore>>) -> Self {
+        self.concurrency_limit = concurrency_limit;
+        self
+    }
+
+    pub fn concurrency_limit(&self) -> Option<Arc<Semaphore>> {
+        self.concurrency_limit.clone()
+    }
+
     /// Verifies all certs, returns Ok only if all are valid.
     pub fn verify_certs_and_checkpoints(
         &self,
@@ -197,6 +216,8 @@ impl SignatureVerifier {
         for cert in &certs {
             self.verify_tx(cert.data())?;
         }
+        // Consensus blocks are verified synchronously, so they are not subject to the
+        // concurrency limit, which would otherwise block the consensus threads.
         batch_verify_all_certificates_and_checkpoints(&self.committee, &certs, &checkpoints)?;
         self.certificate_cache
             .cache_digests(certs.into_iter().map(|c| c.certificate_digest()).collect());
@@ -308,12 +329,32 @@ impl SignatureVerifier {
         let committee = self.committee.clone();
         let metrics = self.metrics.clone();
         let zklogin_inputs_cache = self.zklogin_inputs_cache.clone();
+        let concurrency_limit = self.concurrency_limit.clone();
+        // The permit is acquired in a spawned task, so that the results are still
+        // sent if the caller is cancelled while waiting for it.
         Handle::current()
-            .spawn_blocking(move || {
-                Self::process_queue_sync(committee, metrics, buffer, zklogin_inputs_cache)
+            .spawn(async move {
+                let _permit = match concurrency_limit {
+                    Some(concurrency_limit) => Some(
+                        concurrency_limit
+                            .acquire_owned()
+                            .await
+                            .expect("semaphore should not be closed"),
+                    ),
+                    None => None,
+                };
+                let in_flight_batches = metrics.in_flight_certificate_batches.clone();
+                in_flight_batches.inc();
+                Handle::current()
+                    .spawn_blocking(move || {
+                        Self::process_queue_sync(committee, metrics, buffer, zklogin_inputs_cache)
+                    })
+                    .await
+                    .expect("Spawn blocking should not fail");
+                in_flight_batches.dec();
             })
             .await
-            .expect("Spawn blocking should not fail");
+            .expect("Spawn should not fail");
     }
 
     fn process_queue_sync(
@@ -413,6 +454,7 @@ pub struct SignatureVerifierMetrics {
     partial_batches: IntCounter,
     total_verified_certs: IntCounter,
     total_failed_certs: IntCounter,
+    in_flight_certificate_batches: IntGauge,
 }
 
 impl SignatureVerifierMetrics {
@@ -502,6 +544,12 @@ impl SignatureVerifierMetrics {
                 registry
             )
             .unwrap(),
+            in_flight_certificate_batches: register_int_gauge_with_registry!(
+                "async_batch_verifier_in_flight_certificate_batches",
+                "Number of certificate batches the batch verifier is currently verifying, bounded by the signature verification concurrency limit",
+                registry
+            )
+            .unwrap(),
         })
     }
 }
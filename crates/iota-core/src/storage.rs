@@ -373,7 +373,7 @@ impl RestReadStore {
 
     fn index(&self) -> iota_types::storage::error::Result<&RestIndexStore> {
         self.state.rest_index.as_deref().ok_or_else(|| {
-            iota_types::storage::error::Error::custom("rest index store is disabled")
+            iota_types::storage::error::Error::unsupported("rest index store is disabled")
         })
     }
 }
@@ -8,6 +8,7 @@ pub mod nodefw_test_server;
 pub mod policies;
 
 use std::{
+    collections::HashMap,
     fmt::Debug,
     fs,
     net::{IpAddr, Ipv4Addr},
@@ -36,6 +37,9 @@ use crate::traffic_controller::{
 
 pub const METRICS_INTERVAL_SECS: u64 = 2;
 pub const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = 300;
+/// Maximum number of client IPs for which dropped requests are counted, so
+/// that the counters can't grow without bounds under attack.
+const MAX_DROPPED_REQUESTS_CLIENTS: usize = 10_000;
 
 type Blocklist = Arc<DashMap<IpAddr, SystemTime>>;
 
@@ -51,6 +55,9 @@ pub struct TrafficController {
     blocklists: Blocklists,
     metrics: Arc<TrafficControllerMetrics>,
     dry_run_mode: bool,
+    /// Number of requests dropped per client IP, only blocked IPs are
+    /// recorded, up to `MAX_DROPPED_REQUESTS_CLIENTS` of them.
+    dropped_requests: Arc<DashMap<IpAddr, u64>>,
 }
 
 impl Debug for TrafficController {
@@ -101,6 +108,7 @@ impl TrafficController {
             },
             metrics: metrics.clone(),
             dry_run_mode: policy_config.dry_run,
+            dropped_requests: Arc::new(DashMap::new()),
         };
         let tally_loop_blocklists = ret.blocklists.clone();
         let clear_loop_blocklists = ret.blocklists.clone();
@@ -168,10 +176,30 @@ impl TrafficController {
                 true
             }
             // check failed
-            (false, false) => false,
+            (false, false) => {
+                if let Some(ip) = proxied_client.or(*client) {
+                    if let Some(mut count) = self.dropped_requests.get_mut(&ip) {
+                        *count += 1;
+                    } else if self.dropped_requests.len() < MAX_DROPPED_REQUESTS_CLIENTS {
+                        *self.dropped_requests.entry(ip).or_default() += 1;
+                    }
+                }
+                false
+            }
         }
     }
 
+    /// Returns the number of requests dropped so far per client IP. For
+    /// proxied requests the proxied client IP is used. Clients are only
+    /// tracked up to `MAX_DROPPED_REQUESTS_CLIENTS`, requests of further
+    /// clients are not counted.
+    pub fn dropped_requests(&self) -> HashMap<IpAddr, u64> {
+        self.dropped_requests
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect()
+    }
+
     /// Returns true if the connection is allowed, false if it is blocked
     pub async fn check_impl(
         &self,
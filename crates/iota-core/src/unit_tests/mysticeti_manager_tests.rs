@@ -11,7 +11,10 @@ use iota_types::messages_checkpoint::{
     CertifiedCheckpointSummary, CheckpointContents, CheckpointSummary,
 };
 use prometheus::Registry;
-use tokio::{sync::mpsc, time::sleep};
+use tokio::{
+    sync::mpsc,
+    time::{sleep, timeout},
+};
 
 use crate::{
     authority::{AuthorityState, test_authority_builder::TestAuthorityBuilder},
@@ -77,7 +80,7 @@ async fn test_mysticeti_manager() {
         consensus_config.db_path().to_path_buf(),
         registry_service,
         metrics,
-        client,
+        client.clone(),
     );
 
     let boot_counter = *manager.boot_counter.lock().await;
@@ -106,6 +109,9 @@ async fn test_mysticeti_manager() {
 
         // THEN
         assert!(manager.is_running().await);
+        timeout(Duration::from_secs(1), client.wait_until_ready())
+            .await
+            .expect("consensus should be ready after it started");
         let boot_counter = *manager.boot_counter.lock().await;
         if i == 1 || i == 2 {
             assert_eq!(boot_counter, 0);
@@ -133,5 +139,11 @@ async fn test_mysticeti_manager() {
 
         // THEN
         assert!(!manager.is_running().await);
+        assert!(
+            timeout(Duration::from_millis(100), client.wait_until_ready())
+                .await
+                .is_err(),
+            "consensus should not be ready after it was shut down"
+        );
     }
 }
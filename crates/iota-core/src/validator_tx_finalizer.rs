@@ -4,7 +4,15 @@
 
 #[cfg(any(msim, test))]
 use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
-use std::{cmp::min, ops::Add, sync::Arc, time::Duration};
+use std::{
+    cmp::min,
+    ops::Add,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use arc_swap::ArcSwap;
 use iota_metrics::LATENCY_SEC_BUCKETS;
@@ -135,6 +143,9 @@ pub struct ValidatorTxFinalizer<C: Clone> {
     name: AuthorityName,
     config: Arc<ValidatorTxFinalizerConfig>,
     metrics: Arc<ValidatorTxFinalizerMetrics>,
+    /// Whether signed transactions are finalized. Can be switched off at
+    /// runtime to reduce the load on the validator.
+    enabled: AtomicBool,
 }
 
 impl<C: Clone> ValidatorTxFinalizer<C> {
@@ -148,9 +159,28 @@ impl<C: Clone> ValidatorTxFinalizer<C> {
             name,
             config: Arc::new(ValidatorTxFinalizerConfig::default()),
             metrics: Arc::new(ValidatorTxFinalizerMetrics::new(registry)),
+            enabled: AtomicBool::new(true),
         }
     }
 
+    /// Pauses or resumes the finalization of signed transactions. Transactions
+    /// which are already waiting for their finalization delay are skipped too
+    /// while paused.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Resumes the finalization of signed transactions. The finalizer is only
+    /// created if it is enabled in the node config, so this restores the
+    /// configured state.
+    pub fn reset_enabled(&self) {
+        self.set_enabled(true);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
     #[cfg(test)]
     pub(crate) fn new_for_testing(
         agg: Arc<ArcSwap<AuthorityAggregator<C>>>,
@@ -201,6 +231,10 @@ where
         tx: VerifiedSignedTransaction,
     ) -> anyhow::Result<bool> {
         let tx_digest = *tx.digest();
+        if !self.is_enabled() {
+            trace!(?tx_digest, "Validator tx finalizer is paused");
+            return Ok(false);
+        }
         let Some(tx_finalization_delay) = self.determine_finalization_delay(&tx_digest) else {
             return Ok(false);
         };
@@ -214,6 +248,13 @@ where
                 return Ok(false);
             }
         }
+        if !self.is_enabled() {
+            trace!(
+                ?tx_digest,
+                "Validator tx finalizer was paused while waiting"
+            );
+            return Ok(false);
+        }
 
         self.metrics
             .validator_tx_finalizer_attempt_delay
@@ -7,11 +7,17 @@ use iota_json_rpc_api::BridgeReadApiClient;
 use iota_macros::sim_test;
 use iota_types::{
     IOTA_BRIDGE_OBJECT_ID,
-    bridge::{BridgeTrait, get_bridge},
+    bridge::{BridgeTrait, TOKEN_ID_BTC, TOKEN_ID_ETH, TOKEN_ID_USDC, TOKEN_ID_USDT, get_bridge},
     crypto::get_key_pair,
 };
 use test_cluster::TestClusterBuilder;
 
+fn bridge_authority_keys(count: usize) -> Vec<BridgeAuthorityKeyPair> {
+    (0..count)
+        .map(|_| get_key_pair::<BridgeAuthorityKeyPair>().1)
+        .collect()
+}
+
 #[sim_test]
 #[ignore = "https://github.com/iotaledger/iota/issues/3224"]
 async fn test_create_bridge_state_object() {
@@ -108,3 +114,87 @@ async fn test_bridge_api_compatibility() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+#[ignore = "https://github.com/iotaledger/iota/issues/3224"]
+async fn test_bridge_committee_members() {
+    let test_cluster = TestClusterBuilder::new()
+        .with_protocol_version(BRIDGE_ENABLE_PROTOCOL_VERSION.into())
+        .build_with_bridge(bridge_authority_keys(4), false)
+        .await;
+    test_cluster
+        .trigger_reconfiguration_if_not_yet_and_assert_bridge_committee_initialized()
+        .await;
+
+    // Every member is reported with the url it registered on chain.
+    let summary = test_cluster.get_bridge_summary().await.unwrap();
+    let members = test_cluster.bridge_committee_members();
+    assert_eq!(members.len(), summary.committee.members.len());
+    for (name, url) in members {
+        let address = test_cluster
+            .swarm
+            .node(&name)
+            .unwrap()
+            .config()
+            .iota_address();
+        let (_, member) = summary
+            .committee
+            .members
+            .iter()
+            .find(|(_, member)| member.iota_address == address)
+            .unwrap_or_else(|| panic!("validator {name} is not a bridge committee member"));
+        assert_eq!(member.http_rest_url, url.as_bytes());
+    }
+
+    // A cluster without a bridge has no bridge committee members.
+    let test_cluster = TestClusterBuilder::new().build().await;
+    assert!(test_cluster.bridge_committee_members().is_empty());
+}
+
+#[tokio::test]
+#[ignore = "https://github.com/iotaledger/iota/issues/3224"]
+async fn test_add_bridge_tokens() {
+    let test_cluster = TestClusterBuilder::new()
+        .with_protocol_version(BRIDGE_ENABLE_PROTOCOL_VERSION.into())
+        .build_with_bridge(bridge_authority_keys(4), false)
+        .await;
+    test_cluster
+        .trigger_reconfiguration_if_not_yet_and_assert_bridge_committee_initialized()
+        .await;
+
+    test_cluster
+        .add_bridge_tokens(
+            vec![
+                "../../bridge/move/tokens/btc".into(),
+                "../../bridge/move/tokens/eth".into(),
+            ],
+            vec![TOKEN_ID_BTC, TOKEN_ID_ETH],
+            vec![500_000_000, 30_000_000],
+            0,
+        )
+        .await;
+
+    let summary = test_cluster.get_bridge_summary().await.unwrap();
+    for (token_id, price) in [(TOKEN_ID_BTC, 500_000_000), (TOKEN_ID_ETH, 30_000_000)] {
+        let (_, metadata) = summary
+            .treasury
+            .supported_tokens
+            .iter()
+            .find(|(_, metadata)| metadata.id == token_id)
+            .unwrap_or_else(|| panic!("token {token_id} is not registered on the bridge"));
+        assert_eq!(metadata.notional_value, price);
+    }
+}
+
+#[tokio::test]
+#[ignore = "https://github.com/iotaledger/iota/issues/3224"]
+async fn test_assert_bridge_ready() {
+    let test_cluster = TestClusterBuilder::new()
+        .with_protocol_version(BRIDGE_ENABLE_PROTOCOL_VERSION.into())
+        .build_with_bridge(bridge_authority_keys(4), true)
+        .await;
+
+    test_cluster
+        .assert_bridge_ready(&[TOKEN_ID_BTC, TOKEN_ID_ETH, TOKEN_ID_USDC, TOKEN_ID_USDT])
+        .await;
+}
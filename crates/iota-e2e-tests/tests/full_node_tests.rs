@@ -6,14 +6,18 @@ use std::{path::PathBuf, sync::Arc};
 
 use futures::future;
 use iota::client_commands::{IotaClientCommandResult, IotaClientCommands, OptsWithGas};
-use iota_config::node::RunWithRange;
+use iota_config::{
+    node::{RunWithRange, StateArchiveConfig},
+    object_storage_config::{ObjectStoreConfig, ObjectStoreType},
+};
 use iota_json_rpc_types::{
     EventFilter, EventPage, IotaEvent, IotaExecutionStatus, IotaTransactionBlockEffectsAPI,
     IotaTransactionBlockResponse, IotaTransactionBlockResponseOptions, TransactionFilter,
 };
 use iota_keys::keystore::AccountKeystore;
 use iota_macros::*;
-use iota_node::IotaNodeHandle;
+use iota_metrics::RegistryService;
+use iota_node::{ArchivalNode, IotaNodeHandle};
 use iota_sdk::wallet_context::WalletContext;
 use iota_storage::{
     key_value_store::TransactionKeyValueStore, key_value_store_metrics::KeyValueStoreMetrics,
@@ -44,6 +48,7 @@ use iota_types::{
 };
 use jsonrpsee::{core::client::ClientT, rpc_params};
 use move_core_types::{annotated_value::MoveStructLayout, ident_str};
+use prometheus::Registry;
 use rand::rngs::OsRng;
 use test_cluster::TestClusterBuilder;
 use tokio::{
@@ -1428,3 +1433,66 @@ async fn publish_init_events_without_local_execution() {
         .unwrap();
     assert_eq!(response.events.unwrap().data.len(), 1);
 }
+
+#[sim_test]
+async fn test_archival_only_node_keeps_archiving() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new()
+        .with_epoch_duration_ms(5000)
+        .build()
+        .await;
+
+    let archive_dir = tempfile::tempdir()?;
+    let archive_store_config = ObjectStoreConfig {
+        object_store: Some(ObjectStoreType::File),
+        directory: Some(archive_dir.path().to_path_buf()),
+        ..Default::default()
+    };
+    let mut config = test_cluster
+        .fullnode_config_builder()
+        .build(&mut OsRng, test_cluster.swarm.config());
+    config.archival_only = true;
+    config.state_archive_write_config = StateArchiveConfig {
+        object_store_config: Some(archive_store_config.clone()),
+        concurrency: 5,
+        use_for_pruning_watermark: false,
+    };
+    // An archival-only node is not an `IotaNode`, so it is not started by the
+    // swarm. In the simulator it needs a node of its own to bind its address.
+    #[cfg(msim)]
+    let _archival_node = {
+        let ip = config.network_address.to_socket_addr()?.ip();
+        iota_simulator::runtime::Handle::current()
+            .create_node()
+            .ip(ip)
+            .name("archival")
+            .init(move || {
+                let config = config.clone();
+                async move {
+                    let _node = ArchivalNode::start(config, RegistryService::new(Registry::new()))
+                        .await
+                        .unwrap();
+                    std::future::pending::<()>().await
+                }
+            })
+            .build()
+    };
+    #[cfg(not(msim))]
+    let _archival_node = ArchivalNode::start(config, RegistryService::new(Registry::new())).await?;
+
+    // Archive files are uploaded at the end of each epoch, so the manifest has to
+    // advance to later epochs while the node keeps syncing checkpoints.
+    let remote_store = archive_store_config.make()?;
+    tokio::time::timeout(Duration::from_secs(120), async {
+        loop {
+            if let Ok(manifest) = iota_archival::read_manifest(remote_store.clone()).await {
+                if manifest.epoch_num() >= 2 {
+                    break;
+                }
+            }
+            sleep(Duration::from_secs(1)).await;
+        }
+    })
+    .await
+    .expect("the archival-only node did not archive the checkpoints of later epochs");
+    Ok(())
+}
@@ -466,6 +466,7 @@ mod sim_only_tests {
         TestClusterBuilder::new()
             .with_epoch_duration_ms(20000)
             .with_objects([iota_system_package_object(from)])
+            .with_system_package_override()
             .with_supported_protocol_versions(SupportedProtocolVersions::new_for_testing(
                 START, FINISH,
             ))
@@ -681,6 +682,7 @@ mod sim_only_tests {
         let test_cluster = TestClusterBuilder::new()
             .with_epoch_duration_ms(20000)
             .with_objects([iota_system_package_object("base")])
+            .with_system_package_override()
             .with_supported_protocol_versions(SupportedProtocolVersions::new_for_testing(
                 START, START,
             ))
@@ -789,6 +791,7 @@ mod sim_only_tests {
             .with_epoch_duration_ms(20000)
             // Overrides with a iota system package that would abort during epoch change txn
             .with_objects([iota_system_package_object("mock_iota_systems/safe_mode")])
+            .with_system_package_override()
             .with_supported_protocol_versions(SupportedProtocolVersions::new_for_testing(
                 START, FINISH,
             ))
@@ -844,6 +847,7 @@ mod sim_only_tests {
                 START, START,
             ))
             .with_objects([iota_system_package_object("mock_iota_systems/base")])
+            .with_system_package_override()
             .build()
             .await;
         // Make sure we can survive at least one epoch.
@@ -865,6 +869,7 @@ mod sim_only_tests {
                 START, FINISH,
             ))
             .with_objects([iota_system_package_object("mock_iota_systems/base")])
+            .with_system_package_override()
             .build()
             .await;
         // Wait for the upgrade to finish. After the upgrade, the new framework will be
@@ -902,6 +907,7 @@ mod sim_only_tests {
                 START, FINISH,
             ))
             .with_objects([iota_system_package_object("mock_iota_systems/base")])
+            .with_system_package_override()
             .build()
             .await;
         // Wait for the upgrade to finish. After the upgrade, the new framework will be
@@ -2,6 +2,9 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
+use iota_json_rpc_types::IotaTransactionBlockEffectsAPI;
 use iota_macros::sim_test;
 use iota_rest_api::{Client, ExecuteTransactionQueryParameters, client::BalanceChange};
 use iota_test_transaction_builder::make_transfer_iota_transaction;
@@ -52,3 +55,68 @@ async fn execute_transaction_transfer() {
 
     assert_eq!(actual, expected);
 }
+
+#[sim_test]
+async fn rebuild_rest_index_while_executing_transactions() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let recipient = IotaAddress::random_for_testing_only();
+
+    let state = test_cluster
+        .fullnode_handle
+        .iota_node
+        .with(|node| node.state());
+    let rest_index = state
+        .rest_index
+        .clone()
+        .expect("rest index is enabled on fullnodes");
+
+    let rebuild = tokio::task::spawn_blocking({
+        let state = state.clone();
+        let rest_index = rest_index.clone();
+        move || {
+            let epoch_store = state.load_epoch_store_one_call_per_task().clone();
+            rest_index.rebuild(
+                &state.database_for_testing(),
+                state.get_checkpoint_store(),
+                &epoch_store,
+                state.get_backing_package_store(),
+                state.get_object_cache_reader().as_ref(),
+                state.get_transaction_cache_reader().as_ref(),
+            )
+        }
+    });
+
+    let test_cluster = &test_cluster;
+    let transfer = move || async move {
+        let txn =
+            make_transfer_iota_transaction(&test_cluster.wallet, Some(recipient), Some(1)).await;
+        let effects = test_cluster.execute_transaction(txn).await.effects.unwrap();
+        effects.created()[0].object_id()
+    };
+
+    let mut transferred = vec![];
+    for _ in 0..3 {
+        transferred.push(transfer().await);
+    }
+    rebuild.await.unwrap().unwrap();
+    // Executed once the rebuilt index is in use
+    transferred.push(transfer().await);
+    transferred.sort();
+
+    tokio::time::timeout(Duration::from_secs(60), async {
+        loop {
+            let mut owned = rest_index
+                .owner_iter(recipient, None)
+                .unwrap()
+                .map(|(key, _)| key.object_id)
+                .collect::<Vec<_>>();
+            owned.sort();
+            if owned == transferred {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("the rebuilt rest index is missing transferred objects");
+}
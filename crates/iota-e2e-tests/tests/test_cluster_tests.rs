@@ -0,0 +1,340 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, time::Duration};
+
+use iota_macros::sim_test;
+use iota_test_transaction_builder::{
+    TestTransactionBuilder, batch_make_transfer_transactions, make_transfer_iota_transaction,
+};
+use iota_types::{
+    IOTA_SYSTEM_PACKAGE_ID,
+    base_types::AuthorityName,
+    digests::TransactionDigest,
+    iota_system_state::{IOTA_SYSTEM_MODULE_NAME, IotaSystemStateTrait},
+    messages_consensus::AuthorityCapabilitiesV1,
+    transaction::CallArg,
+};
+use test_cluster::{TestCluster, TestClusterBuilder};
+use tokio::time::timeout;
+
+/// Waits until validator `name` advertised capabilities for which `predicate`
+/// holds.
+async fn wait_for_advertised_capabilities(
+    test_cluster: &TestCluster,
+    name: &AuthorityName,
+    predicate: impl Fn(&AuthorityCapabilitiesV1) -> bool,
+) -> AuthorityCapabilitiesV1 {
+    timeout(Duration::from_secs(30), async {
+        loop {
+            if let Some(capabilities) = test_cluster
+                .get_advertised_capabilities(name)
+                .filter(&predicate)
+            {
+                return capabilities;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("validator did not advertise the expected capabilities")
+}
+
+/// Returns the digests of the transactions in checkpoint `sequence_number`, as
+/// executed by the fullnode.
+fn checkpoint_transactions(
+    test_cluster: &TestCluster,
+    sequence_number: u64,
+) -> Vec<TransactionDigest> {
+    test_cluster.fullnode_handle.iota_node.with(|node| {
+        let checkpoint_store = node.state().get_checkpoint_store().clone();
+        let checkpoint = checkpoint_store
+            .get_checkpoint_by_sequence_number(sequence_number)
+            .unwrap()
+            .expect("checkpoint should exist");
+        checkpoint_store
+            .get_checkpoint_contents(&checkpoint.content_digest)
+            .unwrap()
+            .expect("checkpoint contents should exist")
+            .iter()
+            .map(|digests| digests.transaction)
+            .collect()
+    })
+}
+
+#[sim_test]
+async fn export_and_restore_state() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let digest = {
+        let test_cluster = TestClusterBuilder::new().build().await;
+        let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+        let digest = test_cluster.execute_transaction(tx).await.digest;
+        test_cluster.export_state(dir.path()).await.unwrap();
+        digest
+    };
+
+    let test_cluster = TestClusterBuilder::from_exported_state(dir.path())
+        .unwrap()
+        .build()
+        .await;
+
+    // The restored cluster continues from the exported state.
+    test_cluster
+        .fullnode_handle
+        .iota_node
+        .state()
+        .get_transaction_cache_reader()
+        .notify_read_executed_effects(&[digest])
+        .await
+        .unwrap();
+
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    test_cluster.execute_transaction(tx).await;
+}
+
+#[sim_test]
+async fn wipe_and_restart_validator() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    let digest = test_cluster.execute_transaction(tx).await.digest;
+
+    let name = test_cluster.get_validator_pubkeys()[0];
+    test_cluster.wipe_and_restart_node(&name).await;
+
+    // The wiped validator re-executes the transaction from the synced checkpoints.
+    let handle = test_cluster
+        .swarm
+        .node(&name)
+        .unwrap()
+        .get_node_handle()
+        .unwrap();
+    handle
+        .state()
+        .get_transaction_cache_reader()
+        .notify_read_executed_effects(&[digest])
+        .await
+        .unwrap();
+
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    test_cluster.execute_transaction(tx).await;
+}
+
+#[sim_test]
+async fn stop_validators_and_assert_liveness() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let name = test_cluster.get_validator_pubkeys()[0];
+
+    // A duplicated validator only holds its stake once, so the remaining three
+    // validators still form a quorum.
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    test_cluster
+        .stop_validators_and_assert_liveness(&[name, name], tx)
+        .await;
+
+    assert!(test_cluster.swarm.node(&name).unwrap().is_running());
+}
+
+#[sim_test]
+async fn get_advertised_capabilities() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let name = test_cluster.get_validator_pubkeys()[0];
+
+    let capabilities = wait_for_advertised_capabilities(&test_cluster, &name, |_| true).await;
+    assert_eq!(capabilities.authority, name);
+    assert!(!capabilities.available_system_packages.is_empty());
+
+    // The capabilities are advertised again at the start of every epoch.
+    test_cluster.force_new_epoch().await;
+    wait_for_advertised_capabilities(&test_cluster, &name, |next| {
+        next.generation > capabilities.generation
+    })
+    .await;
+}
+
+#[sim_test]
+async fn execute_and_get_checkpoint() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+
+    let (response, checkpoint) = test_cluster.execute_and_get_checkpoint(tx).await;
+
+    assert!(checkpoint_transactions(&test_cluster, checkpoint).contains(&response.digest));
+}
+
+#[sim_test]
+async fn validator_gas_price_and_commission_rate() {
+    let test_cluster = TestClusterBuilder::new()
+        .with_validator_gas_price(1, 2_000)
+        .with_validator_commission_rate(1, 500)
+        .build()
+        .await;
+
+    let address = test_cluster.swarm.config().validator_configs()[1].iota_address();
+    let system_state = test_cluster.fullnode_handle.iota_node.with(|node| {
+        node.state()
+            .get_iota_system_state_object_for_testing()
+            .unwrap()
+            .into_iota_system_state_summary()
+    });
+    let validator = system_state
+        .iter_active_validators()
+        .find(|validator| validator.iota_address == address)
+        .expect("validator should be active");
+    assert_eq!(validator.gas_price, 2_000);
+    assert_eq!(validator.commission_rate, 500);
+}
+
+#[cfg(msim)]
+#[sim_test]
+async fn set_clock_offset() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let checkpoint_timestamp_ms = |sequence_number| {
+        test_cluster.fullnode_handle.iota_node.with(|node| {
+            node.state()
+                .get_checkpoint_store()
+                .get_checkpoint_by_sequence_number(sequence_number)
+                .unwrap()
+                .expect("checkpoint should exist")
+                .timestamp_ms
+        })
+    };
+
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    let (_, before) = test_cluster.execute_and_get_checkpoint(tx).await;
+
+    // Shifting the clocks of all validators keeps them in sync with each other,
+    // while the timestamps of new checkpoints jump ahead.
+    let offset = Duration::from_secs(3600);
+    for name in test_cluster.get_validator_pubkeys() {
+        test_cluster.set_clock_offset(&name, offset);
+    }
+
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    let (_, after) = test_cluster.execute_and_get_checkpoint(tx).await;
+    assert!(
+        checkpoint_timestamp_ms(after)
+            >= checkpoint_timestamp_ms(before) + offset.as_millis() as u64
+    );
+}
+
+#[sim_test]
+async fn advance_epochs_until_system_state() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let config = &test_cluster.swarm.config().validator_configs()[0];
+    let address = config.iota_address();
+    let gas = test_cluster
+        .wallet
+        .get_one_gas_object_owned_by_address(address)
+        .await
+        .unwrap()
+        .unwrap();
+    let tx =
+        TestTransactionBuilder::new(address, gas, test_cluster.get_reference_gas_price().await)
+            .move_call(
+                IOTA_SYSTEM_PACKAGE_ID,
+                IOTA_SYSTEM_MODULE_NAME.as_str(),
+                "request_set_commission_rate",
+                vec![
+                    CallArg::IOTA_SYSTEM_MUT,
+                    CallArg::Pure(bcs::to_bytes(&500u64).unwrap()),
+                ],
+            )
+            .build_and_sign(config.account_key_pair.keypair());
+
+    // The new commission rate takes effect at the next epoch boundary.
+    let system_state = test_cluster
+        .advance_epochs_until_system_state(vec![tx], 2, |system_state| {
+            system_state
+                .clone()
+                .into_iota_system_state_summary()
+                .iter_active_validators()
+                .any(|validator| {
+                    validator.iota_address == address && validator.commission_rate == 500
+                })
+        })
+        .await;
+    assert_eq!(system_state.epoch(), 1);
+}
+
+#[sim_test]
+async fn assert_transaction_not_executed() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+
+    test_cluster.assert_transaction_not_executed(tx.digest());
+}
+
+#[tokio::test]
+#[should_panic(expected = "was executed by")]
+async fn assert_transaction_not_executed_panics_once_executed() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let tx = make_transfer_iota_transaction(&test_cluster.wallet, None, None).await;
+    let digest = test_cluster.execute_transaction(tx).await.digest;
+
+    test_cluster.assert_transaction_not_executed(&digest);
+}
+
+#[sim_test]
+async fn measure_finality() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let txs = batch_make_transfer_transactions(&test_cluster.wallet, 10).await;
+
+    let stats = test_cluster.measure_finality(txs).await;
+
+    assert!(stats.min > Duration::ZERO);
+    assert!(stats.min <= stats.median);
+    assert!(stats.median <= stats.p95);
+    assert!(stats.p95 <= stats.max);
+}
+
+#[sim_test]
+async fn execute_and_get_committed_order() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let txs = batch_make_transfer_transactions(&test_cluster.wallet, 10).await;
+    let digests = txs.iter().map(|tx| *tx.digest()).collect::<HashSet<_>>();
+
+    let order = test_cluster.execute_and_get_committed_order(txs).await;
+
+    assert_eq!(order.len(), digests.len());
+    assert_eq!(order.iter().copied().collect::<HashSet<_>>(), digests);
+    // The transactions are ordered by the checkpoints which contain them.
+    let checkpoints = test_cluster.fullnode_handle.iota_node.with(|node| {
+        node.state()
+            .get_checkpoint_cache()
+            .multi_get_transactions_perpetual_checkpoints(&order)
+            .unwrap()
+            .into_iter()
+            .map(|checkpoint| checkpoint.unwrap().1)
+            .collect::<Vec<_>>()
+    });
+    assert!(checkpoints.is_sorted());
+}
+
+#[sim_test]
+async fn protocol_version_timeline() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let protocol_version = test_cluster.highest_protocol_version().as_u64();
+    test_cluster.force_new_epoch().await;
+    test_cluster.force_new_epoch().await;
+
+    let timeline = timeout(Duration::from_secs(30), async {
+        loop {
+            let timeline = test_cluster.protocol_version_timeline();
+            if timeline.len() >= 3 {
+                return timeline;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("epoch changes were not recorded");
+    assert_eq!(
+        timeline,
+        vec![
+            (0, protocol_version),
+            (1, protocol_version),
+            (2, protocol_version)
+        ]
+    );
+}
@@ -234,7 +234,7 @@ async fn test_validator_traffic_control_error_blocked() -> Result<(), anyhow::Er
         .with_policy_config(Some(policy_config))
         .build();
     let committee = network_config.committee_with_network();
-    let _test_cluster = TestClusterBuilder::new()
+    let test_cluster = TestClusterBuilder::new()
         .set_network_config(network_config)
         .build()
         .await;
@@ -242,7 +242,7 @@ async fn test_validator_traffic_control_error_blocked() -> Result<(), anyhow::Er
         &committee,
         &default_iota_network_config(),
     );
-    let (_, auth_client) = local_clients.first_key_value().unwrap();
+    let (name, auth_client) = local_clients.first_key_value().unwrap();
 
     // transaction signed using user wallet from a different chain/genesis,
     // therefore we should fail with UserInputError
@@ -256,6 +256,11 @@ async fn test_validator_traffic_control_error_blocked() -> Result<(), anyhow::Er
         let response = auth_client.handle_transaction(tx.clone(), None).await;
         if let Err(err) = response {
             if err.to_string().contains("Too many requests") {
+                // The blocked request must have been counted as dropped.
+                let dropped_requests = test_cluster
+                    .get_traffic_controller_dropped_requests(name)
+                    .await;
+                assert!(dropped_requests.values().sum::<u64>() > 0);
                 return Ok(());
             }
         }
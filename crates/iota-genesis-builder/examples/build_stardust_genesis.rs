@@ -60,6 +60,7 @@ fn main() -> anyhow::Result<()> {
     let GenesisBuildEffects {
         genesis,
         migration_tx_data,
+        ..
     } = builder.build();
     // Save to file
     genesis.save("genesis.blob")?;
@@ -0,0 +1,34 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Comparing two token distribution schedule CSV files.
+
+use std::{fs::File, path::PathBuf};
+
+use anyhow::Context;
+use clap::Parser;
+use iota_config::genesis::TokenDistributionSchedule;
+use iota_genesis_builder::token_distribution_diff::TokenDistributionScheduleDiff;
+
+#[derive(Parser, Debug)]
+#[command(about = "Report the differences between two token distribution schedule CSV files")]
+struct Cli {
+    #[arg(help = "Path to the old token distribution schedule CSV file")]
+    old: PathBuf,
+    #[arg(help = "Path to the new token distribution schedule CSV file")]
+    new: PathBuf,
+}
+
+fn read_schedule(path: &PathBuf) -> anyhow::Result<TokenDistributionSchedule> {
+    TokenDistributionSchedule::from_csv(File::open(path)?)
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let old = read_schedule(&cli.old)?;
+    let new = read_schedule(&cli.new)?;
+
+    print!("{}", TokenDistributionScheduleDiff::new(&old, &new));
+    Ok(())
+}
@@ -1,18 +1,59 @@
 // Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_config::{genesis::Genesis, migration_tx_data::MigrationTxData};
+use iota_config::{
+    genesis::{Genesis, TokenDistributionSchedule},
+    migration_tx_data::MigrationTxData,
+};
+use iota_types::digests::{ChainIdentifier, CheckpointDigest};
+use serde::{Deserialize, Serialize};
 
 pub struct GenesisBuildEffects {
     pub genesis: Genesis,
     pub migration_tx_data: Option<MigrationTxData>,
+    pub token_distribution_schedule: TokenDistributionSchedule,
 }
 
 impl GenesisBuildEffects {
-    pub fn new(genesis: Genesis, migration_tx_data: Option<MigrationTxData>) -> Self {
+    pub fn new(
+        genesis: Genesis,
+        migration_tx_data: Option<MigrationTxData>,
+        token_distribution_schedule: TokenDistributionSchedule,
+    ) -> Self {
         Self {
             genesis,
             migration_tx_data,
+            token_distribution_schedule,
         }
     }
+
+    /// Summarizes what the built genesis contains, e.g. to be published
+    /// together with the genesis blob.
+    pub fn summary(&self) -> anyhow::Result<GenesisSummary> {
+        let checkpoint_digest = *self.genesis.checkpoint().digest();
+        Ok(GenesisSummary {
+            validator_count: self.genesis.validator_set_for_tooling().len(),
+            total_supply_nanos: self.genesis.iota_total_supply()?,
+            allocation_count: self.token_distribution_schedule.allocations.len(),
+            migration_transaction_count: self
+                .migration_tx_data
+                .as_ref()
+                .map_or(0, |data| data.txs_data().len()),
+            checkpoint_digest,
+            chain_identifier: ChainIdentifier::from(checkpoint_digest),
+        })
+    }
+}
+
+/// The key facts of a built genesis.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct GenesisSummary {
+    pub validator_count: usize,
+    pub total_supply_nanos: u64,
+    /// The number of allocations of the token distribution schedule.
+    pub allocation_count: usize,
+    pub migration_transaction_count: usize,
+    pub checkpoint_digest: CheckpointDigest,
+    pub chain_identifier: ChainIdentifier,
 }
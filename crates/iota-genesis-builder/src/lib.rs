@@ -3,17 +3,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap, hash_map::Entry},
     fs::{self, File},
     io::{BufReader, BufWriter, prelude::Read},
     path::{Path, PathBuf},
     str::FromStr,
     sync::Arc,
+    time::Duration,
 };
 
-use anyhow::{Context, bail};
+use anyhow::{Context, bail, ensure};
 use camino::Utf8Path;
 use fastcrypto::{hash::HashFunction, traits::KeyPair};
+use fastcrypto_zkp::bn254::zk_login::{JWK, JwkId};
 use flate2::bufread::GzDecoder;
 use genesis_build_effects::GenesisBuildEffects;
 use iota_config::{
@@ -30,14 +32,18 @@ use iota_genesis_common::{execute_genesis_transaction, get_genesis_protocol_conf
 use iota_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
 use iota_sdk::Url;
 use iota_types::{
-    BRIDGE_ADDRESS, IOTA_BRIDGE_OBJECT_ID, IOTA_FRAMEWORK_PACKAGE_ID, IOTA_SYSTEM_ADDRESS,
+    BRIDGE_ADDRESS, IOTA_AUTHENTICATOR_STATE_OBJECT_ID, IOTA_BRIDGE_OBJECT_ID,
+    IOTA_FRAMEWORK_PACKAGE_ID, IOTA_SYSTEM_ADDRESS,
+    authenticator_state::{
+        AUTHENTICATOR_STATE_MODULE_NAME, AUTHENTICATOR_STATE_UPDATE_FUNCTION_NAME, ActiveJwk,
+    },
     balance::{BALANCE_MODULE_NAME, Balance},
     base_types::{
         ExecutionDigests, IotaAddress, ObjectID, ObjectRef, SequenceNumber, TransactionDigest,
         TxContext,
     },
     bridge::{BRIDGE_CREATE_FUNCTION_NAME, BRIDGE_MODULE_NAME, BridgeChainId},
-    committee::Committee,
+    committee::{Committee, CommitteeTrait, StakeUnit},
     crypto::{
         AuthorityKeyPair, AuthorityPublicKeyBytes, AuthoritySignInfo, AuthoritySignInfoTrait,
         AuthoritySignature, DefaultHash, IotaAuthoritySignature,
@@ -60,6 +66,7 @@ use iota_types::{
         CheckpointVersionSpecificData, CheckpointVersionSpecificDataV1,
     },
     metrics::LimitsMetrics,
+    multiaddr::Multiaddr,
     object::{Object, Owner},
     programmable_transaction_builder::ProgrammableTransactionBuilder,
     randomness_state::{RANDOMNESS_MODULE_NAME, RANDOMNESS_STATE_CREATE_FUNCTION_NAME},
@@ -85,6 +92,7 @@ use validator_info::{GenesisValidatorInfo, GenesisValidatorMetadata, ValidatorIn
 pub mod genesis_build_effects;
 mod stake;
 pub mod stardust;
+pub mod token_distribution_diff;
 pub mod validator_info;
 
 const GENESIS_BUILDER_COMMITTEE_DIR: &str = "committee";
@@ -95,6 +103,7 @@ const GENESIS_BUILDER_UNSIGNED_GENESIS_FILE: &str = "unsigned-genesis";
 const GENESIS_BUILDER_MIGRATION_SOURCES_FILE: &str = "migration-sources";
 const GENESIS_BUILDER_DELEGATOR_FILE: &str = "delegator";
 const GENESIS_BUILDER_DELEGATOR_MAP_FILE: &str = "delegator-map";
+const GENESIS_BUILDER_INITIAL_JWKS_FILE: &str = "initial-jwks";
 
 pub const OBJECT_SNAPSHOT_FILE_PATH: &str = "stardust_object_snapshot.bin";
 pub const IOTA_OBJECT_SNAPSHOT_URL: &str = "https://stardust-objects.s3.eu-central-1.amazonaws.com/iota/alphanet/latest/stardust_object_snapshot.bin.gz";
@@ -116,6 +125,74 @@ pub struct Builder {
     migration_sources: Vec<SnapshotSource>,
     migration_tx_data: Option<MigrationTxData>,
     delegation: Option<GenesisDelegation>,
+    initial_jwks: Vec<(JwkId, JWK)>,
+}
+
+/// The gas coins and stakes owned by an address after genesis, including the
+/// objects created by the migration transactions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct AllocationSummary {
+    pub gas_coins: Vec<ObjectID>,
+    pub gas_coins_amount: u64,
+    pub staked_iota: Vec<ObjectID>,
+    pub staked_iota_amount: u64,
+    pub timelocked_staked_iota: Vec<ObjectID>,
+    pub timelocked_staked_iota_amount: u64,
+}
+
+/// The validators which did or did not yet sign the genesis checkpoint,
+/// together with their voting power in the genesis committee.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SignatureManifest {
+    pub signed: Vec<SignatureManifestEntry>,
+    pub unsigned: Vec<SignatureManifestEntry>,
+    pub signed_stake: StakeUnit,
+    pub total_stake: StakeUnit,
+    pub quorum_threshold: StakeUnit,
+    pub has_quorum: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SignatureManifestEntry {
+    pub name: String,
+    pub authority_key: AuthorityPublicKeyBytes,
+    pub stake: StakeUnit,
+}
+
+/// A validator address which could not be reached, as reported by
+/// [`Builder::check_validator_reachability`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UnreachableValidatorAddress {
+    pub name: String,
+    pub address: Multiaddr,
+    pub error: String,
+}
+
+/// The gas coins and stakes contained in a set of objects, keyed by object ID.
+struct TypedObjects<'a> {
+    gas_coins: BTreeMap<ObjectID, (&'a Object, GasCoin)>,
+    staked_iota: BTreeMap<ObjectID, (&'a Object, StakedIota)>,
+    timelocked_staked_iota: BTreeMap<ObjectID, (&'a Object, TimelockedStakedIota)>,
+}
+
+impl<'a> TypedObjects<'a> {
+    fn new(objects: impl IntoIterator<Item = &'a Object>) -> Self {
+        let mut typed_objects = Self {
+            gas_coins: Default::default(),
+            staked_iota: Default::default(),
+            timelocked_staked_iota: Default::default(),
+        };
+        for o in objects {
+            if let Ok(g) = GasCoin::try_from(o) {
+                typed_objects.gas_coins.insert(o.id(), (o, g));
+            } else if let Ok(s) = StakedIota::try_from(o) {
+                typed_objects.staked_iota.insert(o.id(), (o, s));
+            } else if let Ok(s) = TimelockedStakedIota::try_from(o) {
+                typed_objects.timelocked_staked_iota.insert(o.id(), (o, s));
+            }
+        }
+        typed_objects
+    }
 }
 
 enum GenesisDelegation {
@@ -146,6 +223,7 @@ impl Builder {
             migration_sources: Default::default(),
             migration_tx_data: Default::default(),
             delegation: None,
+            initial_jwks: Default::default(),
         }
     }
 
@@ -208,6 +286,31 @@ impl Builder {
         self
     }
 
+    /// Set the chain start timestamp used for both the genesis checkpoint and
+    /// the initial value of the `Clock` object.
+    pub fn with_chain_start_timestamp_ms(mut self, timestamp_ms: u64) -> Self {
+        self.parameters.chain_start_timestamp_ms = timestamp_ms;
+        self
+    }
+
+    /// Split the migration objects into exactly `count` migration
+    /// transactions, so that the structure of the genesis checkpoint doesn't
+    /// depend on how the chunk size is derived.
+    pub fn with_migration_transaction_count(mut self, count: u64) -> Self {
+        self.parameters.migration_transaction_count = Some(count);
+        self
+    }
+
+    /// Seed the authenticator state with the given JWKs, so that zkLogin can
+    /// be used right after genesis without waiting for the JWK updater.
+    ///
+    /// The JWKs are active from epoch 0. This requires the protocol version to
+    /// create the authenticator state in genesis.
+    pub fn with_initial_jwks(mut self, jwks: Vec<(JwkId, JWK)>) -> Self {
+        self.initial_jwks = jwks;
+        self
+    }
+
     pub fn add_object(mut self, object: Object) -> Self {
         self.objects.insert(object.id(), object);
         self
@@ -266,6 +369,29 @@ impl Builder {
         self
     }
 
+    /// Skip objects whose ID was already loaded from a previous migration
+    /// source, instead of failing. Only the first occurrence of an object is
+    /// kept.
+    pub fn with_allow_duplicate_migration_objects(mut self, allow: bool) -> Self {
+        self.parameters.allow_duplicate_migration_objects = allow;
+        self
+    }
+
+    /// Fail loading the migration sources if they contain more than `max`
+    /// objects in total.
+    pub fn with_max_migration_objects(mut self, max: usize) -> Self {
+        self.parameters.max_migration_objects = Some(max);
+        self
+    }
+
+    /// Fail loading the migration sources if their serialized size exceeds
+    /// `max` bytes in total. The check happens while reading, before the
+    /// objects of a source are held in memory.
+    pub fn with_max_migration_bytes(mut self, max: u64) -> Self {
+        self.parameters.max_migration_bytes = Some(max);
+        self
+    }
+
     pub fn add_migration_source(mut self, source: SnapshotSource) -> Self {
         self.migration_sources.push(source);
         self
@@ -275,11 +401,71 @@ impl Builder {
         self.built_genesis.clone()
     }
 
+    /// Load the objects of all migration sources into memory.
+    ///
+    /// Fails if an object ID is contained more than once, unless duplicates
+    /// are explicitly allowed, in which case they are skipped with a warning.
+    /// Also fails if the sources exceed the configured maximum number of
+    /// objects or bytes.
     pub fn load_migration_sources(&mut self) -> anyhow::Result<()> {
+        let mut object_sources = HashMap::<ObjectID, &SnapshotSource>::new();
+        let mut remaining_bytes = self.parameters.max_migration_bytes;
         for source in &self.migration_sources {
             tracing::info!("Adding migration objects from {:?}", source);
-            self.migration_objects
-                .extend(bcs::from_reader::<Vec<_>>(source.to_reader()?)?);
+            let objects = match remaining_bytes {
+                Some(remaining) => {
+                    // Read at most one byte more than allowed, so that exceeding
+                    // the limit can be told apart from a source of exactly the
+                    // remaining size.
+                    let mut reader = source.to_reader()?.take(remaining.saturating_add(1));
+                    let objects = bcs::from_reader::<Vec<Object>>(&mut reader);
+                    if reader.limit() == 0 {
+                        bail!(
+                            "migration sources exceed the maximum of {} bytes while reading {source:?}, \
+                             use smaller snapshots or raise the limit",
+                            self.parameters.max_migration_bytes.unwrap_or_default()
+                        );
+                    }
+                    remaining_bytes = Some(reader.limit() - 1);
+                    objects?
+                }
+                None => bcs::from_reader::<Vec<Object>>(source.to_reader()?)?,
+            };
+            if let Some(max) = self.parameters.max_migration_objects {
+                let total = object_sources.len() + objects.len();
+                if total > max {
+                    bail!(
+                        "migration sources contain more than {max} objects after reading {source:?}, \
+                         use smaller snapshots or raise the limit"
+                    );
+                }
+            }
+            let mut unique_objects = Vec::with_capacity(objects.len());
+            for object in objects {
+                match object_sources.entry(object.id()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(source);
+                        unique_objects.push(object);
+                    }
+                    Entry::Occupied(entry) => {
+                        if !self.parameters.allow_duplicate_migration_objects {
+                            bail!(
+                                "object {} is contained in migration source {:?} and in {:?}",
+                                object.id(),
+                                entry.get(),
+                                source
+                            );
+                        }
+                        tracing::warn!(
+                            "skipping object {} of migration source {:?}, already loaded from {:?}",
+                            object.id(),
+                            source,
+                            entry.get()
+                        );
+                    }
+                }
+            }
+            self.migration_objects.extend(unique_objects);
         }
         Ok(())
     }
@@ -368,17 +554,91 @@ impl Builder {
         }
     }
 
+    /// Compute the total supply the genesis will have, i.e., the pre-minted
+    /// supply plus the sum of all allocations of the resolved
+    /// [`TokenDistributionSchedule`].
+    ///
+    /// This does not execute genesis, so it can be used to cheaply double
+    /// check the totals before building. Migration sources are loaded if
+    /// needed, but the token distribution schedule given as input is left
+    /// untouched.
+    pub fn computed_total_supply(&mut self) -> u64 {
+        let schedule = if self.built_genesis.is_some() {
+            self.token_distribution_schedule
+                .clone()
+                .expect("token distribution schedule should be cached after build")
+        } else {
+            if self.migration_objects.is_empty() {
+                self.load_migration_sources()
+                    .expect("migration sources should be loaded without errors");
+            }
+            self.create_and_cache_genesis_stake()
+                .expect("genesis stake should be created without errors");
+            let input_schedule = self.token_distribution_schedule.clone();
+            let schedule = self.resolve_token_distribution_schedule();
+            self.token_distribution_schedule = input_schedule;
+            schedule
+        };
+
+        schedule.pre_minted_supply
+            + schedule
+                .allocations
+                .iter()
+                .map(|allocation| allocation.amount_nanos)
+                .sum::<u64>()
+    }
+
+    /// Aggregate the gas coins, staked IOTA and timelocked staked IOTA owned
+    /// by each address after genesis, including the objects of the migration
+    /// transactions. Builds the genesis if it wasn't built yet.
+    ///
+    /// Only objects owned by an address are taken into account.
+    pub fn migration_allocation_report(&mut self) -> BTreeMap<IotaAddress, AllocationSummary> {
+        let mut objects = self.get_or_build_unsigned_genesis().objects().to_vec();
+        objects.extend(self.tx_migration_objects());
+        let typed_objects = TypedObjects::new(&objects);
+
+        let mut report = BTreeMap::<IotaAddress, AllocationSummary>::new();
+        for (id, (object, gas_coin)) in &typed_objects.gas_coins {
+            if let Owner::AddressOwner(owner) = object.owner {
+                let summary = report.entry(owner).or_default();
+                summary.gas_coins.push(*id);
+                summary.gas_coins_amount += gas_coin.value();
+            }
+        }
+        for (id, (object, staked_iota)) in &typed_objects.staked_iota {
+            if let Owner::AddressOwner(owner) = object.owner {
+                let summary = report.entry(owner).or_default();
+                summary.staked_iota.push(*id);
+                summary.staked_iota_amount += staked_iota.principal();
+            }
+        }
+        for (id, (object, timelocked_staked_iota)) in &typed_objects.timelocked_staked_iota {
+            if let Owner::AddressOwner(owner) = object.owner {
+                let summary = report.entry(owner).or_default();
+                summary.timelocked_staked_iota.push(*id);
+                summary.timelocked_staked_iota_amount += timelocked_staked_iota.principal();
+            }
+        }
+        report
+    }
+
     fn build_and_cache_unsigned_genesis(&mut self) {
+        // If migration sources are present, then load them into memory, unless
+        // they were already loaded (e.g. by `computed_total_supply`).
+        // Otherwise do nothing.
+        if self.migration_objects.is_empty() {
+            self.load_migration_sources()
+                .expect("migration sources should be loaded without errors");
+        }
+
         // Verify that all input data is valid.
         // Check that if extra objects are present then it is allowed by the parameters
-        // to add extra objects and it also validates the validator info
+        // to add extra objects and it also validates the validator info. The migration
+        // objects are loaded first, so that the migration transaction count can be
+        // checked against them.
         self.validate_inputs().unwrap();
 
-        // If migration sources are present, then load them into memory.
-        // Otherwise do nothing.
-        self.load_migration_sources()
-            .expect("migration sources should be loaded without errors");
-
         // If migration objects are present, then create and cache the genesis stake;
         // this also prepares the data needed to resolve the token distribution
         // schedule. Otherwise do nothing.
@@ -405,6 +665,7 @@ impl Builder {
             self.objects.clone().into_values().collect::<Vec<_>>(),
             &mut self.genesis_stake,
             &mut self.migration_objects,
+            &self.initial_jwks,
         );
 
         // Store built data
@@ -431,6 +692,103 @@ impl Builder {
             .clone()
     }
 
+    /// Checks that the addresses of all validators can be reached, and returns
+    /// the ones which can't. A TCP connection is opened to the network
+    /// address. The p2p and primary addresses use UDP, so for them only the
+    /// host name is resolved. Every attempt is given up after `timeout`.
+    pub async fn check_validator_reachability(
+        &self,
+        timeout: Duration,
+    ) -> Vec<UnreachableValidatorAddress> {
+        let checks = self.validators.values().flat_map(|validator| {
+            let info = &validator.info;
+            [
+                (info.network_address(), true),
+                (info.p2p_address(), false),
+                (info.primary_address(), false),
+            ]
+            .into_iter()
+            .map(move |(address, connect)| async move {
+                let result =
+                    tokio::time::timeout(timeout, check_address_reachability(address, connect))
+                        .await
+                        .unwrap_or_else(|_| Err(anyhow::anyhow!("timed out after {timeout:?}")));
+                result.err().map(|e| UnreachableValidatorAddress {
+                    name: info.name().to_owned(),
+                    address: address.clone(),
+                    error: format!("{e:#}"),
+                })
+            })
+        });
+        futures::future::join_all(checks)
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Lists which validators signed the unsigned genesis checkpoint and
+    /// whether their combined stake reaches the quorum threshold of the
+    /// genesis committee, i.e., whether [`Builder::build`] can certify the
+    /// checkpoint.
+    ///
+    /// Fails if a signature belongs to an unknown validator or does not verify
+    /// against the current unsigned genesis checkpoint.
+    pub fn signature_manifest(&mut self) -> anyhow::Result<SignatureManifest> {
+        let (checkpoint, committee) = {
+            let unsigned_genesis = self.get_or_build_unsigned_genesis();
+            (
+                unsigned_genesis.checkpoint().clone(),
+                Self::committee(unsigned_genesis.objects()),
+            )
+        };
+
+        for signature in self.signatures.values() {
+            ensure!(
+                self.validators.contains_key(&signature.authority),
+                "found signature for unknown validator {}",
+                signature.authority
+            );
+            signature
+                .verify_secure(
+                    &checkpoint,
+                    Intent::iota_app(IntentScope::CheckpointSummary),
+                    &committee,
+                )
+                .with_context(|| {
+                    format!(
+                        "invalid signature of validator {}",
+                        self.validators[&signature.authority].info.name()
+                    )
+                })?;
+        }
+
+        let mut signed = Vec::new();
+        let mut unsigned = Vec::new();
+        for (authority_key, validator) in &self.validators {
+            let entry = SignatureManifestEntry {
+                name: validator.info.name().to_owned(),
+                authority_key: *authority_key,
+                stake: committee.weight(authority_key),
+            };
+            if self.signatures.contains_key(authority_key) {
+                signed.push(entry);
+            } else {
+                unsigned.push(entry);
+            }
+        }
+        let signed_stake = signed.iter().map(|entry| entry.stake).sum();
+
+        Ok(SignatureManifest {
+            signed,
+            unsigned,
+            signed_stake,
+            total_stake: committee.total_votes(),
+            quorum_threshold: committee.quorum_threshold(),
+            has_quorum: signed_stake >= committee.quorum_threshold(),
+        })
+    }
+
     pub fn protocol_version(&self) -> ProtocolVersion {
         self.parameters.protocol_version
     }
@@ -473,6 +831,8 @@ impl Builder {
                 objects,
             ),
             self.migration_tx_data,
+            self.token_distribution_schedule
+                .expect("token distribution schedule should be cached after build"),
         )
     }
 
@@ -491,6 +851,44 @@ impl Builder {
         if !self.parameters.allow_insertion_of_extra_objects && !self.objects.is_empty() {
             bail!("extra objects are disallowed");
         }
+        if !self.parameters.allow_system_package_override {
+            if let Some(id) = find_system_package_override(self.objects.values()) {
+                bail!("extra object {id} would override a system package");
+            }
+        }
+
+        if !self.initial_jwks.is_empty() {
+            let protocol_config = get_genesis_protocol_config(self.parameters.protocol_version);
+            ensure!(
+                protocol_config.create_authenticator_state_in_genesis(),
+                "initial JWKs require the authenticator state to be created in genesis"
+            );
+        }
+
+        // One slot of the migration checkpoint is taken by the genesis transaction.
+        if let Some(max) = self.parameters.max_migration_transactions_per_checkpoint {
+            ensure!(
+                max >= 2,
+                "max_migration_transactions_per_checkpoint must be at least 2, got {max}"
+            );
+        }
+        if let Some(count) = self.parameters.migration_transaction_count {
+            let max = max_migration_transactions(
+                self.parameters.max_migration_transactions_per_checkpoint,
+                &get_genesis_protocol_config(self.parameters.protocol_version),
+            );
+            ensure!(
+                (1..=max).contains(&count),
+                "migration_transaction_count must be between 1 and {max}, got {count}"
+            );
+            if !self.migration_objects.is_empty() {
+                let objects = self.migration_objects.len();
+                ensure!(
+                    count <= objects as u64,
+                    "migration_transaction_count must not exceed the {objects} migration objects, got {count}"
+                );
+            }
+        }
 
         for validator in self.validators.values() {
             validator.validate().with_context(|| {
@@ -501,6 +899,33 @@ impl Builder {
             })?;
         }
 
+        self.validate_unique_validator_addresses()?;
+
+        Ok(())
+    }
+
+    /// Checks that no two validators share a network, p2p or primary address,
+    /// which would otherwise only surface as connectivity failures at runtime.
+    fn validate_unique_validator_addresses(&self) -> anyhow::Result<()> {
+        let mut network_addresses = BTreeMap::new();
+        let mut p2p_addresses = BTreeMap::new();
+        let mut primary_addresses = BTreeMap::new();
+        for validator in self.validators.values() {
+            let info = &validator.info;
+            for (kind, seen, address) in [
+                ("network", &mut network_addresses, &info.network_address),
+                ("p2p", &mut p2p_addresses, &info.p2p_address),
+                ("primary", &mut primary_addresses, &info.primary_address),
+            ] {
+                if let Some(other) = seen.insert(address.to_string(), info.name()) {
+                    bail!(
+                        "validators {other} and {} share the same {kind} address {address}",
+                        info.name()
+                    );
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -551,7 +976,10 @@ impl Builder {
 
         if protocol_config.create_authenticator_state_in_genesis() {
             let authenticator_state = unsigned_genesis.authenticator_state_object().unwrap();
-            assert!(authenticator_state.active_jwks.is_empty());
+            assert_eq!(
+                authenticator_state.active_jwks,
+                initial_active_jwks(&self.initial_jwks)
+            );
         } else {
             assert!(unsigned_genesis.authenticator_state_object().is_none());
         }
@@ -670,26 +1098,11 @@ impl Builder {
             token_distribution_schedule.pre_minted_supply + allocations_amount
         );
 
-        let mut gas_objects: BTreeMap<ObjectID, (&Object, GasCoin)> = unsigned_genesis
-            .objects()
-            .iter()
-            .filter_map(|o| GasCoin::try_from(o).ok().map(|g| (o.id(), (o, g))))
-            .collect();
-        let mut staked_iota_objects: BTreeMap<ObjectID, (&Object, StakedIota)> = unsigned_genesis
-            .objects()
-            .iter()
-            .filter_map(|o| StakedIota::try_from(o).ok().map(|s| (o.id(), (o, s))))
-            .collect();
-        let mut timelock_staked_iota_objects: BTreeMap<ObjectID, (&Object, TimelockedStakedIota)> =
-            unsigned_genesis
-                .objects()
-                .iter()
-                .filter_map(|o| {
-                    TimelockedStakedIota::try_from(o)
-                        .ok()
-                        .map(|s| (o.id(), (o, s)))
-                })
-                .collect();
+        let TypedObjects {
+            gas_coins: mut gas_objects,
+            staked_iota: mut staked_iota_objects,
+            timelocked_staked_iota: mut timelock_staked_iota_objects,
+        } = TypedObjects::new(unsigned_genesis.objects());
 
         for allocation in token_distribution_schedule.allocations {
             if let Some(staked_with_validator) = allocation.staked_with_validator {
@@ -807,7 +1220,23 @@ impl Builder {
     }
 
     pub async fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self, anyhow::Error> {
-        let path = path.as_ref();
+        Self::load_impl(path.as_ref(), true).await
+    }
+
+    /// Like [`Builder::load`], but takes a stored unsigned genesis as is
+    /// instead of rebuilding it and checking that both are equal.
+    ///
+    /// NOT SAFE FOR PRODUCTION: nothing guarantees that the loaded genesis
+    /// matches its constituent parts. Only meant for tests which already
+    /// trust the stored artifacts.
+    pub async fn load_trusting<P: AsRef<Path>>(path: P) -> anyhow::Result<Self, anyhow::Error> {
+        Self::load_impl(path.as_ref(), false).await
+    }
+
+    async fn load_impl(
+        path: &Path,
+        verify_unsigned_genesis: bool,
+    ) -> anyhow::Result<Self, anyhow::Error> {
         let path: &Utf8Path = path.try_into()?;
         trace!("Reading Genesis Builder from {}", path);
 
@@ -900,6 +1329,15 @@ impl Builder {
             .map(GenesisDelegation::OneToAll)
             .or(delegator_map.map(GenesisDelegation::ManyToMany));
 
+        // Load initial JWKs
+        let initial_jwks_file = path.join(GENESIS_BUILDER_INITIAL_JWKS_FILE);
+        let initial_jwks = if initial_jwks_file.exists() {
+            serde_json::from_slice(&fs::read(initial_jwks_file)?)
+                .context("unable to deserialize initial JWKs")?
+        } else {
+            Default::default()
+        };
+
         let mut builder = Self {
             parameters,
             token_distribution_schedule,
@@ -912,6 +1350,7 @@ impl Builder {
             migration_sources,
             migration_tx_data,
             delegation,
+            initial_jwks,
         };
 
         let unsigned_genesis_file = path.join(GENESIS_BUILDER_UNSIGNED_GENESIS_FILE);
@@ -927,6 +1366,11 @@ impl Builder {
                 "If a built genesis is present, then there must also be a token-distribution-schedule present"
             );
 
+            if !verify_unsigned_genesis {
+                builder.built_genesis = Some(loaded_genesis);
+                return Ok(builder);
+            }
+
             // Verify loaded genesis matches one build from the constituent parts
             builder = tokio::task::spawn_blocking(move || {
                 builder.get_or_build_unsigned_genesis();
@@ -999,6 +1443,11 @@ impl Builder {
                 .save(file)?;
         }
 
+        if !self.initial_jwks.is_empty() {
+            let file = path.join(GENESIS_BUILDER_INITIAL_JWKS_FILE);
+            fs::write(file, serde_json::to_string(&self.initial_jwks)?)?;
+        }
+
         if let Some(delegation) = &self.delegation {
             match delegation {
                 GenesisDelegation::OneToAll(delegator) => {
@@ -1056,6 +1505,7 @@ fn build_unsigned_genesis_data<'info>(
     objects: Vec<Object>,
     genesis_stake: &mut GenesisStake,
     migration_objects: &mut MigrationObjects,
+    initial_jwks: &[(JwkId, JWK)],
 ) -> (UnsignedGenesis, MigrationTxData) {
     if !parameters.allow_insertion_of_extra_objects && !objects.is_empty() {
         panic!(
@@ -1080,7 +1530,7 @@ fn build_unsigned_genesis_data<'info>(
 
     // if system packages are provided in `objects`, update them with the provided
     // bytes. This is a no-op under normal conditions and only an issue with
-    // certain tests.
+    // certain tests, which have to allow it explicitly (see `validate_inputs`).
     update_system_packages_from_objects(&mut system_packages, &objects);
 
     let mut genesis_ctx = create_genesis_context(
@@ -1106,6 +1556,7 @@ fn build_unsigned_genesis_data<'info>(
         &genesis_chain_parameters,
         token_distribution_schedule,
         system_packages,
+        initial_jwks,
         metrics.clone(),
     );
 
@@ -1132,6 +1583,8 @@ fn build_unsigned_genesis_data<'info>(
         // data.
         txs_data = create_migration_tx_data(
             migration_objects,
+            parameters.max_migration_transactions_per_checkpoint,
+            parameters.migration_transaction_count,
             &protocol_config,
             metrics.clone(),
             &epoch_data,
@@ -1172,42 +1625,143 @@ fn build_unsigned_genesis_data<'info>(
     )
 }
 
+/// The maximum amount of migration transactions, i.e., the transactions of the
+/// genesis checkpoint without the genesis transaction itself.
+fn max_migration_transactions(
+    max_transactions_per_checkpoint: Option<u64>,
+    protocol_config: &ProtocolConfig,
+) -> u64 {
+    max_transactions_per_checkpoint
+        .or_else(|| protocol_config.max_transactions_per_checkpoint_as_option())
+        .unwrap_or(MAX_AMOUNT_OF_TX_PER_CHECKPOINT)
+        - 1
+}
+
 // Creates a map of transaction digest to transaction content involving data
 // coming from a migration. Migration objects come into a vector of objects,
 // here it splits this vector into chunks and creates a `GenesisTransaction`
-// for each chunk.
+// for each chunk. The maximum amount of transactions per checkpoint can be
+// overridden through the ceremony parameters, otherwise the protocol config
+// value is used.
 fn create_migration_tx_data(
     migration_objects: Vec<Object>,
+    max_transactions_per_checkpoint: Option<u64>,
+    migration_transaction_count: Option<u64>,
     protocol_config: &ProtocolConfig,
     metrics: Arc<LimitsMetrics>,
     epoch_data: &EpochData,
 ) -> TransactionsData {
     let mut txs_data = TransactionsData::new();
-    let migration_tx_max_amount = protocol_config
-        .max_transactions_per_checkpoint_as_option()
-        .unwrap_or(MAX_AMOUNT_OF_TX_PER_CHECKPOINT)
-        - 1;
-    let chunk_size = migration_objects.len() / (migration_tx_max_amount as usize) + 1;
+    let migration_tx_max_amount =
+        max_migration_transactions(max_transactions_per_checkpoint, protocol_config);
+    let chunks = match migration_transaction_count {
+        Some(count) => {
+            assert!(
+                count <= migration_tx_max_amount,
+                "{count} migration transactions exceed the maximum of {migration_tx_max_amount}"
+            );
+            // The count is validated against the loaded migration objects, but some of
+            // them may have been destroyed in favor of the genesis stake since.
+            let count = if count as usize > migration_objects.len() {
+                tracing::warn!(
+                    "only {} migration objects are left for {count} migration transactions",
+                    migration_objects.len()
+                );
+                migration_objects.len()
+            } else {
+                count as usize
+            };
+            // The first `remainder` chunks take one object more than the others. No
+            // chunks are created if no objects are left.
+            let (chunk_size, remainder) = (
+                migration_objects
+                    .len()
+                    .checked_div(count)
+                    .unwrap_or_default(),
+                migration_objects
+                    .len()
+                    .checked_rem(count)
+                    .unwrap_or_default(),
+            );
+            let mut objects = migration_objects.into_iter();
+            (0..count)
+                .map(|i| {
+                    objects
+                        .by_ref()
+                        .take(chunk_size + usize::from(i < remainder))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        }
+        None => {
+            let chunk_size = migration_objects.len() / (migration_tx_max_amount as usize) + 1;
+            migration_objects
+                .chunks(chunk_size)
+                .map(<[Object]>::to_vec)
+                .collect()
+        }
+    };
 
-    for objects_per_chunk in migration_objects.chunks(chunk_size) {
-        let (migration_transaction, migration_effects, migration_events, _) =
+    let mut total_written_objects = 0;
+    for objects_per_chunk in chunks {
+        let (migration_transaction, migration_effects, migration_events, written_objects) =
             create_genesis_transaction(
-                objects_per_chunk.to_vec(),
+                objects_per_chunk,
                 vec![],
                 protocol_config,
                 metrics.clone(),
                 epoch_data,
             );
 
+        tracing::info!(
+            digest = %migration_transaction.digest(),
+            written_objects = written_objects.len(),
+            "created migration transaction"
+        );
+        total_written_objects += written_objects.len();
         txs_data.insert(
             *migration_transaction.digest(),
             (migration_transaction, migration_effects, migration_events),
         );
     }
+    tracing::info!(
+        transactions = txs_data.len(),
+        total_written_objects,
+        "created migration transactions"
+    );
 
     txs_data
 }
 
+/// Resolves the host of `address` and, if `connect` is set, opens a TCP
+/// connection to one of the resolved socket addresses.
+async fn check_address_reachability(address: &Multiaddr, connect: bool) -> anyhow::Result<()> {
+    let host = address.hostname().context("address has no host")?;
+    let port = address.port().context("address has no port")?;
+    let socket_addrs = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .with_context(|| format!("failed to resolve {host}"))?
+        .collect::<Vec<_>>();
+    ensure!(!socket_addrs.is_empty(), "{host} resolved to no addresses");
+    if connect {
+        tokio::net::TcpStream::connect(socket_addrs.as_slice())
+            .await
+            .context("failed to connect")?;
+    }
+    Ok(())
+}
+
+/// Returns the ID of the first object which is a system package.
+fn find_system_package_override<'a>(
+    objects: impl IntoIterator<Item = &'a Object>,
+) -> Option<ObjectID> {
+    objects
+        .into_iter()
+        .filter_map(|obj| obj.data.try_as_package())
+        .map(|pkg| pkg.id())
+        .find(|id| is_system_package(*id))
+}
+
 // Some tests provide an override of the system packages via objects to the
 // genesis builder. When that happens we need to update the system packages with
 // the new bytes provided. Mock system packages in protocol config tests are an
@@ -1352,6 +1906,7 @@ fn create_genesis_objects(
     parameters: &GenesisChainParameters,
     token_distribution_schedule: &TokenDistributionSchedule,
     system_packages: Vec<SystemPackage>,
+    initial_jwks: &[(JwkId, JWK)],
     metrics: Arc<LimitsMetrics>,
 ) -> (Vec<Object>, Vec<Event>) {
     let mut store = InMemoryStorage::new(Vec::new());
@@ -1394,6 +1949,7 @@ fn create_genesis_objects(
         genesis_ctx,
         parameters,
         token_distribution_schedule,
+        initial_jwks,
         metrics,
     )
     .expect("Genesis creation should not fail here");
@@ -1483,6 +2039,7 @@ pub fn generate_genesis_system_object(
     genesis_ctx: &mut TxContext,
     genesis_chain_parameters: &GenesisChainParameters,
     token_distribution_schedule: &TokenDistributionSchedule,
+    initial_jwks: &[(JwkId, JWK)],
     metrics: Arc<LimitsMetrics>,
 ) -> anyhow::Result<()> {
     let protocol_config = ProtocolConfig::get_for_version(
@@ -1624,7 +2181,7 @@ pub fn generate_genesis_system_object(
     let InnerTemporaryStore { mut written, .. } = executor.update_genesis_state(
         &*store,
         &protocol_config,
-        metrics,
+        metrics.clone(),
         genesis_ctx,
         CheckedInputObjects::new_for_genesis(vec![]),
         pt,
@@ -1642,6 +2199,95 @@ pub fn generate_genesis_system_object(
 
     store.finish(written);
 
+    if !initial_jwks.is_empty() {
+        seed_authenticator_state_jwks(
+            store,
+            executor,
+            genesis_ctx,
+            &protocol_config,
+            initial_jwks,
+            metrics,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Converts the initial JWKs of the builder into the sorted and deduplicated
+/// active JWKs expected in the genesis authenticator state.
+fn initial_active_jwks(initial_jwks: &[(JwkId, JWK)]) -> Vec<ActiveJwk> {
+    let mut active_jwks = initial_jwks
+        .iter()
+        .map(|(jwk_id, jwk)| ActiveJwk {
+            jwk_id: jwk_id.clone(),
+            jwk: jwk.clone(),
+            epoch: 0,
+        })
+        .collect::<Vec<_>>();
+    active_jwks.sort();
+    active_jwks.dedup();
+    active_jwks
+}
+
+// Adds the initial JWKs to the authenticator state created by
+// `generate_genesis_system_object`, the same way the authenticator state update
+// transaction does it after genesis.
+fn seed_authenticator_state_jwks(
+    store: &mut InMemoryStorage,
+    executor: &dyn Executor,
+    genesis_ctx: &mut TxContext,
+    protocol_config: &ProtocolConfig,
+    initial_jwks: &[(JwkId, JWK)],
+    metrics: Arc<LimitsMetrics>,
+) -> anyhow::Result<()> {
+    let authenticator_state = store
+        .get_object(&IOTA_AUTHENTICATOR_STATE_OBJECT_ID)
+        .context("the authenticator state should be created before seeding JWKs")?
+        .clone();
+    let Owner::Shared {
+        initial_shared_version,
+    } = authenticator_state.owner
+    else {
+        bail!("the authenticator state should be a shared object");
+    };
+
+    let pt = {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.move_call(
+            IOTA_FRAMEWORK_PACKAGE_ID,
+            AUTHENTICATOR_STATE_MODULE_NAME.to_owned(),
+            AUTHENTICATOR_STATE_UPDATE_FUNCTION_NAME.to_owned(),
+            vec![],
+            vec![
+                CallArg::Object(ObjectArg::SharedObject {
+                    id: IOTA_AUTHENTICATOR_STATE_OBJECT_ID,
+                    initial_shared_version,
+                    mutable: true,
+                }),
+                CallArg::Pure(bcs::to_bytes(&initial_active_jwks(initial_jwks))?),
+            ],
+        )?;
+        builder.finish()
+    };
+
+    let InnerTemporaryStore { written, .. } = executor.update_genesis_state(
+        &*store,
+        protocol_config,
+        metrics,
+        genesis_ctx,
+        CheckedInputObjects::new_for_genesis(vec![ObjectReadResult::new(
+            InputObjectKind::SharedMoveObject {
+                id: IOTA_AUTHENTICATOR_STATE_OBJECT_ID,
+                initial_shared_version,
+                mutable: true,
+            },
+            authenticator_state.into(),
+        )]),
+        pt,
+    )?;
+
+    store.finish(written);
+
     Ok(())
 }
 
@@ -1853,21 +2499,37 @@ impl SnapshotUrl {
 
 #[cfg(test)]
 mod test {
+    use std::{path::PathBuf, sync::Arc};
+
     use fastcrypto::traits::KeyPair;
+    use fastcrypto_zkp::bn254::zk_login::{JWK, JwkId};
     use iota_config::{
         genesis::*,
         local_ip_utils,
         node::{DEFAULT_COMMISSION_RATE, DEFAULT_VALIDATOR_GAS_PRICE},
     };
+    use iota_framework::BuiltInFramework;
+    use iota_genesis_common::get_genesis_protocol_config;
+    use iota_protocol_config::ProtocolVersion;
     use iota_types::{
-        base_types::IotaAddress,
+        IOTA_CLOCK_OBJECT_ID,
+        balance::Balance,
+        base_types::{IotaAddress, ObjectID, SequenceNumber, TxContext},
+        clock::Clock,
         crypto::{
-            AccountKeyPair, AuthorityKeyPair, NetworkKeyPair, generate_proof_of_possession,
-            get_key_pair_from_rng,
+            AccountKeyPair, AuthorityKeyPair, AuthoritySignature, NetworkKeyPair,
+            generate_proof_of_possession, get_key_pair_from_rng,
         },
+        epoch_data::EpochData,
+        governance::MIN_VALIDATOR_JOINING_STAKE_NANOS,
+        id::UID,
+        iota_system_state::IotaSystemState,
+        metrics::LimitsMetrics,
+        object::Object,
+        timelock::timelock::{TimeLock, to_genesis_object},
     };
 
-    use crate::{Builder, validator_info::ValidatorInfo};
+    use crate::{Builder, SnapshotSource, create_migration_tx_data, validator_info::ValidatorInfo};
 
     #[test]
     fn allocation_csv() {
@@ -1920,4 +2582,491 @@ mod test {
         builder.save(dir.path()).unwrap();
         Builder::load(dir.path()).await.unwrap();
     }
+
+    #[tokio::test]
+    #[cfg_attr(msim, ignore)]
+    async fn load_trusting_keeps_stored_genesis() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (validator, pop) = new_validator("0");
+        let mut builder = Builder::new().add_validator(validator, pop);
+        let genesis = builder.get_or_build_unsigned_genesis().clone();
+        builder.save(dir.path()).unwrap();
+
+        let mut loaded = Builder::load_trusting(dir.path()).await.unwrap();
+        assert!(*loaded.get_or_build_unsigned_genesis() == genesis);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(msim, ignore)]
+    async fn save_and_load_keeps_migration_limits() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (validator, pop) = new_validator("0");
+        let mut builder = Builder::new()
+            .add_validator(validator, pop)
+            .with_allow_duplicate_migration_objects(true)
+            .with_max_migration_objects(10)
+            .with_max_migration_bytes(1024);
+        builder.get_or_build_unsigned_genesis();
+        builder.save(dir.path()).unwrap();
+
+        let loaded = Builder::load_trusting(dir.path()).await.unwrap();
+        assert!(loaded.parameters.allow_duplicate_migration_objects);
+        assert_eq!(loaded.parameters.max_migration_objects, Some(10));
+        assert_eq!(loaded.parameters.max_migration_bytes, Some(1024));
+    }
+
+    fn new_validator(name: &str) -> (ValidatorInfo, AuthoritySignature) {
+        let authority_key: AuthorityKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let protocol_key: NetworkKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let account_key: AccountKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let network_key: NetworkKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let validator = ValidatorInfo {
+            name: name.into(),
+            authority_key: authority_key.public().into(),
+            protocol_key: protocol_key.public().clone(),
+            account_address: IotaAddress::from(account_key.public()),
+            network_key: network_key.public().clone(),
+            gas_price: DEFAULT_VALIDATOR_GAS_PRICE,
+            commission_rate: DEFAULT_COMMISSION_RATE,
+            network_address: local_ip_utils::new_local_tcp_address_for_testing(),
+            p2p_address: local_ip_utils::new_local_udp_address_for_testing(),
+            primary_address: local_ip_utils::new_local_udp_address_for_testing(),
+            description: String::new(),
+            image_url: String::new(),
+            project_url: String::new(),
+        };
+        let pop = generate_proof_of_possession(&authority_key, account_key.public().into());
+        (validator, pop)
+    }
+
+    fn random_object() -> Object {
+        Object::with_id_owner_for_testing(
+            ObjectID::random(),
+            IotaAddress::random_for_testing_only(),
+        )
+    }
+
+    fn write_migration_source(path: PathBuf, objects: &[Object]) -> SnapshotSource {
+        std::fs::write(&path, bcs::to_bytes(objects).unwrap()).unwrap();
+        SnapshotSource::Local(path)
+    }
+
+    #[tokio::test]
+    #[cfg_attr(msim, ignore)]
+    async fn validator_reachability() {
+        let (validator, pop) = new_validator("0");
+        let network_address = validator.network_address().clone();
+        let builder = Builder::new().add_validator(validator, pop);
+
+        // Nothing listens on the network address, while the UDP addresses are
+        // only resolved.
+        let unreachable = builder
+            .check_validator_reachability(std::time::Duration::from_secs(5))
+            .await;
+        assert_eq!(unreachable.len(), 1);
+        assert_eq!(unreachable[0].name, "0");
+        assert_eq!(unreachable[0].address, network_address);
+
+        let _listener =
+            std::net::TcpListener::bind(network_address.to_socket_addr().unwrap()).unwrap();
+        let unreachable = builder
+            .check_validator_reachability(std::time::Duration::from_secs(5))
+            .await;
+        assert!(unreachable.is_empty(), "{unreachable:?}");
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn signature_manifest() {
+        let authority_key: AuthorityKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let account_key: AccountKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let (mut signing_validator, _) = new_validator("signing");
+        signing_validator.authority_key = authority_key.public().into();
+        signing_validator.account_address = IotaAddress::from(account_key.public());
+        let signing_pop = generate_proof_of_possession(&authority_key, account_key.public().into());
+        let (other_validator, other_pop) = new_validator("other");
+
+        let mut builder = Builder::new()
+            .add_validator(signing_validator, signing_pop)
+            .add_validator(other_validator, other_pop);
+        let manifest = builder.signature_manifest().unwrap();
+        assert!(manifest.signed.is_empty());
+        assert_eq!(manifest.unsigned.len(), 2);
+        assert_eq!(manifest.signed_stake, 0);
+        assert!(!manifest.has_quorum);
+
+        let mut builder = builder.add_validator_signature(&authority_key);
+        let manifest = builder.signature_manifest().unwrap();
+        assert_eq!(manifest.signed.len(), 1);
+        assert_eq!(manifest.signed[0].name, "signing");
+        assert_eq!(manifest.unsigned.len(), 1);
+        assert_eq!(manifest.unsigned[0].name, "other");
+        assert_eq!(manifest.signed_stake, manifest.signed[0].stake);
+        assert_eq!(
+            manifest.signed_stake + manifest.unsigned[0].stake,
+            manifest.total_stake
+        );
+        // Two validators with equal stake, so a single signature is not enough.
+        assert!(!manifest.has_quorum);
+    }
+
+    #[test]
+    fn system_package_override_requires_opt_in() {
+        let (validator, pop) = new_validator("0");
+        let system_package = BuiltInFramework::iter_system_packages()
+            .next()
+            .unwrap()
+            .genesis_object();
+        let builder = Builder::new()
+            .add_validator(validator, pop)
+            .add_object(system_package.clone());
+        let err = builder.validate_inputs().unwrap_err();
+        assert!(err.to_string().contains(&system_package.id().to_string()));
+
+        let builder = builder.with_parameters(GenesisCeremonyParameters {
+            allow_system_package_override: true,
+            ..GenesisCeremonyParameters::new()
+        });
+        builder.validate_inputs().unwrap();
+    }
+
+    #[test]
+    fn duplicate_validator_network_address() {
+        let (validator_0, pop_0) = new_validator("0");
+        let (mut validator_1, pop_1) = new_validator("1");
+        validator_1.network_address = validator_0.network_address.clone();
+
+        let builder = Builder::new()
+            .add_validator(validator_0, pop_0)
+            .add_validator(validator_1, pop_1);
+        let err = builder.validate_inputs().unwrap_err();
+        assert!(err.to_string().contains("network address"));
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn computed_total_supply_matches_genesis() {
+        let (validator, pop) = new_validator("0");
+        let mut builder = Builder::new().add_validator(validator, pop);
+
+        let total_supply = builder.computed_total_supply();
+        let IotaSystemState::V1(system_state) =
+            builder.get_or_build_unsigned_genesis().iota_system_object()
+        else {
+            unreachable!("genesis system state must be V1");
+        };
+        assert_eq!(
+            system_state.iota_treasury_cap.total_supply().value,
+            total_supply
+        );
+        assert_eq!(builder.computed_total_supply(), total_supply);
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn genesis_iota_total_supply() {
+        let authority_key: AuthorityKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let account_key: AccountKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let (mut validator, _) = new_validator("0");
+        validator.authority_key = authority_key.public().into();
+        validator.account_address = IotaAddress::from(account_key.public());
+        let pop = generate_proof_of_possession(&authority_key, account_key.public().into());
+        let mut builder = Builder::new()
+            .add_validator(validator, pop)
+            .add_validator_signature(&authority_key);
+
+        let total_supply = builder.computed_total_supply();
+        let effects = builder.build();
+        assert_eq!(effects.genesis.iota_total_supply().unwrap(), total_supply);
+        assert_eq!(effects.summary().unwrap().total_supply_nanos, total_supply);
+    }
+
+    #[test]
+    fn duplicate_migration_objects() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let duplicate = random_object();
+        let sources = (0..2)
+            .map(|i| {
+                write_migration_source(
+                    dir.path().join(format!("source-{i}.bin")),
+                    &[duplicate.clone(), random_object()],
+                )
+            })
+            .collect::<Vec<_>>();
+        let new_builder = || {
+            sources
+                .iter()
+                .cloned()
+                .fold(Builder::new(), Builder::add_migration_source)
+        };
+
+        let err = new_builder().load_migration_sources().unwrap_err();
+        assert!(err.to_string().contains(&duplicate.id().to_string()));
+
+        let mut builder = new_builder().with_allow_duplicate_migration_objects(true);
+        builder.load_migration_sources().unwrap();
+        assert_eq!(builder.migration_objects.take_objects().len(), 3);
+    }
+
+    #[test]
+    fn migration_objects_limits() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("source.bin");
+        let objects = (0..2).map(|_| random_object()).collect::<Vec<_>>();
+        let bytes = bcs::to_bytes(&objects).unwrap();
+        std::fs::write(&path, &bytes).unwrap();
+        let new_builder =
+            || Builder::new().add_migration_source(SnapshotSource::Local(path.clone()));
+
+        let err = new_builder()
+            .with_max_migration_objects(1)
+            .load_migration_sources()
+            .unwrap_err();
+        assert!(err.to_string().contains("more than 1 objects"));
+
+        let err = new_builder()
+            .with_max_migration_bytes(bytes.len() as u64 - 1)
+            .load_migration_sources()
+            .unwrap_err();
+        assert!(err.to_string().contains("bytes"));
+
+        let mut builder = new_builder()
+            .with_max_migration_objects(2)
+            .with_max_migration_bytes(bytes.len() as u64);
+        builder.load_migration_sources().unwrap();
+        assert_eq!(builder.migration_objects.take_objects().len(), 2);
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn migration_tx_data_respects_max_transactions_per_checkpoint() {
+        let migration_objects = (0..10).map(|_| random_object()).collect::<Vec<_>>();
+        let protocol_config = get_genesis_protocol_config(ProtocolVersion::MAX);
+        let metrics = Arc::new(LimitsMetrics::new(&prometheus::Registry::new()));
+        let epoch_data = EpochData::new_genesis(0);
+
+        // 5 slots are available for migration transactions, so 10 objects are
+        // split in chunks of 3 objects.
+        let txs_data = create_migration_tx_data(
+            migration_objects,
+            Some(6),
+            None,
+            &protocol_config,
+            metrics,
+            &epoch_data,
+        );
+        assert_eq!(txs_data.len(), 4);
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn migration_tx_data_respects_migration_transaction_count() {
+        let migration_objects = (0..10).map(|_| random_object()).collect::<Vec<_>>();
+        let protocol_config = get_genesis_protocol_config(ProtocolVersion::MAX);
+        let metrics = Arc::new(LimitsMetrics::new(&prometheus::Registry::new()));
+        let epoch_data = EpochData::new_genesis(0);
+
+        // The chunk size derived from the 5 available slots would result in 4
+        // transactions, but the count is pinned to 5.
+        let txs_data = create_migration_tx_data(
+            migration_objects,
+            Some(6),
+            Some(5),
+            &protocol_config,
+            metrics,
+            &epoch_data,
+        );
+        assert_eq!(txs_data.len(), 5);
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn migration_transaction_count_must_fit_in_checkpoint() {
+        let (validator, pop) = new_validator("0");
+        let mut parameters = GenesisCeremonyParameters::new();
+        parameters.max_migration_transactions_per_checkpoint = Some(6);
+        let builder = Builder::new()
+            .with_parameters(parameters)
+            .with_migration_transaction_count(6)
+            .add_validator(validator, pop);
+
+        let err = builder.validate_inputs().unwrap_err();
+        assert!(err.to_string().contains("migration_transaction_count"));
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn migration_transaction_count_must_not_exceed_migration_objects() {
+        let (validator, pop) = new_validator("0");
+        let mut builder = Builder::new()
+            .with_migration_transaction_count(3)
+            .add_validator(validator, pop);
+        builder.migration_objects =
+            MigrationObjects::new((0..2).map(|_| random_object()).collect());
+
+        let err = builder.validate_inputs().unwrap_err();
+        assert!(err.to_string().contains("2 migration objects"));
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn chain_start_timestamp_is_configurable() {
+        let timestamp_ms = 1_700_000_000_000;
+        let (validator, pop) = new_validator("0");
+        let mut builder = Builder::new()
+            .with_chain_start_timestamp_ms(timestamp_ms)
+            .add_validator(validator, pop);
+
+        let genesis = builder.get_or_build_unsigned_genesis();
+        assert_eq!(genesis.checkpoint().timestamp_ms, timestamp_ms);
+
+        let clock = genesis
+            .object(IOTA_CLOCK_OBJECT_ID)
+            .expect("clock must exist in genesis");
+        let clock = bcs::from_bytes::<Clock>(clock.data.try_as_move().unwrap().contents()).unwrap();
+        assert_eq!(clock.timestamp_ms, timestamp_ms);
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn migration_allocation_report_matches_schedule() {
+        let (validator, pop) = new_validator("0");
+        let validator_address = validator.iota_address();
+        let recipient = IotaAddress::random_for_testing_only();
+        let mut schedule = TokenDistributionSchedule::new_for_validators_with_default_allocation([
+            validator_address,
+        ]);
+        schedule.allocations.push(TokenAllocation {
+            recipient_address: recipient,
+            amount_nanos: 1_000,
+            staked_with_validator: None,
+            staked_with_timelock_expiration: None,
+        });
+        let mut builder = Builder::new()
+            .with_token_distribution_schedule(schedule.clone())
+            .add_validator(validator, pop);
+
+        let report = builder.migration_allocation_report();
+        let validator_stake = schedule
+            .allocations
+            .iter()
+            .filter(|allocation| allocation.staked_with_validator.is_some())
+            .map(|allocation| allocation.amount_nanos)
+            .sum::<u64>();
+        assert_eq!(
+            report[&validator_address].staked_iota_amount,
+            validator_stake
+        );
+        assert_eq!(report[&recipient].gas_coins.len(), 1);
+        assert_eq!(report[&recipient].gas_coins_amount, 1_000);
+        assert!(report[&recipient].staked_iota.is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn initial_jwks_are_active_at_genesis() {
+        let jwk = |iss: &str, kid: &str| {
+            (
+                JwkId {
+                    iss: iss.to_string(),
+                    kid: kid.to_string(),
+                },
+                JWK {
+                    kty: "RSA".to_string(),
+                    e: "AQAB".to_string(),
+                    n: format!("n-{kid}"),
+                    alg: "RS256".to_string(),
+                },
+            )
+        };
+        let initial_jwks = vec![
+            jwk("https://accounts.google.com", "kid2"),
+            jwk("https://accounts.google.com", "kid1"),
+        ];
+        let (validator, pop) = new_validator("0");
+        let mut builder = Builder::new()
+            .with_initial_jwks(initial_jwks.clone())
+            .add_validator(validator, pop);
+
+        let authenticator_state = builder
+            .get_or_build_unsigned_genesis()
+            .authenticator_state_object()
+            .expect("authenticator state must exist in genesis");
+        let active_jwks = authenticator_state
+            .active_jwks
+            .into_iter()
+            .map(|active_jwk| {
+                assert_eq!(active_jwk.epoch, 0);
+                (active_jwk.jwk_id, active_jwk.jwk)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            active_jwks,
+            [initial_jwks[1].clone(), initial_jwks[0].clone()]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(msim, ignore)]
+    fn genesis_stake_is_deterministic() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let delegator = IotaAddress::random_for_testing_only();
+        let protocol_config = get_genesis_protocol_config(ProtocolVersion::MAX);
+        let tx_context = TxContext::random_for_testing_only();
+        // Two timelocks with the same expiration and two gas coins, so that
+        // the order of the objects in the snapshot could matter.
+        let mut objects = (0..2)
+            .map(|_| {
+                let timelock = TimeLock::new(
+                    UID::new(ObjectID::random()),
+                    Balance::new(MIN_VALIDATOR_JOINING_STAKE_NANOS),
+                    1_000_000,
+                    None,
+                );
+                to_genesis_object(
+                    timelock,
+                    delegator,
+                    &protocol_config,
+                    &tx_context,
+                    SequenceNumber::MIN,
+                )
+                .unwrap()
+            })
+            .chain((0..2).map(|_| {
+                Object::new_gas_with_balance_and_owner_for_testing(
+                    MIN_VALIDATOR_JOINING_STAKE_NANOS / 2,
+                    delegator,
+                )
+            }))
+            .collect::<Vec<_>>();
+        let mut sources = vec![];
+        for i in 0..2 {
+            sources.push(write_migration_source(
+                dir.path().join(format!("source-{i}.bin")),
+                &objects,
+            ));
+            objects.reverse();
+        }
+        let validators = ["0", "1", "2"].map(new_validator);
+
+        let reports = sources
+            .into_iter()
+            .map(|source| {
+                let mut builder = validators
+                    .iter()
+                    .cloned()
+                    .fold(Builder::new(), |builder, (validator, pop)| {
+                        builder.add_validator(validator, pop)
+                    })
+                    .with_delegator(delegator)
+                    .add_migration_source(source);
+                builder.migration_allocation_report()
+            })
+            .collect::<Vec<_>>();
+
+        let summary = &reports[0][&delegator];
+        assert_eq!(summary.timelocked_staked_iota.len(), 2);
+        assert_eq!(summary.staked_iota.len(), 1);
+        assert_eq!(reports[0], reports[1]);
+    }
 }
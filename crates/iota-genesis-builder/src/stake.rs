@@ -109,7 +109,9 @@ impl GenesisStake {
     /// allocations for validators by some delegators.
     ///
     /// This function invokes `delegate_genesis_stake` for each delegator found
-    /// in `Delegations`.
+    /// in `Delegations`. The gas coins of a delegator are used in the order of
+    /// their ids, so the created stakes only depend on the set of migration
+    /// objects and not on their order.
     pub fn new_with_delegations(
         delegations: Delegations,
         migration_objects: &MigrationObjects,
@@ -120,10 +122,13 @@ impl GenesisStake {
             // Fetch all timelock and gas objects owned by the delegator
             let timelocks_pool =
                 migration_objects.get_sorted_timelocks_and_expiration_by_owner(delegator);
-            let gas_coins_pool = migration_objects.get_gas_coins_by_owner(delegator);
+            let mut gas_coins_pool = migration_objects.get_gas_coins_by_owner(delegator);
             if timelocks_pool.is_none() && gas_coins_pool.is_none() {
                 anyhow::bail!("no timelocks or gas-coin objects found for delegator {delegator:?}");
             }
+            if let Some(gas_coins) = &mut gas_coins_pool {
+                gas_coins.sort_by_key(|object| object.id());
+            }
             stake.delegate_genesis_stake(
                 &validators_allocations,
                 delegator,
@@ -358,20 +358,26 @@ impl MigrationObjects {
         self.inner.is_empty()
     }
 
+    /// The number of inner objects.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
     /// Get [`TimeLock`] objects created during the migration together with
     /// their expiration timestamp.
     ///
     /// The query is filtered by the object owner.
     ///
     /// The returned objects are ordered by expiration timestamp, in descending
-    /// order.
+    /// order. Objects with the same expiration timestamp are ordered by their
+    /// id, so that the order does not depend on the order of the snapshot.
     pub fn get_sorted_timelocks_and_expiration_by_owner(
         &self,
         address: IotaAddress,
     ) -> Option<Vec<(&Object, ExpirationTimestamp)>> {
         self.get_timelocks_and_expiration_by_owner(address)
             .map(|mut timelocks| {
-                timelocks.sort_by_key(|&(_, timestamp)| Reverse(timestamp));
+                timelocks.sort_by_key(|&(object, timestamp)| (Reverse(timestamp), object.id()));
                 timelocks
             })
     }
@@ -0,0 +1,161 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Comparison of two [`TokenDistributionSchedule`]s, used to review changes to
+//! a schedule during a genesis ceremony.
+
+use std::{collections::BTreeMap, fmt};
+
+use iota_config::genesis::TokenDistributionSchedule;
+use iota_types::base_types::IotaAddress;
+
+/// The differences between an old and a new [`TokenDistributionSchedule`].
+///
+/// Allocations are compared per recipient, i.e., all the allocations of a
+/// recipient are summed up before being compared.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenDistributionScheduleDiff {
+    /// Recipients only present in the new schedule, with their amount.
+    pub added: BTreeMap<IotaAddress, u64>,
+    /// Recipients only present in the old schedule, with their amount.
+    pub removed: BTreeMap<IotaAddress, u64>,
+    /// Recipients present in both schedules with a different amount, with
+    /// their old and new amount.
+    pub changed: BTreeMap<IotaAddress, (u64, u64)>,
+    /// Difference of the pre-minted supply, new minus old.
+    pub pre_minted_supply_delta: i128,
+    /// Difference of the total supply, i.e., pre-minted supply plus all
+    /// allocations, new minus old.
+    pub total_supply_delta: i128,
+}
+
+impl TokenDistributionScheduleDiff {
+    pub fn new(old: &TokenDistributionSchedule, new: &TokenDistributionSchedule) -> Self {
+        let old_amounts = amounts_per_recipient(old);
+        let mut new_amounts = amounts_per_recipient(new);
+
+        let mut diff = Self {
+            pre_minted_supply_delta: new.pre_minted_supply as i128 - old.pre_minted_supply as i128,
+            total_supply_delta: total_supply(new) - total_supply(old),
+            ..Default::default()
+        };
+        for (recipient, old_amount) in old_amounts {
+            match new_amounts.remove(&recipient) {
+                Some(new_amount) if new_amount != old_amount => {
+                    diff.changed.insert(recipient, (old_amount, new_amount));
+                }
+                Some(_) => {}
+                None => {
+                    diff.removed.insert(recipient, old_amount);
+                }
+            }
+        }
+        diff.added = new_amounts;
+        diff
+    }
+
+    /// Whether both schedules distribute the same amounts to the same
+    /// recipients.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl fmt::Display for TokenDistributionScheduleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "The token distribution schedules are equivalent");
+        }
+        for (recipient, amount) in &self.added {
+            writeln!(f, "+ {recipient}: {amount}")?;
+        }
+        for (recipient, amount) in &self.removed {
+            writeln!(f, "- {recipient}: {amount}")?;
+        }
+        for (recipient, (old_amount, new_amount)) in &self.changed {
+            writeln!(
+                f,
+                "~ {recipient}: {old_amount} -> {new_amount} ({:+})",
+                *new_amount as i128 - *old_amount as i128
+            )?;
+        }
+        writeln!(
+            f,
+            "Pre-minted supply delta: {:+}",
+            self.pre_minted_supply_delta
+        )?;
+        writeln!(f, "Total supply delta: {:+}", self.total_supply_delta)
+    }
+}
+
+fn amounts_per_recipient(schedule: &TokenDistributionSchedule) -> BTreeMap<IotaAddress, u64> {
+    let mut amounts = BTreeMap::<IotaAddress, u64>::new();
+    for allocation in &schedule.allocations {
+        *amounts.entry(allocation.recipient_address).or_default() += allocation.amount_nanos;
+    }
+    amounts
+}
+
+fn total_supply(schedule: &TokenDistributionSchedule) -> i128 {
+    schedule.pre_minted_supply as i128
+        + schedule
+            .allocations
+            .iter()
+            .map(|allocation| allocation.amount_nanos as i128)
+            .sum::<i128>()
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_config::genesis::{TokenAllocation, TokenDistributionSchedule};
+    use iota_types::base_types::IotaAddress;
+
+    use super::TokenDistributionScheduleDiff;
+
+    fn allocation(recipient_address: IotaAddress, amount_nanos: u64) -> TokenAllocation {
+        TokenAllocation {
+            recipient_address,
+            amount_nanos,
+            staked_with_validator: None,
+            staked_with_timelock_expiration: None,
+        }
+    }
+
+    #[test]
+    fn diff_schedules() {
+        let [kept, changed, removed, added] =
+            std::array::from_fn(|_| IotaAddress::random_for_testing_only());
+        let old = TokenDistributionSchedule {
+            pre_minted_supply: 100,
+            allocations: vec![
+                allocation(kept, 10),
+                allocation(changed, 20),
+                allocation(removed, 30),
+            ],
+        };
+        let new = TokenDistributionSchedule {
+            pre_minted_supply: 90,
+            allocations: vec![
+                allocation(kept, 4),
+                allocation(kept, 6),
+                allocation(changed, 25),
+                allocation(added, 40),
+            ],
+        };
+
+        let diff = TokenDistributionScheduleDiff::new(&old, &new);
+        assert_eq!(diff.added.into_iter().collect::<Vec<_>>(), [(added, 40)]);
+        assert_eq!(
+            diff.removed.into_iter().collect::<Vec<_>>(),
+            [(removed, 30)]
+        );
+        assert_eq!(
+            diff.changed.into_iter().collect::<Vec<_>>(),
+            [(changed, (20, 25))]
+        );
+        assert_eq!(diff.pre_minted_supply_delta, -10);
+        assert_eq!(diff.total_supply_delta, 5);
+
+        assert!(TokenDistributionScheduleDiff::new(&old, &old).is_empty());
+    }
+}
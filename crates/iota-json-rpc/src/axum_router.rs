@@ -30,6 +30,7 @@ use jsonrpsee::{
     },
 };
 use serde_json::value::RawValue;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use tracing::error;
 
 use crate::{
@@ -52,6 +53,10 @@ pub struct JsonRpcService<L> {
     rpc_router: RpcRouter,
     traffic_controller: Option<Arc<TrafficController>>,
     client_id_source: Option<ClientIdSource>,
+    max_response_body_size: u32,
+    /// Limits the number of requests served concurrently, if set. A websocket
+    /// connection holds a permit for as long as it is open.
+    request_limit: Option<Arc<Semaphore>>,
 }
 
 impl<L> JsonRpcService<L> {
@@ -78,8 +83,27 @@ impl<L> JsonRpcService<L> {
                 ))
             }),
             client_id_source: policy_config.map(|policy| policy.client_id_source),
+            max_response_body_size: MAX_RESPONSE_SIZE,
+            request_limit: None,
         }
     }
+
+    pub fn with_max_response_body_size(mut self, max_response_body_size: u32) -> Self {
+        self.max_response_body_size = max_response_body_size;
+        self
+    }
+
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.request_limit = Some(Arc::new(Semaphore::new(max_concurrent_requests)));
+        self
+    }
+
+    fn try_acquire_request_permit(&self) -> Result<Option<OwnedSemaphorePermit>, TryAcquireError> {
+        self.request_limit
+            .clone()
+            .map(Semaphore::try_acquire_owned)
+            .transpose()
+    }
 }
 
 impl<L: Logger> JsonRpcService<L> {
@@ -89,7 +113,7 @@ impl<L: Logger> JsonRpcService<L> {
             methods: &self.methods,
             rpc_router: &self.rpc_router,
             extensions: &self.extensions,
-            max_response_body_size: MAX_RESPONSE_SIZE,
+            max_response_body_size: self.max_response_body_size,
             request_start: self.logger.on_request(TransportProtocol::Http),
         }
     }
@@ -103,7 +127,7 @@ impl<L: Logger> JsonRpcService<L> {
             logger: &self.logger,
             methods: &self.methods,
             extensions: &self.extensions,
-            max_response_body_size: MAX_RESPONSE_SIZE,
+            max_response_body_size: self.max_response_body_size,
             request_start: self.logger.on_request(TransportProtocol::Http),
             bounded_subscriptions,
             id_provider: &*self.id_provider,
@@ -142,6 +166,11 @@ pub async fn json_rpc_handler<L: Logger>(
     headers: HeaderMap,
     Json(raw_request): Json<Box<RawValue>>,
 ) -> impl axum::response::IntoResponse {
+    let Ok(_permit) = service.try_acquire_request_permit() else {
+        let err_obj =
+            ErrorObject::borrowed(ErrorCode::ServerIsBusy.code(), TOO_MANY_REQUESTS_MSG, None);
+        return ok_response(MethodResponse::error(Id::Null, err_obj).into_result());
+    };
     let headers_clone = headers.clone();
     // Get version from header.
     let api_version = headers
@@ -442,12 +471,23 @@ pub mod ws {
         ws: WebSocketUpgrade,
         State(service): State<JsonRpcService<L>>,
     ) -> Response {
-        ws.on_upgrade(|ws| ws_json_rpc_handler(ws, service))
+        let Ok(permit) = service.try_acquire_request_permit() else {
+            return from_template(
+                hyper::StatusCode::SERVICE_UNAVAILABLE,
+                TOO_MANY_REQUESTS_MSG,
+                "text/plain",
+            );
+        };
+        ws.on_upgrade(|ws| async move {
+            ws_json_rpc_handler(ws, service).await;
+            // Only release the permit once the websocket is closed.
+            drop(permit);
+        })
     }
 
     async fn ws_json_rpc_handler<L: Logger>(mut socket: WebSocket, service: JsonRpcService<L>) {
         let (tx, mut rx) = mpsc::channel::<String>(MAX_WS_MESSAGE_BUFFER);
-        let sink = MethodSink::new_with_limit(tx, MAX_RESPONSE_SIZE);
+        let sink = MethodSink::new_with_limit(tx, service.max_response_body_size);
         let bounded_subscriptions = BoundedSubscriptions::new(100);
 
         loop {
@@ -2,7 +2,14 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashSet, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
 
 use anyhow::{anyhow, bail};
 use async_trait::async_trait;
@@ -34,13 +41,12 @@ use iota_types::{
     event::EventID,
 };
 use jsonrpsee::{
-    PendingSubscriptionSink, RpcModule, SendTimeoutError, SubscriptionMessage,
+    ConnectionId, PendingSubscriptionSink, RpcModule, SendTimeoutError, SubscriptionMessage,
     core::{RpcResult, SubscriptionResult},
 };
 use move_bytecode_utils::layout::TypeLayoutBuilder;
 use move_core_types::language_storage::TypeTag;
 use serde::Serialize;
-use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tracing::{debug, instrument};
 
 use crate::{
@@ -84,7 +90,7 @@ async fn pipe_from_stream<T: Serialize>(
 pub fn spawn_subscription<S, T>(
     pending: PendingSubscriptionSink,
     rx: S,
-    permit: Option<OwnedSemaphorePermit>,
+    permit: Option<SubscriptionPermit>,
 ) where
     S: Stream<Item = T> + Unpin + Send + 'static,
     T: Serialize + Send,
@@ -103,13 +109,128 @@ pub fn spawn_subscription<S, T>(
 }
 const DEFAULT_MAX_SUBSCRIPTIONS: usize = 100;
 
+/// Limits on the number of subscriptions served by the [`IndexerApi`]. The
+/// limits can be changed while the server is running, lowering them only
+/// rejects new subscriptions and keeps the existing ones.
+#[derive(Debug)]
+pub struct SubscriptionLimits {
+    max_subscriptions: AtomicUsize,
+    /// `0` if the subscriptions per connection are not limited.
+    max_subscriptions_per_connection: AtomicUsize,
+    active: Mutex<ActiveSubscriptions>,
+}
+
+#[derive(Debug, Default)]
+struct ActiveSubscriptions {
+    total: usize,
+    per_connection: HashMap<ConnectionId, usize>,
+}
+
+impl SubscriptionLimits {
+    pub fn new(
+        max_subscriptions: Option<usize>,
+        max_subscriptions_per_connection: Option<usize>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            max_subscriptions: AtomicUsize::new(
+                max_subscriptions.unwrap_or(DEFAULT_MAX_SUBSCRIPTIONS),
+            ),
+            max_subscriptions_per_connection: AtomicUsize::new(
+                max_subscriptions_per_connection.unwrap_or_default(),
+            ),
+            active: Default::default(),
+        })
+    }
+
+    pub fn max_subscriptions(&self) -> usize {
+        self.max_subscriptions.load(Ordering::Relaxed)
+    }
+
+    pub fn set_max_subscriptions(&self, max_subscriptions: usize) {
+        self.max_subscriptions
+            .store(max_subscriptions, Ordering::Relaxed);
+    }
+
+    pub fn max_subscriptions_per_connection(&self) -> Option<usize> {
+        match self
+            .max_subscriptions_per_connection
+            .load(Ordering::Relaxed)
+        {
+            0 => None,
+            max => Some(max),
+        }
+    }
+
+    /// Limits the subscriptions of a single connection to
+    /// `max_subscriptions_per_connection`, or removes the limit if `None`.
+    pub fn set_max_subscriptions_per_connection(
+        &self,
+        max_subscriptions_per_connection: Option<usize>,
+    ) {
+        self.max_subscriptions_per_connection.store(
+            max_subscriptions_per_connection.unwrap_or_default(),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// The number of subscriptions currently served.
+    pub fn active_subscriptions(&self) -> usize {
+        self.active.lock().unwrap().total
+    }
+
+    fn try_acquire(
+        self: &Arc<Self>,
+        connection_id: ConnectionId,
+    ) -> anyhow::Result<SubscriptionPermit> {
+        let mut active = self.active.lock().unwrap();
+        let max_subscriptions = self.max_subscriptions();
+        if active.total >= max_subscriptions {
+            bail!("Resources exhausted, the limit of {max_subscriptions} subscriptions is reached");
+        }
+        let connection_subscriptions = active.per_connection.entry(connection_id).or_default();
+        if let Some(max) = self.max_subscriptions_per_connection() {
+            if *connection_subscriptions >= max {
+                bail!(
+                    "Resources exhausted, the limit of {max} subscriptions per connection is reached"
+                );
+            }
+        }
+        *connection_subscriptions += 1;
+        active.total += 1;
+        Ok(SubscriptionPermit {
+            limits: self.clone(),
+            connection_id,
+        })
+    }
+}
+
+/// Counts a subscription towards the [`SubscriptionLimits`] until it is
+/// dropped.
+pub struct SubscriptionPermit {
+    limits: Arc<SubscriptionLimits>,
+    connection_id: ConnectionId,
+}
+
+impl Drop for SubscriptionPermit {
+    fn drop(&mut self) {
+        let mut active = self.limits.active.lock().unwrap();
+        active.total -= 1;
+        if let Some(count) = active.per_connection.get_mut(&self.connection_id) {
+            *count -= 1;
+            if *count == 0 {
+                active.per_connection.remove(&self.connection_id);
+            }
+        }
+    }
+}
+
 pub struct IndexerApi<R> {
     state: Arc<dyn StateRead>,
     read_api: R,
     transaction_kv_store: Arc<TransactionKeyValueStore>,
     iota_names_config: IotaNamesConfig,
     pub metrics: Arc<JsonRpcMetrics>,
-    subscription_semaphore: Arc<Semaphore>,
+    subscription_limits: Arc<SubscriptionLimits>,
 }
 
 impl<R: ReadApiServer> IndexerApi<R> {
@@ -119,16 +240,15 @@ impl<R: ReadApiServer> IndexerApi<R> {
         transaction_kv_store: Arc<TransactionKeyValueStore>,
         metrics: Arc<JsonRpcMetrics>,
         iota_names_config: IotaNamesConfig,
-        max_subscriptions: Option<usize>,
+        subscription_limits: Arc<SubscriptionLimits>,
     ) -> Self {
-        let max_subscriptions = max_subscriptions.unwrap_or(DEFAULT_MAX_SUBSCRIPTIONS);
         Self {
             state,
             transaction_kv_store,
             read_api,
             metrics,
             iota_names_config,
-            subscription_semaphore: Arc::new(Semaphore::new(max_subscriptions)),
+            subscription_limits,
         }
     }
 
@@ -147,11 +267,11 @@ impl<R: ReadApiServer> IndexerApi<R> {
         Ok((name_type, name_bcs_value))
     }
 
-    fn acquire_subscribe_permit(&self) -> anyhow::Result<OwnedSemaphorePermit> {
-        match self.subscription_semaphore.clone().try_acquire_owned() {
-            Ok(p) => Ok(p),
-            Err(_) => bail!("Resources exhausted"),
-        }
+    fn acquire_subscribe_permit(
+        &self,
+        sink: &PendingSubscriptionSink,
+    ) -> anyhow::Result<SubscriptionPermit> {
+        self.subscription_limits.try_acquire(sink.connection_id())
     }
 
     async fn get_dynamic_field_object(
@@ -367,7 +487,7 @@ impl<R: ReadApiServer> IndexerApiServer for IndexerApi<R> {
         sink: PendingSubscriptionSink,
         filter: EventFilter,
     ) -> SubscriptionResult {
-        let permit = self.acquire_subscribe_permit()?;
+        let permit = self.acquire_subscribe_permit(&sink)?;
         spawn_subscription(
             sink,
             self.state
@@ -388,7 +508,7 @@ impl<R: ReadApiServer> IndexerApiServer for IndexerApi<R> {
             return Err("checkpoint filter is not supported".into());
         }
 
-        let permit = self.acquire_subscribe_permit()?;
+        let permit = self.acquire_subscribe_permit(&sink)?;
         spawn_subscription(
             sink,
             self.state
@@ -32,7 +32,7 @@ use tower_http::{
 use tracing::{debug, info};
 
 use crate::{
-    axum_router::{json_rpc_handler, ws::ws_json_rpc_upgrade},
+    axum_router::{MAX_RESPONSE_SIZE, json_rpc_handler, ws::ws_json_rpc_upgrade},
     error::Error,
     metrics::MetricsLogger,
     routing_layer::RpcRouter,
@@ -65,6 +65,8 @@ pub struct JsonRpcServerBuilder {
     registry: Registry,
     policy_config: Option<PolicyConfig>,
     firewall_config: Option<RemoteFirewallConfig>,
+    max_response_body_size: u32,
+    max_concurrent_requests: Option<usize>,
 }
 
 pub fn iota_rpc_doc(version: &str) -> Project {
@@ -93,9 +95,25 @@ impl JsonRpcServerBuilder {
             registry: prometheus_registry.clone(),
             policy_config,
             firewall_config,
+            max_response_body_size: MAX_RESPONSE_SIZE,
+            max_concurrent_requests: None,
         }
     }
 
+    /// Sets the maximum size in bytes of a single response, defaults to
+    /// [`MAX_RESPONSE_SIZE`].
+    pub fn with_max_response_body_size(mut self, max_response_body_size: u32) -> Self {
+        self.max_response_body_size = max_response_body_size;
+        self
+    }
+
+    /// Limits the number of requests served concurrently. An open websocket
+    /// connection counts as a single request. Unlimited by default.
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: Option<usize>) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+
     pub fn register_module<T: IotaRpcModule>(&mut self, module: T) -> Result<(), Error> {
         self.rpc_doc.add_module(T::rpc_doc_module());
         Ok(self.module.merge(module.rpc())?)
@@ -175,7 +193,7 @@ impl JsonRpcServerBuilder {
             .layer(Self::trace_layer())
             .layer(Self::cors()?);
 
-        let service = crate::axum_router::JsonRpcService::new(
+        let mut service = crate::axum_router::JsonRpcService::new(
             module.into(),
             rpc_router,
             metrics_logger,
@@ -183,7 +201,11 @@ impl JsonRpcServerBuilder {
             self.policy_config.clone(),
             traffic_controller_metrics,
             Extensions::new(),
-        );
+        )
+        .with_max_response_body_size(self.max_response_body_size);
+        if let Some(max_concurrent_requests) = self.max_concurrent_requests {
+            service = service.with_max_concurrent_requests(max_concurrent_requests);
+        }
 
         let mut router = axum::Router::new();
 
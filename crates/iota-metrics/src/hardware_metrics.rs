@@ -12,7 +12,10 @@ use prometheus::{
     core::{Collector, Desc, Number},
     proto::{LabelPair, Metric, MetricFamily, MetricType},
 };
-use sysinfo::{CpuRefreshKind, Disk, Disks, MemoryRefreshKind, RefreshKind, System};
+use sysinfo::{
+    CpuRefreshKind, Disk, Disks, MemoryRefreshKind, ProcessRefreshKind, ProcessesToUpdate,
+    RefreshKind, System,
+};
 
 use crate::RegistryService;
 
@@ -41,6 +44,19 @@ pub fn register_hardware_metrics(
     Ok(())
 }
 
+/// Returns the resident memory of the current process in bytes, if it can be
+/// determined.
+pub fn process_resident_memory_bytes() -> Option<u64> {
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    system.process(pid).map(|process| process.memory())
+}
+
 pub struct HardwareMetrics {
     system: Arc<Mutex<System>>,
     disks: Arc<Mutex<Disks>>,
@@ -11,10 +11,13 @@ use anemo::codegen::InboundRequestLayer;
 use anemo_tower::{inflight_limit, rate_limit};
 use iota_archival::reader::ArchiveReaderBalancer;
 use iota_config::p2p::StateSyncConfig;
-use iota_types::{messages_checkpoint::VerifiedCheckpoint, storage::WriteStore};
+use iota_types::{
+    messages_checkpoint::{CheckpointSequenceNumber, VerifiedCheckpoint},
+    storage::WriteStore,
+};
 use tap::Pipe;
 use tokio::{
-    sync::{broadcast, mpsc},
+    sync::{Semaphore, broadcast, mpsc, watch},
     task::JoinSet,
 };
 
@@ -29,6 +32,8 @@ pub struct Builder<S> {
     config: Option<StateSyncConfig>,
     metrics: Option<Metrics>,
     archive_readers: Option<ArchiveReaderBalancer>,
+    highest_executed_checkpoint: Option<watch::Receiver<CheckpointSequenceNumber>>,
+    signature_verification_limit: Option<Arc<Semaphore>>,
 }
 
 impl Builder<()> {
@@ -39,6 +44,8 @@ impl Builder<()> {
             config: None,
             metrics: None,
             archive_readers: None,
+            highest_executed_checkpoint: None,
+            signature_verification_limit: None,
         }
     }
 }
@@ -50,6 +57,8 @@ impl<S> Builder<S> {
             config: self.config,
             metrics: self.metrics,
             archive_readers: self.archive_readers,
+            highest_executed_checkpoint: self.highest_executed_checkpoint,
+            signature_verification_limit: self.signature_verification_limit,
         }
     }
 
@@ -67,6 +76,27 @@ impl<S> Builder<S> {
         self.archive_readers = Some(archive_readers);
         self
     }
+
+    /// Lets state sync follow the progress of checkpoint execution, which is
+    /// required to bound how far the contents sync gets ahead of it.
+    pub fn highest_executed_checkpoint(
+        mut self,
+        highest_executed_checkpoint: watch::Receiver<CheckpointSequenceNumber>,
+    ) -> Self {
+        self.highest_executed_checkpoint = Some(highest_executed_checkpoint);
+        self
+    }
+
+    /// Verifies checkpoint signatures only while holding a permit of
+    /// `signature_verification_limit`, which is shared with the signature
+    /// verifier of the epoch store. `None` means no limit.
+    pub fn signature_verification_limit(
+        mut self,
+        signature_verification_limit: Option<Arc<Semaphore>>,
+    ) -> Self {
+        self.signature_verification_limit = signature_verification_limit;
+        self
+    }
 }
 
 impl<S> Builder<S>
@@ -127,6 +157,8 @@ where
             config,
             metrics,
             archive_readers,
+            highest_executed_checkpoint,
+            signature_verification_limit,
         } = self;
         let store = store.unwrap();
         let config = config.unwrap_or_default();
@@ -168,6 +200,8 @@ where
                 checkpoint_event_sender,
                 metrics,
                 archive_readers,
+                highest_executed_checkpoint,
+                signature_verification_limit,
             },
             server,
         )
@@ -184,6 +218,8 @@ pub struct UnstartedStateSync<S> {
     pub(super) checkpoint_event_sender: broadcast::Sender<VerifiedCheckpoint>,
     pub(super) metrics: Metrics,
     pub(super) archive_readers: ArchiveReaderBalancer,
+    pub(super) highest_executed_checkpoint: Option<watch::Receiver<CheckpointSequenceNumber>>,
+    pub(super) signature_verification_limit: Option<Arc<Semaphore>>,
 }
 
 impl<S> UnstartedStateSync<S>
@@ -201,6 +237,8 @@ where
             checkpoint_event_sender,
             metrics,
             archive_readers,
+            highest_executed_checkpoint,
+            signature_verification_limit,
         } = self;
 
         (
@@ -219,6 +257,8 @@ where
                 metrics,
                 archive_readers,
                 sync_checkpoint_from_archive_task: None,
+                highest_executed_checkpoint,
+                signature_verification_limit,
             },
             handle,
         )
@@ -47,6 +47,12 @@ impl Metrics {
         }
     }
 
+    pub fn set_checkpoints_ahead_of_execution(&self, checkpoints: u64) {
+        if let Some(inner) = &self.0 {
+            inner.checkpoints_ahead_of_execution.set(checkpoints as i64);
+        }
+    }
+
     pub fn checkpoint_summary_age_metric(&self) -> Option<&Histogram> {
         if let Some(inner) = &self.0 {
             return Some(&inner.checkpoint_summary_age_ms);
@@ -59,6 +65,7 @@ struct Inner {
     highest_known_checkpoint: IntGauge,
     highest_verified_checkpoint: IntGauge,
     highest_synced_checkpoint: IntGauge,
+    checkpoints_ahead_of_execution: IntGauge,
     checkpoint_summary_age_ms: Histogram,
 }
 
@@ -86,6 +93,13 @@ impl Inner {
             )
             .unwrap(),
 
+            checkpoints_ahead_of_execution: register_int_gauge_with_registry!(
+                "checkpoints_ahead_of_execution",
+                "Number of synced checkpoints which are not yet executed",
+                registry
+            )
+            .unwrap(),
+
             checkpoint_summary_age_ms: Histogram::new_in_registry(
                 "checkpoint_summary_age_ms",
                 "Age of checkpoints summaries when they arrive and are verified.",
@@ -60,6 +60,7 @@
 
 use std::{
     collections::{HashMap, VecDeque},
+    num::NonZeroU64,
     sync::{
         Arc, RwLock,
         atomic::{AtomicU64, Ordering},
@@ -82,7 +83,7 @@ use iota_types::{
 use rand::Rng;
 use tap::{Pipe, TapFallible, TapOptional};
 use tokio::{
-    sync::{broadcast, mpsc, oneshot, watch},
+    sync::{Semaphore, broadcast, mpsc, oneshot, watch},
     task::{AbortHandle, JoinSet},
 };
 use tracing::{debug, info, instrument, trace, warn};
@@ -400,6 +401,8 @@ struct StateSyncEventLoop<S> {
 
     archive_readers: ArchiveReaderBalancer,
     sync_checkpoint_from_archive_task: Option<AbortHandle>,
+    highest_executed_checkpoint: Option<watch::Receiver<CheckpointSequenceNumber>>,
+    signature_verification_limit: Option<Arc<Semaphore>>,
 }
 
 impl<S> StateSyncEventLoop<S>
@@ -435,6 +438,7 @@ where
             receiver,
             self.store.clone(),
             self.metrics.clone(),
+            self.highest_executed_checkpoint.clone(),
         ));
 
         // Start checkpoint contents sync loop.
@@ -447,6 +451,8 @@ where
             self.config.checkpoint_content_download_concurrency(),
             self.config.checkpoint_content_download_tx_concurrency(),
             self.config.checkpoint_content_timeout(),
+            self.config.max_checkpoints_ahead_of_execution,
+            self.highest_executed_checkpoint.clone(),
             target_checkpoint_contents_sequence_receiver,
         );
         let task_handle = self.tasks.spawn(task);
@@ -734,6 +740,7 @@ where
                 self.config.pinned_checkpoints.clone(),
                 self.config.checkpoint_header_download_concurrency(),
                 self.config.timeout(),
+                self.signature_verification_limit.clone(),
                 // The if condition should ensure that this is Some
                 highest_known_checkpoint.unwrap(),
             )
@@ -1019,6 +1026,7 @@ async fn sync_to_checkpoint<S>(
     pinned_checkpoints: Vec<(CheckpointSequenceNumber, CheckpointDigest)>,
     checkpoint_header_download_concurrency: usize,
     timeout: Duration,
+    signature_verification_limit: Option<Arc<Semaphore>>,
     checkpoint: Checkpoint,
 ) -> Result<()>
 where
@@ -1131,6 +1139,15 @@ where
             {
                 break 'cp VerifiedCheckpoint::new_unchecked(checkpoint);
             }
+            let _permit = match &signature_verification_limit {
+                Some(limit) => Some(
+                    limit
+                        .acquire()
+                        .await
+                        .expect("semaphore should not be closed"),
+                ),
+                None => None,
+            };
             match verify_checkpoint(&current, &store, checkpoint) {
                 Ok(verified_checkpoint) => verified_checkpoint,
                 Err(checkpoint) => {
@@ -1261,6 +1278,8 @@ async fn sync_checkpoint_contents<S>(
     checkpoint_content_download_concurrency: usize,
     checkpoint_content_download_tx_concurrency: u64,
     timeout: Duration,
+    max_checkpoints_ahead_of_execution: Option<NonZeroU64>,
+    mut highest_executed_checkpoint: Option<watch::Receiver<CheckpointSequenceNumber>>,
     mut target_sequence_channel: watch::Receiver<CheckpointSequenceNumber>,
 ) where
     S: WriteStore + Clone,
@@ -1275,9 +1294,22 @@ async fn sync_checkpoint_contents<S>(
     let mut checkpoint_contents_tasks = FuturesOrdered::new();
 
     let mut tx_concurrency_remaining = checkpoint_content_download_tx_concurrency;
+    if max_checkpoints_ahead_of_execution.is_some() && highest_executed_checkpoint.is_none() {
+        warn!(
+            "the progress of checkpoint execution is unknown, checkpoint contents sync is not bounded by it"
+        );
+    }
+    // Set while no new contents are synced because execution fell behind.
+    let mut paused_for_execution = false;
 
     loop {
         tokio::select! {
+            result = wait_for_execution(&mut highest_executed_checkpoint), if paused_for_execution => {
+                if result.is_err() {
+                    // Execution stopped, syncing ahead of it can't be bounded anymore.
+                    highest_executed_checkpoint = None;
+                }
+            },
             result = target_sequence_channel.changed() => {
                 match result {
                     Ok(()) => {
@@ -1327,9 +1359,27 @@ async fn sync_checkpoint_contents<S>(
             },
         }
 
+        // Don't start syncing checkpoints too far ahead of execution.
+        let sync_limit = max_checkpoints_ahead_of_execution
+            .zip(highest_executed_checkpoint.as_mut())
+            .map(|(max, highest_executed)| {
+                (*highest_executed.borrow_and_update()).saturating_add(max.get())
+            });
+        let was_paused_for_execution = paused_for_execution;
+        paused_for_execution = current_sequence < target_sequence_cursor
+            && sync_limit.is_some_and(|limit| current_sequence > limit);
+        if paused_for_execution && !was_paused_for_execution {
+            info!(
+                "pausing checkpoint contents sync at {current_sequence} until execution catches up"
+            );
+        } else if !paused_for_execution && was_paused_for_execution {
+            info!("resuming checkpoint contents sync at {current_sequence}");
+        }
+
         // Start syncing tasks up to configured concurrency limits.
         while current_sequence < target_sequence_cursor
             && checkpoint_contents_tasks.len() < checkpoint_content_download_concurrency
+            && sync_limit.is_none_or(|limit| current_sequence <= limit)
         {
             let next_checkpoint = store
                 .get_checkpoint_by_sequence_number(current_sequence)
@@ -1370,6 +1420,16 @@ async fn sync_checkpoint_contents<S>(
     }
 }
 
+/// Waits until a new checkpoint is executed.
+async fn wait_for_execution(
+    highest_executed_checkpoint: &mut Option<watch::Receiver<CheckpointSequenceNumber>>,
+) -> std::result::Result<(), watch::error::RecvError> {
+    match highest_executed_checkpoint {
+        Some(highest_executed_checkpoint) => highest_executed_checkpoint.changed().await,
+        None => std::future::pending().await,
+    }
+}
+
 #[instrument(level = "debug", skip_all, fields(sequence_number = ?checkpoint.sequence_number()))]
 /// Requests a single checkpoint contents from peers if the store does not
 /// have it.
@@ -1485,6 +1545,7 @@ async fn update_checkpoint_watermark_metrics<S>(
     mut recv: oneshot::Receiver<()>,
     store: S,
     metrics: Metrics,
+    highest_executed_checkpoint: Option<watch::Receiver<CheckpointSequenceNumber>>,
 ) -> Result<()>
 where
     S: WriteStore + Clone + Send + Sync,
@@ -1499,6 +1560,11 @@ where
                 let highest_synced_checkpoint = store.get_highest_synced_checkpoint()
                     .expect("store operation should not fail");
                 metrics.set_highest_synced_checkpoint(highest_synced_checkpoint.sequence_number);
+                if let Some(highest_executed_checkpoint) = &highest_executed_checkpoint {
+                    metrics.set_checkpoints_ahead_of_execution(
+                        highest_synced_checkpoint.sequence_number.saturating_sub(*highest_executed_checkpoint.borrow()),
+                    );
+                }
              },
             _ = &mut recv => break,
         }
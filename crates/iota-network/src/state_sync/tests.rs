@@ -2,7 +2,11 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashMap, num::NonZeroUsize, time::Duration};
+use std::{
+    collections::HashMap,
+    num::{NonZeroU64, NonZeroUsize},
+    time::Duration,
+};
 
 use anemo::{PeerId, Request};
 use anyhow::anyhow;
@@ -10,6 +14,7 @@ use iota_archival::{reader::ArchiveReaderBalancer, writer::ArchiveWriter};
 use iota_config::{
     node::ArchiveReaderConfig,
     object_storage_config::{ObjectStoreConfig, ObjectStoreType},
+    p2p::StateSyncConfig,
 };
 use iota_storage::{FileCompression, StorageFormat};
 use iota_swarm_config::test_utils::{CommitteeFixture, empty_contents};
@@ -19,7 +24,10 @@ use iota_types::{
 };
 use prometheus::Registry;
 use tempfile::tempdir;
-use tokio::time::{Instant, timeout};
+use tokio::{
+    sync::watch,
+    time::{Instant, timeout},
+};
 
 use crate::{
     state_sync::{
@@ -583,6 +591,92 @@ async fn sync_with_checkpoints_being_inserted() {
     );
 }
 
+#[tokio::test]
+async fn sync_bounded_by_checkpoint_execution() {
+    telemetry_subscribers::init_for_testing();
+    let committee = CommitteeFixture::generate(rand::rngs::OsRng, 0, 4);
+    // Build mock data
+    let (ordered_checkpoints, _contents, _sequence_number_to_digest, _checkpoints) =
+        committee.make_empty_checkpoints(6, None);
+
+    // Build and connect two nodes, the second one may only sync the contents of
+    // two checkpoints ahead of execution
+    let (builder, server) = Builder::new().store(SharedInMemoryStore::default()).build();
+    let network_1 = build_network(|router| router.add_rpc_service(server));
+    let (event_loop_1, handle_1) = builder.build(network_1.clone());
+    let (highest_executed_sender, highest_executed_receiver) = watch::channel(0);
+    let (builder, server) = Builder::new()
+        .store(SharedInMemoryStore::default())
+        .config(StateSyncConfig {
+            max_checkpoints_ahead_of_execution: NonZeroU64::new(2),
+            ..Default::default()
+        })
+        .highest_executed_checkpoint(highest_executed_receiver)
+        .build();
+    let network_2 = build_network(|router| router.add_rpc_service(server));
+    let (event_loop_2, handle_2) = builder.build(network_2.clone());
+
+    // Init the root committee in both nodes
+    event_loop_1.store.inner_mut().insert_genesis_state(
+        ordered_checkpoints.first().cloned().unwrap(),
+        empty_contents(),
+        committee.committee().to_owned(),
+    );
+    event_loop_2.store.inner_mut().insert_genesis_state(
+        ordered_checkpoints.first().cloned().unwrap(),
+        empty_contents(),
+        committee.committee().to_owned(),
+    );
+
+    let store_1 = event_loop_1.store.clone();
+    let store_2 = event_loop_2.store.clone();
+    // Start both event loops
+    tokio::spawn(event_loop_1.start());
+    tokio::spawn(event_loop_2.start());
+
+    let mut subscriber_2 = handle_2.subscribe_to_synced_checkpoints();
+
+    network_1.connect(network_2.local_addr()).await.unwrap();
+
+    // Inject all the checkpoints into the first node
+    for checkpoint in ordered_checkpoints.iter().skip(1) {
+        store_1
+            .insert_checkpoint_contents(checkpoint, empty_contents())
+            .unwrap();
+        store_1.insert_certified_checkpoint(checkpoint);
+        handle_1.send_checkpoint(checkpoint.clone()).await;
+    }
+
+    // Only the checkpoints up to the limit are synced while nothing is executed
+    timeout(Duration::from_secs(1), async {
+        for checkpoint in &ordered_checkpoints[1..=2] {
+            assert_eq!(subscriber_2.recv().await.unwrap().data(), checkpoint.data());
+        }
+    })
+    .await
+    .unwrap();
+    timeout(Duration::from_millis(500), subscriber_2.recv())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        store_2
+            .get_highest_synced_checkpoint()
+            .unwrap()
+            .sequence_number(),
+        ordered_checkpoints[2].sequence_number()
+    );
+
+    // The sync resumes once execution catches up
+    highest_executed_sender.send_replace(*ordered_checkpoints[3].sequence_number());
+    timeout(Duration::from_secs(1), async {
+        for checkpoint in &ordered_checkpoints[3..] {
+            assert_eq!(subscriber_2.recv().await.unwrap().data(), checkpoint.data());
+        }
+    })
+    .await
+    .unwrap();
+}
+
 #[tokio::test]
 async fn sync_with_checkpoints_watermark() {
     telemetry_subscribers::init_for_testing();
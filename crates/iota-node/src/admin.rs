@@ -48,6 +48,63 @@ use crate::IotaNode;
 //
 //   $ curl 'http://127.0.0.1:1337/node-config'
 //
+// View the protocol config of the current epoch, including all feature flags,
+// as JSON:
+//
+//   $ curl 'http://127.0.0.1:1337/protocol-config'
+//
+// View a snapshot of all node metrics as JSON:
+//
+//   $ curl 'http://127.0.0.1:1337/metrics-json'
+//
+// Check whether the node is transitioning to the next epoch. Responds with
+// status 503 while reconfiguring, so load balancers can deprioritize the node:
+//
+//   $ curl 'http://127.0.0.1:1337/reconfiguring'
+//
+// View the committee peers (peer id and addresses) the discovery layer was last
+// told to trust:
+//
+//   $ curl 'http://127.0.0.1:1337/trusted-peers'
+//
+// Resend the committee peers of the start state of the current epoch to the
+// discovery layer, so it refreshes their addresses. This only restores the
+// epoch-start set, validator metadata changed on-chain during the epoch is not
+// read:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/refresh-trusted-peers'
+//
+// Let a warm standby validator started with `grpc-deferred-bind` bind its
+// network address and start serving:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/activate-validator-grpc'
+//
+// Rebuild the rest index from the checkpoint store in the background. The
+// current index keeps serving requests until the rebuilt one replaces it:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/rebuild-rest-index'
+//
+// Skip pruning and compaction before upload of the db checkpoint taken at the
+// end of the current epoch:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/db-checkpoint-prune-and-compact?enabled=false'
+//
+// View the limits on json-rpc subscriptions and the number of active
+// subscriptions:
+//
+//   $ curl 'http://127.0.0.1:1337/subscription-limits'
+//
+// Lower the limits on json-rpc subscriptions to 50 in total and 5 per
+// connection. Existing subscriptions are kept. A per connection limit of 0
+// removes that limit:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/subscription-limits?max_subscriptions=50&max_subscriptions_per_connection=5'
+//
+// Pause the validator tx finalizer until the end of the current epoch, or
+// resume it:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/validator-tx-finalizer?enabled=false'
+//
 // Set a time-limited tracing config. After the duration expires, tracing will
 // be disabled automatically.
 //
@@ -76,8 +133,18 @@ const TRACING_RESET_ROUTE: &str = "/reset-tracing";
 const SET_BUFFER_STAKE_ROUTE: &str = "/set-override-buffer-stake";
 const CLEAR_BUFFER_STAKE_ROUTE: &str = "/clear-override-buffer-stake";
 const FORCE_CLOSE_EPOCH: &str = "/force-close-epoch";
+const DB_CHECKPOINT_PRUNE_AND_COMPACT: &str = "/db-checkpoint-prune-and-compact";
+const SUBSCRIPTION_LIMITS: &str = "/subscription-limits";
+const VALIDATOR_TX_FINALIZER: &str = "/validator-tx-finalizer";
 const CAPABILITIES: &str = "/capabilities";
 const NODE_CONFIG: &str = "/node-config";
+const PROTOCOL_CONFIG: &str = "/protocol-config";
+const METRICS_JSON: &str = "/metrics-json";
+const RECONFIGURING: &str = "/reconfiguring";
+const TRUSTED_PEERS: &str = "/trusted-peers";
+const REFRESH_TRUSTED_PEERS: &str = "/refresh-trusted-peers";
+const ACTIVATE_VALIDATOR_GRPC: &str = "/activate-validator-grpc";
+const REBUILD_REST_INDEX: &str = "/rebuild-rest-index";
 const RANDOMNESS_PARTIAL_SIGS_ROUTE: &str = "/randomness-partial-sigs";
 const RANDOMNESS_INJECT_PARTIAL_SIGS_ROUTE: &str = "/randomness-inject-partial-sigs";
 const RANDOMNESS_INJECT_FULL_SIG_ROUTE: &str = "/randomness-inject-full-sig";
@@ -103,6 +170,11 @@ pub async fn run_admin_server(
         .route(LOGGING_ROUTE, get(get_filter))
         .route(CAPABILITIES, get(capabilities))
         .route(NODE_CONFIG, get(node_config))
+        .route(PROTOCOL_CONFIG, get(protocol_config))
+        .route(METRICS_JSON, get(metrics_json))
+        .route(RECONFIGURING, get(reconfiguring))
+        .route(TRUSTED_PEERS, get(trusted_peers))
+        .route(SUBSCRIPTION_LIMITS, get(subscription_limits))
         .route(LOGGING_ROUTE, post(set_filter))
         .route(
             SET_BUFFER_STAKE_ROUTE,
@@ -113,6 +185,18 @@ pub async fn run_admin_server(
             post(clear_override_protocol_upgrade_buffer_stake),
         )
         .route(FORCE_CLOSE_EPOCH, post(force_close_epoch))
+        .route(
+            DB_CHECKPOINT_PRUNE_AND_COMPACT,
+            post(set_db_checkpoint_prune_and_compact),
+        )
+        .route(SUBSCRIPTION_LIMITS, post(set_subscription_limits))
+        .route(
+            VALIDATOR_TX_FINALIZER,
+            post(set_validator_tx_finalizer_enabled),
+        )
+        .route(REFRESH_TRUSTED_PEERS, post(refresh_trusted_peers))
+        .route(ACTIVATE_VALIDATOR_GRPC, post(activate_validator_grpc))
+        .route(REBUILD_REST_INDEX, post(rebuild_rest_index))
         .route(TRACING_ROUTE, post(enable_tracing))
         .route(TRACING_RESET_ROUTE, post(reset_tracing))
         .route(RANDOMNESS_PARTIAL_SIGS_ROUTE, get(randomness_partial_sigs))
@@ -268,6 +352,165 @@ async fn node_config(State(state): State<Arc<AppState>>) -> (StatusCode, String)
     (StatusCode::OK, format!("{:#?}\n", node_config))
 }
 
+async fn protocol_config(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    match state.node.current_protocol_config_json() {
+        Ok(json) => (StatusCode::OK, format!("{json}\n")),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn metrics_json(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    match state.node.metrics_json() {
+        Ok(json) => (StatusCode::OK, format!("{json}\n")),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn reconfiguring(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    if state.node.is_reconfiguring() {
+        (StatusCode::SERVICE_UNAVAILABLE, "true\n".to_string())
+    } else {
+        (StatusCode::OK, "false\n".to_string())
+    }
+}
+
+async fn trusted_peers(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let mut output = String::new();
+    for peer in state.node.trusted_peers() {
+        output.push_str(&format!("{} {:?}\n", peer.peer_id, peer.address));
+    }
+
+    (StatusCode::OK, output)
+}
+
+async fn refresh_trusted_peers(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let peers = state.node.refresh_trusted_peers();
+    info!("Refreshed {} trusted peers", peers.len());
+    let mut output = String::new();
+    for peer in peers {
+        output.push_str(&format!("{} {:?}\n", peer.peer_id, peer.address));
+    }
+
+    (StatusCode::OK, output)
+}
+
+async fn activate_validator_grpc(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    if state.node.activate_validator_grpc() {
+        info!("Activating the validator gRPC service");
+        (
+            StatusCode::OK,
+            "activated validator gRPC service\n".to_string(),
+        )
+    } else {
+        (
+            StatusCode::OK,
+            "validator gRPC service already activated\n".to_string(),
+        )
+    }
+}
+
+async fn rebuild_rest_index(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    match state.node.rebuild_rest_index() {
+        Ok(()) => (
+            StatusCode::OK,
+            "started rebuilding the rest index\n".to_string(),
+        ),
+        Err(err) => (StatusCode::BAD_REQUEST, format!("{err}\n")),
+    }
+}
+
+#[derive(Deserialize)]
+struct PruneAndCompact {
+    enabled: bool,
+}
+
+async fn set_db_checkpoint_prune_and_compact(
+    State(state): State<Arc<AppState>>,
+    prune_and_compact: Query<PruneAndCompact>,
+) -> (StatusCode, String) {
+    let Query(PruneAndCompact { enabled }) = prune_and_compact;
+
+    match state.node.set_db_checkpoint_prune_and_compact(enabled) {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("set db checkpoint prune and compact to {enabled} for the current epoch\n"),
+        ),
+        Err(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+    }
+}
+
+async fn subscription_limits(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let limits = state.node.subscription_limits();
+    (
+        StatusCode::OK,
+        format!(
+            "max subscriptions: {}\nmax subscriptions per connection: {:?}\nactive subscriptions: {}\n",
+            limits.max_subscriptions(),
+            limits.max_subscriptions_per_connection(),
+            limits.active_subscriptions()
+        ),
+    )
+}
+
+#[derive(Deserialize)]
+struct SubscriptionLimitsUpdate {
+    max_subscriptions: Option<usize>,
+    max_subscriptions_per_connection: Option<usize>,
+}
+
+async fn set_subscription_limits(
+    State(state): State<Arc<AppState>>,
+    update: Query<SubscriptionLimitsUpdate>,
+) -> (StatusCode, String) {
+    let Query(SubscriptionLimitsUpdate {
+        max_subscriptions,
+        max_subscriptions_per_connection,
+    }) = update;
+
+    let limits = state.node.subscription_limits();
+    if let Some(max_subscriptions) = max_subscriptions {
+        limits.set_max_subscriptions(max_subscriptions);
+    }
+    if let Some(max_subscriptions_per_connection) = max_subscriptions_per_connection {
+        limits.set_max_subscriptions_per_connection(
+            (max_subscriptions_per_connection != 0).then_some(max_subscriptions_per_connection),
+        );
+    }
+    info!(
+        max_subscriptions = limits.max_subscriptions(),
+        max_subscriptions_per_connection = ?limits.max_subscriptions_per_connection(),
+        "updated json-rpc subscription limits"
+    );
+    (
+        StatusCode::OK,
+        format!(
+            "set max subscriptions to {} and max subscriptions per connection to {:?}\n",
+            limits.max_subscriptions(),
+            limits.max_subscriptions_per_connection()
+        ),
+    )
+}
+
+#[derive(Deserialize)]
+struct ValidatorTxFinalizerEnabled {
+    enabled: bool,
+}
+
+async fn set_validator_tx_finalizer_enabled(
+    State(state): State<Arc<AppState>>,
+    enabled: Query<ValidatorTxFinalizerEnabled>,
+) -> (StatusCode, String) {
+    let Query(ValidatorTxFinalizerEnabled { enabled }) = enabled;
+
+    match state.node.set_validator_tx_finalizer_enabled(enabled) {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("set validator tx finalizer enabled to {enabled} until the end of the epoch\n"),
+        ),
+        Err(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+    }
+}
+
 #[derive(Deserialize)]
 struct Epoch {
     epoch: u64,
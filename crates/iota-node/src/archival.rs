@@ -0,0 +1,171 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A full node which only syncs checkpoints and uploads them to the state
+//! archive, see [`NodeConfig::archival_only`].
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use iota_archival::reader::ArchiveReaderBalancer;
+use iota_config::{NodeConfig, object_storage_config::ObjectStoreCredentialMetrics};
+use iota_core::{
+    authority::{
+        AuthorityStore, CHAIN_IDENTIFIER, authority_store_tables::AuthorityPerpetualTables,
+        epoch_start_configuration::EpochStartConfigTrait,
+    },
+    checkpoints::CheckpointStore,
+    epoch::committee_store::CommitteeStore,
+    execution_cache::build_execution_cache,
+    storage::RocksDbStore,
+};
+use iota_metrics::RegistryService;
+use iota_network::{discovery, state_sync};
+use iota_types::{
+    base_types::EpochId, crypto::RandomnessRound, digests::ChainIdentifier,
+    messages_checkpoint::CheckpointSequenceNumber,
+};
+use tokio::sync::{Semaphore, broadcast, mpsc, watch};
+use tracing::info;
+use typed_store::{DBMetrics, rocks::default_db_options};
+
+use crate::{IotaNode, config_validation, send_trusted_peer_change};
+
+/// A node running in archival-only mode.
+///
+/// Checkpoints are synced from the network and uploaded to the state archive,
+/// but they are never executed. Hence, no authority state, epoch store or
+/// checkpoint executor is created and the node stays in its starting epoch.
+pub struct ArchivalNode {
+    _state_sync_handle: state_sync::Handle,
+    _discovery: discovery::Handle,
+    _state_archive_handle: Option<broadcast::Sender<()>>,
+    // Kept alive so that the p2p services don't observe closed channels.
+    _trusted_peer_change_tx: watch::Sender<discovery::TrustedPeerChangeEvent>,
+    _highest_executed_checkpoint_tx: watch::Sender<CheckpointSequenceNumber>,
+    _randomness_rx: mpsc::Receiver<(EpochId, RandomnessRound, Vec<u8>)>,
+}
+
+impl ArchivalNode {
+    /// Starts syncing checkpoints from the network and uploading them to the
+    /// state archive.
+    pub async fn start(config: NodeConfig, registry_service: RegistryService) -> Result<Self> {
+        if let Some(error) = config_validation::archival_only_config_errors(&config)
+            .into_iter()
+            .next()
+        {
+            return Err(error);
+        }
+        info!(
+            "starting in archival-only mode, checkpoints are synced and archived but not executed"
+        );
+        let prometheus_registry = registry_service.default_registry();
+
+        let genesis = config.genesis()?.clone();
+        let chain_identifier = ChainIdentifier::from(*genesis.checkpoint().digest());
+        // It's ok if the value is already set due to data races.
+        let _ = CHAIN_IDENTIFIER.set(chain_identifier);
+        info!("IOTA chain identifier: {chain_identifier}");
+
+        DBMetrics::init(&prometheus_registry);
+        ObjectStoreCredentialMetrics::init(&prometheus_registry);
+        iota_metrics::init_metrics(&prometheus_registry);
+
+        let migration_tx_data = if genesis.contains_migrations() {
+            Some(config.load_migration_tx_data()?)
+        } else {
+            None
+        };
+
+        let committee_store = Arc::new(CommitteeStore::new(
+            config.db_path().join("epochs"),
+            &genesis.committee()?,
+            None,
+        ));
+        let perpetual_options = default_db_options().optimize_db_for_write_throughput(4);
+        let perpetual_tables = Arc::new(AuthorityPerpetualTables::open(
+            &config.db_path().join("store"),
+            Some(perpetual_options.options),
+        ));
+        // The store and the execution cache only hold the synced checkpoint
+        // contents, which are read back by the archive writer.
+        let store = AuthorityStore::open(
+            perpetual_tables,
+            &genesis,
+            &config,
+            &prometheus_registry,
+            migration_tx_data.as_ref(),
+        )
+        .await?;
+        let epoch_start_configuration = store
+            .get_epoch_start_configuration()?
+            .expect("EpochStartConfiguration of the current epoch must exist");
+        let cache_traits =
+            build_execution_cache(&epoch_start_configuration, &prometheus_registry, &store);
+
+        info!("creating checkpoint store");
+        let checkpoint_store = CheckpointStore::new(&config.db_path().join("checkpoints"));
+        // Without an epoch store there is no checkpoint builder to seed, so only the
+        // checkpoint itself is inserted.
+        if checkpoint_store
+            .get_checkpoint_by_digest(genesis.checkpoint().digest())?
+            .is_none()
+        {
+            checkpoint_store.insert_checkpoint_contents(genesis.checkpoint_contents().clone())?;
+            let genesis_checkpoint = genesis.checkpoint();
+            checkpoint_store.insert_verified_checkpoint(&genesis_checkpoint)?;
+            checkpoint_store.update_highest_synced_checkpoint(&genesis_checkpoint)?;
+        }
+
+        let state_sync_store = RocksDbStore::new(cache_traits, committee_store, checkpoint_store);
+
+        let archive_readers =
+            ArchiveReaderBalancer::new(config.archive_reader_config(), &prometheus_registry)?;
+        let (trusted_peer_change_tx, trusted_peer_change_rx) = watch::channel(Default::default());
+        // Nothing is executed, so state sync is never held back by execution.
+        let (highest_executed_checkpoint_tx, highest_executed_checkpoint_rx) =
+            watch::channel(CheckpointSequenceNumber::default());
+        let (randomness_tx, randomness_rx) = mpsc::channel(
+            config
+                .p2p_config
+                .randomness
+                .clone()
+                .unwrap_or_default()
+                .mailbox_capacity(),
+        );
+        let signature_verification_limit = config
+            .signature_verification_concurrency_limit
+            .map(|limit| Arc::new(Semaphore::new(limit.get())));
+        let (_p2p_network, discovery_handle, state_sync_handle, _randomness_handle) =
+            IotaNode::create_p2p_network(
+                &config,
+                state_sync_store.clone(),
+                chain_identifier,
+                trusted_peer_change_rx,
+                highest_executed_checkpoint_rx,
+                archive_readers,
+                randomness_tx,
+                signature_verification_limit,
+                &prometheus_registry,
+            )?;
+        send_trusted_peer_change(
+            &config,
+            &trusted_peer_change_tx,
+            epoch_start_configuration.epoch_start_state(),
+        );
+
+        info!("start state archival");
+        let state_archive_handle =
+            IotaNode::start_state_archival(&config, &prometheus_registry, state_sync_store).await?;
+
+        info!("ArchivalNode started!");
+        Ok(Self {
+            _state_sync_handle: state_sync_handle,
+            _discovery: discovery_handle,
+            _state_archive_handle: state_archive_handle,
+            _trusted_peer_change_tx: trusted_peer_change_tx,
+            _highest_executed_checkpoint_tx: highest_executed_checkpoint_tx,
+            _randomness_rx: randomness_rx,
+        })
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of a [`NodeConfig`] without starting the node.
+
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use fastcrypto_zkp::bn254::zk_login::OIDCProvider;
+use iota_config::{
+    NodeConfig,
+    object_storage_config::{ObjectStoreConfig, ObjectStoreType},
+};
+use iota_types::{
+    base_types::AuthorityName,
+    crypto::{IotaKeyPair, KeypairTraits},
+};
+
+/// Checks a [`NodeConfig`] for the problems which would otherwise only
+/// surface when the node is started, and returns all of them.
+///
+/// The checks do not have side effects, i.e., no databases are opened, no
+/// directories are created and no network connections are made.
+pub fn validate_node_config(config: &NodeConfig) -> Vec<anyhow::Error> {
+    let mut errors = vec![];
+
+    if let Err(e) = config.authority_key_pair.try_authority_keypair() {
+        errors.push(e.context("authority key pair"));
+    }
+    for (name, key_pair) in [
+        ("protocol", &config.protocol_key_pair),
+        ("network", &config.network_key_pair),
+    ] {
+        match key_pair.try_keypair() {
+            Ok(IotaKeyPair::Ed25519(_)) => {}
+            Ok(other) => errors.push(anyhow!(
+                "invalid {name} key pair type: {other:?}, only Ed25519 is allowed"
+            )),
+            Err(e) => errors.push(e.context(format!("{name} key pair"))),
+        }
+    }
+    if let Err(e) = config.account_key_pair.try_keypair() {
+        errors.push(e.context("account key pair"));
+    }
+
+    match config.genesis() {
+        Ok(genesis) => {
+            if genesis.contains_migrations() {
+                if let Err(e) = config.load_migration_tx_data() {
+                    errors.push(e.context("migration transaction data"));
+                }
+            }
+            if let Some(consensus_config) = config.consensus_config() {
+                if let Err(e) = validate_validator_config(config, genesis) {
+                    errors.push(e);
+                }
+                if consensus_config.db_path.as_os_str().is_empty() {
+                    errors.push(anyhow!("consensus db path is empty"));
+                }
+            }
+        }
+        Err(e) => errors.push(e.context("genesis")),
+    }
+
+    errors.extend(archival_only_config_errors(config));
+
+    let object_store_configs = [
+        (
+            "db checkpoint",
+            config.db_checkpoint_config.object_store_config.as_ref(),
+        ),
+        (
+            "state archive write",
+            config
+                .state_archive_write_config
+                .object_store_config
+                .as_ref(),
+        ),
+        (
+            "state snapshot write",
+            config
+                .state_snapshot_write_config
+                .object_store_config
+                .as_ref(),
+        ),
+    ]
+    .into_iter()
+    .chain(
+        config
+            .state_archive_read_config
+            .iter()
+            .map(|c| ("state archive read", c.object_store_config.as_ref())),
+    );
+    for (name, object_store_config) in object_store_configs {
+        if let Some(object_store_config) = object_store_config {
+            if let Err(e) = validate_object_store_config(object_store_config) {
+                errors.push(e.context(format!("{name} object store config")));
+            }
+        }
+    }
+
+    for provider in config.zklogin_jwk_kid_policies.keys() {
+        if OIDCProvider::from_str(provider).is_err() {
+            errors.push(anyhow!(
+                "invalid zklogin provider {provider:?} in zklogin_jwk_kid_policies"
+            ));
+        }
+    }
+
+    let base_url = &config.transaction_kv_store_read_config.base_url;
+    if !base_url.is_empty() {
+        if let Err(e) = base_url.parse::<url::Url>() {
+            errors.push(anyhow!(
+                "failed to parse transaction_kv_store_read_config.base_url ({base_url:?}) as url: {e}"
+            ));
+        }
+    }
+
+    errors
+}
+
+/// Returns the problems of an archival-only [`NodeConfig`], if the node is
+/// configured to run in this mode.
+pub(crate) fn archival_only_config_errors(config: &NodeConfig) -> Vec<anyhow::Error> {
+    let mut errors = vec![];
+    if !config.archival_only {
+        return errors;
+    }
+    if config.consensus_config().is_some() {
+        errors.push(anyhow!(
+            "archival-only mode is not supported for validators"
+        ));
+    }
+    if config
+        .state_archive_write_config
+        .object_store_config
+        .is_none()
+    {
+        errors.push(anyhow!(
+            "archival-only mode requires a state archive write config"
+        ));
+    }
+    if config
+        .p2p_config
+        .state_sync
+        .as_ref()
+        .is_some_and(|state_sync| state_sync.max_checkpoints_ahead_of_execution.is_some())
+    {
+        errors.push(anyhow!(
+            "max_checkpoints_ahead_of_execution is not supported in archival-only mode"
+        ));
+    }
+    errors
+}
+
+/// Checks that the validator is a member of the genesis committee.
+fn validate_validator_config(
+    config: &NodeConfig,
+    genesis: &iota_config::genesis::Genesis,
+) -> Result<()> {
+    let authority_key_pair = config.authority_key_pair.try_authority_keypair()?;
+    let authority_public_key: AuthorityName = authority_key_pair.public().into();
+    let committee = genesis.committee()?;
+    if !committee.authority_exists(&authority_public_key) {
+        return Err(anyhow!(
+            "authority key {authority_public_key} is not part of the genesis committee"
+        ));
+    }
+    Ok(())
+}
+
+fn validate_object_store_config(config: &ObjectStoreConfig) -> Result<()> {
+    match config.object_store {
+        // Building a local store creates its directory, so only check that one is set
+        Some(ObjectStoreType::File) => config
+            .directory
+            .as_ref()
+            .map(|_| ())
+            .ok_or_else(|| anyhow!("no directory provided for local fs storage")),
+        Some(_) => config.make().map(|_| ()),
+        None => Err(anyhow!("no object store type provided")),
+    }
+}
@@ -2,16 +2,18 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(msim)]
-use std::sync::atomic::Ordering;
 use std::{
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::{BTreeSet, HashMap},
     fmt,
-    net::SocketAddr,
+    net::{IpAddr, SocketAddr},
+    num::NonZeroUsize,
     path::PathBuf,
     str::FromStr,
-    sync::{Arc, Weak},
-    time::Duration,
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
 use anemo::Network;
@@ -20,17 +22,22 @@ use anemo_tower::{
     trace::{DefaultMakeSpan, DefaultOnFailure, TraceLayer},
 };
 use anyhow::{Result, anyhow};
-use arc_swap::ArcSwap;
+use arc_swap::{ArcSwap, ArcSwapOption};
+pub use archival::ArchivalNode;
 use fastcrypto_zkp::bn254::zk_login::{JWK, JwkId, OIDCProvider};
 use futures::TryFutureExt;
 pub use handle::IotaNodeHandle;
 use iota_archival::{reader::ArchiveReaderBalancer, writer::ArchiveWriter};
 use iota_config::{
     ConsensusConfig, NodeConfig,
-    node::{DBCheckpointConfig, RunWithRange},
+    node::{
+        CommitteeRemovalPolicy, DBCheckpointConfig, GrpcBindConfig, JwkKidPolicy, RunWithRange,
+    },
     node_config_metrics::NodeConfigMetrics,
-    object_storage_config::{ObjectStoreConfig, ObjectStoreType},
+    object_storage_config::{ObjectStoreConfig, ObjectStoreCredentialMetrics, ObjectStoreType},
 };
+#[cfg(msim)]
+use iota_core::consensus_adapter::SubmissionOverride;
 use iota_core::{
     authority::{
         AuthorityState, AuthorityStore, CHAIN_IDENTIFIER, RandomnessRoundReceiver,
@@ -60,7 +67,7 @@ use iota_core::{
         epoch_metrics::EpochMetrics, randomness::RandomnessManager,
         reconfiguration::ReconfigurationInitiator,
     },
-    execution_cache::build_execution_cache,
+    execution_cache::{ExecutionCacheReconfigAPI, build_execution_cache},
     module_cache_metrics::ResolverMetrics,
     overload_monitor::overload_monitor,
     rest_index::RestIndexStore,
@@ -68,14 +75,19 @@ use iota_core::{
     signature_verifier::SignatureVerifierMetrics,
     state_accumulator::{StateAccumulator, StateAccumulatorMetrics},
     storage::{RestReadStore, RocksDbStore},
-    traffic_controller::metrics::TrafficControllerMetrics,
+    traffic_controller::{TrafficController, metrics::TrafficControllerMetrics},
     transaction_orchestrator::TransactionOrchestrator,
     validator_tx_finalizer::ValidatorTxFinalizer,
 };
 use iota_json_rpc::{
-    JsonRpcServerBuilder, bridge_api::BridgeReadApi, coin_api::CoinReadApi,
-    governance_api::GovernanceReadApi, indexer_api::IndexerApi, move_utils::MoveUtils,
-    read_api::ReadApi, transaction_builder_api::TransactionBuilderApi,
+    JsonRpcServerBuilder,
+    bridge_api::BridgeReadApi,
+    coin_api::CoinReadApi,
+    governance_api::GovernanceReadApi,
+    indexer_api::{IndexerApi, SubscriptionLimits},
+    move_utils::MoveUtils,
+    read_api::ReadApi,
+    transaction_builder_api::TransactionBuilderApi,
     transaction_execution_api::TransactionExecutionApi,
 };
 use iota_json_rpc_api::JsonRpcMetrics;
@@ -99,18 +111,23 @@ use iota_storage::{
     key_value_store::{FallbackTransactionKVStore, TransactionKeyValueStore},
     key_value_store_metrics::KeyValueStoreMetrics,
 };
+#[cfg(msim)]
+use iota_types::digests::TransactionDigest;
 use iota_types::{
     base_types::{AuthorityName, ConciseableName, EpochId},
     committee::Committee,
     crypto::{KeypairTraits, RandomnessRound},
     digests::ChainIdentifier,
+    effects::{TransactionEffects, TransactionEffectsAPI},
     error::{IotaError, IotaResult},
     execution_config_utils::to_binary_config,
     iota_system_state::{
         IotaSystemState, IotaSystemStateTrait,
         epoch_start_iota_system_state::{EpochStartSystemState, EpochStartSystemStateTrait},
     },
+    messages_checkpoint::CheckpointSequenceNumber,
     messages_consensus::{AuthorityCapabilitiesV1, ConsensusTransaction, check_total_jwk_size},
+    multiaddr::Multiaddr,
     quorum_driver_types::QuorumDriverEffectsQueueResult,
     supported_protocol_versions::SupportedProtocolVersions,
     transaction::Transaction,
@@ -123,7 +140,7 @@ use simulator::*;
 use tap::tap::TapFallible;
 use tokio::{
     runtime::Handle,
-    sync::{Mutex, broadcast, mpsc, watch},
+    sync::{Mutex, Semaphore, broadcast, mpsc, watch},
     task::{JoinHandle, JoinSet},
 };
 use tower::ServiceBuilder;
@@ -133,11 +150,14 @@ use typed_store::{DBMetrics, rocks::default_db_options};
 use crate::metrics::{GrpcMetrics, IotaNodeMetrics};
 
 pub mod admin;
+mod archival;
+pub mod config_validation;
 mod handle;
 pub mod metrics;
 
 pub struct ValidatorComponents {
     validator_server_handle: JoinHandle<Result<()>>,
+    traffic_controller: Option<Arc<TrafficController>>,
     validator_overload_monitor_handle: Option<JoinHandle<()>>,
     consensus_manager: ConsensusManager,
     consensus_store_pruner: ConsensusStorePruner,
@@ -225,8 +245,10 @@ pub struct IotaNode {
     /// Broadcast channel to notify [`DiscoveryEventLoop`] for new validator
     /// peers.
     trusted_peer_change_tx: watch::Sender<TrustedPeerChangeEvent>,
+    highest_executed_checkpoint_tx: watch::Sender<CheckpointSequenceNumber>,
 
     _db_checkpoint_handle: Option<tokio::sync::broadcast::Sender<()>>,
+    db_checkpoint_handler: Option<Arc<DBCheckpointHandler>>,
 
     #[cfg(msim)]
     sim_state: SimState,
@@ -243,6 +265,24 @@ pub struct IotaNode {
     // TODO: Eventually we can make this auth aggregator a shared reference so that this
     // update will automatically propagate to other uses.
     auth_agg: Arc<ArcSwap<AuthorityAggregator<NetworkAuthorityClient>>>,
+
+    /// Set while the node transitions to the next epoch, i.e. from the
+    /// execution of the last checkpoint of the epoch until the components of
+    /// the new epoch are running.
+    is_reconfiguring: AtomicBool,
+
+    /// Holds `true` once the validator gRPC service may bind its network
+    /// address. Only starts out as `false` if `grpc_deferred_bind` is set.
+    grpc_activation_tx: watch::Sender<bool>,
+
+    /// The capabilities this validator submitted to consensus most recently.
+    last_advertised_capabilities: ArcSwapOption<AuthorityCapabilitiesV1>,
+
+    authority_store: Arc<AuthorityStore>,
+    /// Set while the rest index is rebuilt in the background.
+    is_rebuilding_rest_index: Arc<AtomicBool>,
+    /// Limits on the json-rpc subscriptions, shared with the `IndexerApi`.
+    subscription_limits: Arc<SubscriptionLimits>,
 }
 
 impl fmt::Debug for IotaNode {
@@ -284,8 +324,19 @@ impl IotaNode {
             .iter()
             .map(|s| OIDCProvider::from_str(s).expect("Invalid provider string"))
             .collect::<Vec<_>>();
+        let kid_policies = config
+            .zklogin_jwk_kid_policies
+            .iter()
+            .map(|(s, policy)| {
+                (
+                    OIDCProvider::from_str(s).expect("Invalid provider string"),
+                    policy.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
 
         let fetch_interval = Duration::from_secs(config.jwk_fetch_interval_seconds);
+        let submission_interval = config.jwk_submission_interval_ms.map(Duration::from_millis);
 
         info!(
             ?fetch_interval,
@@ -295,6 +346,7 @@ impl IotaNode {
         fn validate_jwk(
             metrics: &Arc<IotaNodeMetrics>,
             provider: &OIDCProvider,
+            kid_policy: Option<&JwkKidPolicy>,
             id: &JwkId,
             jwk: &JWK,
         ) -> bool {
@@ -331,6 +383,18 @@ impl IotaNode {
                 return false;
             }
 
+            if kid_policy.is_some_and(|policy| !policy.accepts(&id.kid)) {
+                warn!(
+                    "JWK {:?} (retrieved from {:?}) is rejected by the key id policy",
+                    id, provider
+                );
+                metrics
+                    .jwks_rejected_by_kid_policy
+                    .with_label_values(&[&provider.to_string()])
+                    .inc();
+                return false;
+            }
+
             true
         }
 
@@ -347,11 +411,19 @@ impl IotaNode {
             let epoch_store = epoch_store.clone();
             let consensus_adapter = consensus_adapter.clone();
             let metrics = metrics.clone();
+            let kid_policy = kid_policies
+                .iter()
+                .find(|(provider, _)| *provider == p)
+                .map(|(_, policy)| policy.clone());
             spawn_monitored_task!(epoch_store.clone().within_alive_epoch(
                 async move {
                     // note: restart-safe de-duplication happens after consensus, this is
-                    // just best-effort to reduce unneeded submissions.
-                    let mut seen = HashSet::new();
+                    // just best-effort to reduce unneeded submissions. The keys submitted
+                    // before a restart in this epoch are persisted in the epoch store.
+                    let mut seen = epoch_store
+                        .get_submitted_jwks()
+                        .tap_err(|e| warn!("Failed to load submitted JWKs {:?}", e))
+                        .unwrap_or_default();
                     loop {
                         info!("fetching JWK for provider {:?}", p);
                         metrics.jwk_requests.with_label_values(&[&provider_str]).inc();
@@ -369,7 +441,7 @@ impl IotaNode {
                                     .inc_by(keys.len() as u64);
 
                                 keys.retain(|(id, jwk)| {
-                                    validate_jwk(&metrics, &p, id, jwk) &&
+                                    validate_jwk(&metrics, &p, kid_policy.as_ref(), id, jwk) &&
                                     !epoch_store.jwk_active_in_current_epoch(id, jwk) &&
                                     seen.insert((id.clone(), jwk.clone()))
                                 });
@@ -385,13 +457,24 @@ impl IotaNode {
                                     keys.truncate(MAX_JWK_KEYS_PER_FETCH);
                                 }
 
-                                for (id, jwk) in keys.into_iter() {
+                                // Wait the submission interval before every key but the first.
+                                let delays = std::iter::once(None).chain(std::iter::repeat(submission_interval));
+                                for (delay, (id, jwk)) in delays.zip(keys) {
+                                    if let Some(delay) = delay {
+                                        tokio::time::sleep(delay).await;
+                                    }
                                     info!("Submitting JWK to consensus: {:?}", id);
 
+                                    let key = (id.clone(), jwk.clone());
                                     let txn = ConsensusTransaction::new_jwk_fetched(authority, id, jwk);
-                                    consensus_adapter.submit(txn, None, &epoch_store)
-                                        .tap_err(|e| warn!("Error when submitting JWKs to consensus {:?}", e))
-                                        .ok();
+                                    match consensus_adapter.submit(txn, None, &epoch_store) {
+                                        Ok(_) => {
+                                            if let Err(e) = epoch_store.insert_submitted_jwks([key]) {
+                                                warn!("Failed to persist submitted JWK {:?}", e);
+                                            }
+                                        }
+                                        Err(e) => warn!("Error when submitting JWKs to consensus {:?}", e),
+                                    }
                                 }
                             }
                         }
@@ -422,6 +505,9 @@ impl IotaNode {
         let run_with_range = config.run_with_range;
         let is_validator = config.consensus_config().is_some();
         let is_full_node = !is_validator;
+        if config.archival_only {
+            anyhow::bail!("archival-only nodes are started with `ArchivalNode::start`");
+        }
         let prometheus_registry = registry_service.default_registry();
 
         info!(node =? config.authority_public_key(),
@@ -437,6 +523,9 @@ impl IotaNode {
 
         // Initialize metrics to track db usage before creating any stores
         DBMetrics::init(&prometheus_registry);
+        // Initialize metrics for credential refreshes of the archive and
+        // snapshot stores
+        ObjectStoreCredentialMetrics::init(&prometheus_registry);
 
         // Initialize IOTA metrics.
         iota_metrics::init_metrics(&prometheus_registry);
@@ -522,6 +611,11 @@ impl IotaNode {
             )))
         };
 
+        // Shared by the signature verifiers of all epochs and the checkpoint
+        // verification of state sync.
+        let signature_verification_limit = config
+            .signature_verification_concurrency_limit
+            .map(|limit| Arc::new(Semaphore::new(limit.get())));
         let epoch_options = default_db_options().optimize_db_for_write_throughput(4);
         let epoch_store = AuthorityPerEpochStore::new(
             config.authority_public_key(),
@@ -534,6 +628,7 @@ impl IotaNode {
             cache_traits.object_store.clone(),
             cache_metrics,
             signature_verifier_metrics,
+            signature_verification_limit.clone(),
             &config.expensive_safety_check_config,
             ChainIdentifier::from(*genesis.checkpoint().digest()),
         );
@@ -547,7 +642,11 @@ impl IotaNode {
         );
 
         // the database is empty at genesis time
-        if is_genesis {
+        let run_genesis_conservation_check_in_background = is_genesis
+            && config
+                .expensive_safety_check_config
+                .run_genesis_iota_conservation_check_in_background();
+        if is_genesis && !run_genesis_conservation_check_in_background {
             info!("checking IOTA conservation at genesis");
             // When we are opening the db table, the only time when it's safe to
             // check IOTA conservation is at genesis. Otherwise we may be in the middle of
@@ -555,7 +654,7 @@ impl IotaNode {
             // the expected_network_iota_amount table.
             cache_traits
                 .reconfig_api
-                .expensive_check_iota_conservation(&epoch_store, None)
+                .expensive_check_iota_conservation(&epoch_store, None, false)
                 .expect("IOTA conservation check cannot fail at genesis");
         }
 
@@ -601,7 +700,10 @@ impl IotaNode {
             None
         };
 
-        let rest_index = if is_full_node && config.enable_rest_api && config.enable_index_processing
+        let rest_index = if is_full_node
+            && config.enable_rest_api
+            && config.enable_index_processing
+            && !config.disable_rest_index
         {
             Some(Arc::new(RestIndexStore::new(
                 config.db_path().join("rest_index"),
@@ -622,6 +724,11 @@ impl IotaNode {
         let archive_readers =
             ArchiveReaderBalancer::new(config.archive_reader_config(), &prometheus_registry)?;
         let (trusted_peer_change_tx, trusted_peer_change_rx) = watch::channel(Default::default());
+        let (highest_executed_checkpoint_tx, highest_executed_checkpoint_rx) = watch::channel(
+            checkpoint_store
+                .get_highest_executed_checkpoint_seq_number()?
+                .unwrap_or_default(),
+        );
         let (randomness_tx, randomness_rx) = mpsc::channel(
             config
                 .p2p_config
@@ -636,8 +743,10 @@ impl IotaNode {
                 state_sync_store.clone(),
                 chain_identifier,
                 trusted_peer_change_rx,
+                highest_executed_checkpoint_rx,
                 archive_readers.clone(),
                 randomness_tx,
+                signature_verification_limit,
                 &prometheus_registry,
             )?;
 
@@ -662,11 +771,12 @@ impl IotaNode {
 
         // Start uploading db checkpoints to remote store
         info!("start db checkpoint");
-        let (db_checkpoint_config, db_checkpoint_handle) = Self::start_db_checkpoint(
-            &config,
-            &prometheus_registry,
-            state_snapshot_handle.is_some(),
-        )?;
+        let (db_checkpoint_config, db_checkpoint_handler, db_checkpoint_handle) =
+            Self::start_db_checkpoint(
+                &config,
+                &prometheus_registry,
+                state_snapshot_handle.is_some(),
+            )?;
 
         let mut genesis_objects = genesis.objects().to_vec();
         if let Some(migration_tx_data) = migration_tx_data.as_ref() {
@@ -705,6 +815,9 @@ impl IotaNode {
         )
         .await;
 
+        let iota_node_metrics =
+            Arc::new(IotaNodeMetrics::new(&registry_service.default_registry()));
+
         // ensure genesis and migration txs were executed
         if epoch_store.epoch() == 0 {
             let genesis_tx = &genesis.transaction();
@@ -718,17 +831,32 @@ impl IotaNode {
             )
             .await;
 
-            // Execute migration transactions if present
+            // Execute migration transactions if present, any failure here leaves the
+            // network in an unexpected state so we abort with the failing digest
             if let Some(migration_tx_data) = migration_tx_data {
                 for (tx_digest, (tx, _, _)) in migration_tx_data.txs_data() {
                     let span = error_span!("migration_txn", tx_digest = ?tx_digest);
-                    Self::execute_transaction_immediately_at_zero_epoch(
+                    let effects = Self::execute_transaction_immediately_at_zero_epoch(
                         &state,
                         &epoch_store,
                         tx,
                         span,
                     )
                     .await;
+                    if !effects.status().is_ok() {
+                        error!(
+                            ?tx_digest,
+                            status = ?effects.status(),
+                            "Migration transaction failed to execute at genesis"
+                        );
+                        iota_node_metrics
+                            .genesis_migration_transactions_failed
+                            .inc();
+                        panic!(
+                            "migration transaction {tx_digest:?} failed at genesis with status {:?}",
+                            effects.status()
+                        );
+                    }
                 }
             }
         }
@@ -769,6 +897,10 @@ impl IotaNode {
             None
         };
 
+        let subscription_limits = SubscriptionLimits::new(
+            config.indexer_max_subscriptions,
+            config.indexer_max_subscriptions_per_connection,
+        );
         let http_server = build_http_server(
             state.clone(),
             state_sync_store,
@@ -777,6 +909,7 @@ impl IotaNode {
             &prometheus_registry,
             custom_rpc_runtime,
             software_version,
+            subscription_limits.clone(),
         )
         .await?;
 
@@ -807,8 +940,8 @@ impl IotaNode {
         };
 
         let connection_monitor_status = Arc::new(connection_monitor_status);
-        let iota_node_metrics =
-            Arc::new(IotaNodeMetrics::new(&registry_service.default_registry()));
+
+        let (grpc_activation_tx, _) = watch::channel(!config.grpc_deferred_bind);
 
         let validator_components = if state.is_validator(&epoch_store) {
             let components = Self::construct_validator_components(
@@ -823,10 +956,17 @@ impl IotaNode {
                 connection_monitor_status.clone(),
                 &registry_service,
                 iota_node_metrics.clone(),
+                grpc_activation_tx.subscribe(),
             )
             .await?;
             // This is only needed during cold start.
-            components.consensus_adapter.submit_recovered(&epoch_store);
+            if config.skip_submit_recovered_on_startup {
+                warn!(
+                    "Skipping the resubmission of recovered consensus transactions, as configured"
+                );
+            } else {
+                components.consensus_adapter.submit_recovered(&epoch_store);
+            }
 
             Some(components)
         } else {
@@ -853,8 +993,10 @@ impl IotaNode {
             end_of_epoch_channel,
             connection_monitor_status,
             trusted_peer_change_tx,
+            highest_executed_checkpoint_tx,
 
             _db_checkpoint_handle: db_checkpoint_handle,
+            db_checkpoint_handler,
 
             #[cfg(msim)]
             sim_state: Default::default(),
@@ -864,10 +1006,24 @@ impl IotaNode {
             shutdown_channel_tx: shutdown_channel,
 
             auth_agg,
+            is_reconfiguring: AtomicBool::new(false),
+            grpc_activation_tx,
+            last_advertised_capabilities: ArcSwapOption::empty(),
+
+            authority_store: store,
+            is_rebuilding_rest_index: Arc::new(AtomicBool::new(false)),
+            subscription_limits,
         };
 
         info!("IotaNode started!");
         let node = Arc::new(node);
+        if run_genesis_conservation_check_in_background {
+            Self::spawn_genesis_conservation_check(
+                &node,
+                cache_traits.reconfig_api.clone(),
+                epoch_store.clone(),
+            );
+        }
         let node_copy = node.clone();
         spawn_monitored_task!(async move {
             let result = Self::monitor_reconfiguration(node_copy).await;
@@ -879,10 +1035,192 @@ impl IotaNode {
         Ok(node)
     }
 
+    /// Runs the genesis IOTA conservation check in a blocking background task
+    /// so that startup isn't gated on it. The outcome is logged and reported
+    /// via metrics, and the node is shut down on failure if configured so.
+    ///
+    /// Execution continues while the live object set is scanned, so the check
+    /// doesn't persist the expected storage fund imbalance or total supply.
+    /// They are recorded by the first end of epoch check instead.
+    fn spawn_genesis_conservation_check(
+        node: &Arc<IotaNode>,
+        reconfig_api: Arc<dyn ExecutionCacheReconfigAPI>,
+        epoch_store: Arc<AuthorityPerEpochStore>,
+    ) {
+        info!("checking IOTA conservation at genesis in the background");
+        let metrics = node.metrics.clone();
+        let shutdown_on_failure = node
+            .config
+            .expensive_safety_check_config
+            .shutdown_on_genesis_iota_conservation_check_failure();
+        let shutdown_channel_tx = node.shutdown_channel_tx.clone();
+        spawn_monitored_task!(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                reconfig_api.expensive_check_iota_conservation(&epoch_store, None, true)
+            })
+            .await
+            .expect("IOTA conservation check task panicked");
+            match result {
+                Ok(()) => {
+                    info!("background IOTA conservation check at genesis succeeded");
+                    metrics.genesis_iota_conservation_check_failed.set(0);
+                }
+                Err(err) => {
+                    error!("background IOTA conservation check at genesis failed: {err:?}");
+                    metrics.genesis_iota_conservation_check_failed.set(1);
+                    if shutdown_on_failure {
+                        error!("shutting down the node after failed IOTA conservation check");
+                        // The receivers may already be gone if the node is shutting down.
+                        let _ = shutdown_channel_tx.send(None);
+                    }
+                }
+            }
+        });
+    }
+
     pub fn subscribe_to_epoch_change(&self) -> broadcast::Receiver<IotaSystemState> {
         self.end_of_epoch_channel.subscribe()
     }
 
+    /// Whether the node is currently transitioning to the next epoch. Requests
+    /// served during the transition may fail transiently.
+    pub fn is_reconfiguring(&self) -> bool {
+        self.is_reconfiguring.load(Ordering::Relaxed)
+    }
+
+    /// The committee peers which were last sent to the discovery layer as
+    /// trusted peers.
+    pub fn trusted_peers(&self) -> Vec<anemo::types::PeerInfo> {
+        self.trusted_peer_change_tx.borrow().new_committee.clone()
+    }
+
+    /// Resends the committee peers of the start state of the current epoch to
+    /// the discovery layer, which updates the addresses of the peers it
+    /// already knows. On-chain validator metadata changes made during the
+    /// epoch are not picked up, they only take effect at the next epoch.
+    /// Returns the sent peers.
+    pub fn refresh_trusted_peers(&self) -> Vec<anemo::types::PeerInfo> {
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        send_trusted_peer_change(
+            &self.config,
+            &self.trusted_peer_change_tx,
+            epoch_store.epoch_start_state(),
+        );
+        self.trusted_peers()
+    }
+
+    /// Starts rebuilding the rest index from the checkpoint store and the live
+    /// object set in the background. The current index keeps serving reads
+    /// until the rebuilt one replaces it.
+    pub fn rebuild_rest_index(&self) -> Result<()> {
+        let rest_index = self
+            .state
+            .rest_index
+            .clone()
+            .ok_or_else(|| anyhow!("rest index is disabled"))?;
+        if self.is_rebuilding_rest_index.swap(true, Ordering::SeqCst) {
+            anyhow::bail!("rest index is already being rebuilt");
+        }
+
+        info!("rebuilding the rest index in the background");
+        let is_rebuilding = self.is_rebuilding_rest_index.clone();
+        let authority_store = self.authority_store.clone();
+        let checkpoint_store = self.checkpoint_store.clone();
+        let epoch_store = self.state.load_epoch_store_one_call_per_task().clone();
+        let package_store = self.state.get_backing_package_store().clone();
+        let object_cache_reader = self.state.get_object_cache_reader().clone();
+        let transaction_cache_reader = self.state.get_transaction_cache_reader().clone();
+        spawn_monitored_task!(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                rest_index.rebuild(
+                    &authority_store,
+                    &checkpoint_store,
+                    &epoch_store,
+                    &package_store,
+                    object_cache_reader.as_ref(),
+                    transaction_cache_reader.as_ref(),
+                )
+            })
+            .await
+            .expect("rest index rebuild task panicked");
+            match result {
+                Ok(()) => info!("rebuilding the rest index succeeded"),
+                Err(err) => error!("rebuilding the rest index failed: {err:?}"),
+            }
+            is_rebuilding.store(false, Ordering::SeqCst);
+        });
+        Ok(())
+    }
+
+    /// Overrides whether the db checkpoint taken at the end of the current
+    /// epoch is pruned and compacted before being uploaded.
+    pub fn set_db_checkpoint_prune_and_compact(&self, enabled: bool) -> Result<()> {
+        let db_checkpoint_handler = self
+            .db_checkpoint_handler
+            .as_ref()
+            .ok_or_else(|| anyhow!("db checkpoint handler is not running"))?;
+        let epoch = self.state.load_epoch_store_one_call_per_task().epoch();
+        db_checkpoint_handler.set_prune_and_compact_before_upload(epoch, enabled);
+        Ok(())
+    }
+
+    /// Pauses or resumes the validator tx finalizer until the end of the
+    /// current epoch. Fails if the finalizer is disabled in the node config.
+    pub fn set_validator_tx_finalizer_enabled(&self, enabled: bool) -> Result<()> {
+        let validator_tx_finalizer =
+            self.state.validator_tx_finalizer.as_ref().ok_or_else(|| {
+                anyhow!("validator tx finalizer is not enabled in the node config")
+            })?;
+        validator_tx_finalizer.set_enabled(enabled);
+        Ok(())
+    }
+
+    /// The limits on the json-rpc subscriptions served by this node. Changing
+    /// them only affects new subscriptions.
+    pub fn subscription_limits(&self) -> &SubscriptionLimits {
+        &self.subscription_limits
+    }
+
+    /// The capabilities this node most recently advertised to the committee,
+    /// or `None` if it never advertised any, i.e. it was never a validator.
+    pub fn last_advertised_capabilities(&self) -> Option<AuthorityCapabilitiesV1> {
+        self.last_advertised_capabilities
+            .load_full()
+            .map(|capabilities| (*capabilities).clone())
+    }
+
+    /// Serializes the protocol config of the current epoch, including all
+    /// feature flags and limits, to pretty-printed JSON.
+    pub fn current_protocol_config_json(&self) -> Result<String> {
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        Ok(serde_json::to_string_pretty(epoch_store.protocol_config())?)
+    }
+
+    /// Serializes the current values of all metrics of the node to
+    /// pretty-printed JSON, see [`metrics::metric_families_to_json`].
+    pub fn metrics_json(&self) -> Result<String> {
+        let families = self.registry_service.gather_all();
+        Ok(serde_json::to_string_pretty(
+            &metrics::metric_families_to_json(&families),
+        )?)
+    }
+
+    /// Allows the validator gRPC service to bind its network address if it was
+    /// deferred with `grpc_deferred_bind`. Returns `false` if the service was
+    /// already activated.
+    pub fn activate_validator_grpc(&self) -> bool {
+        self.grpc_activation_tx.send_if_modified(|activated| {
+            let was_activated = std::mem::replace(activated, true);
+            !was_activated
+        })
+    }
+
+    /// Whether the validator gRPC service is allowed to bind its network
+    /// address.
+    pub fn is_validator_grpc_activated(&self) -> bool {
+        *self.grpc_activation_tx.borrow()
+    }
+
     pub fn subscribe_to_shutdown_channel(&self) -> broadcast::Receiver<Option<RunWithRange>> {
         self.shutdown_channel_tx.subscribe()
     }
@@ -963,12 +1301,16 @@ impl IotaNode {
         prometheus_registry: &Registry,
         checkpoint_store: Arc<CheckpointStore>,
     ) -> Result<Option<tokio::sync::broadcast::Sender<()>>> {
-        if let Some(remote_store_config) = &config.state_snapshot_write_config.object_store_config {
+        let snapshot_config = &config.state_snapshot_write_config;
+        if let Some(remote_store_config) = &snapshot_config.object_store_config {
             let snapshot_uploader = StateSnapshotUploader::new(
                 &config.db_checkpoint_path(),
                 &config.snapshot_path(),
                 remote_store_config.clone(),
                 60,
+                NonZeroUsize::new(snapshot_config.concurrency)
+                    .unwrap_or(NonZeroUsize::new(20).unwrap()),
+                snapshot_config.per_file_concurrency,
                 prometheus_registry,
                 checkpoint_store,
             )?;
@@ -984,6 +1326,7 @@ impl IotaNode {
         state_snapshot_enabled: bool,
     ) -> Result<(
         DBCheckpointConfig,
+        Option<Arc<DBCheckpointHandler>>,
         Option<tokio::sync::broadcast::Sender<()>>,
     )> {
         let checkpoint_path = Some(
@@ -1017,7 +1360,7 @@ impl IotaNode {
             // state snapshot object store is specified, create handler
             // anyway for marking db checkpoints as completed so that they
             // can be uploaded as state snapshots.
-            (None, false) => Ok((db_checkpoint_config, None)),
+            (None, false) => Ok((db_checkpoint_config, None, None)),
             (_, _) => {
                 let handler = DBCheckpointHandler::new(
                     &db_checkpoint_config.checkpoint_path.clone().unwrap(),
@@ -1033,6 +1376,7 @@ impl IotaNode {
                 )?;
                 Ok((
                     db_checkpoint_config,
+                    Some(handler.clone()),
                     Some(DBCheckpointHandler::start(handler)),
                 ))
             }
@@ -1044,8 +1388,10 @@ impl IotaNode {
         state_sync_store: RocksDbStore,
         chain_identifier: ChainIdentifier,
         trusted_peer_change_rx: watch::Receiver<TrustedPeerChangeEvent>,
+        highest_executed_checkpoint_rx: watch::Receiver<CheckpointSequenceNumber>,
         archive_readers: ArchiveReaderBalancer,
         randomness_tx: mpsc::Sender<(EpochId, RandomnessRound, Vec<u8>)>,
+        signature_verification_limit: Option<Arc<Semaphore>>,
         prometheus_registry: &Registry,
     ) -> Result<(
         Network,
@@ -1057,6 +1403,8 @@ impl IotaNode {
             .config(config.p2p_config.state_sync.clone().unwrap_or_default())
             .store(state_sync_store)
             .archive_readers(archive_readers)
+            .highest_executed_checkpoint(highest_executed_checkpoint_rx)
+            .signature_verification_limit(signature_verification_limit)
             .with_metrics(prometheus_registry)
             .build();
 
@@ -1191,6 +1539,7 @@ impl IotaNode {
         connection_monitor_status: Arc<ConnectionMonitorStatus>,
         registry_service: &RegistryService,
         iota_node_metrics: Arc<IotaNodeMetrics>,
+        grpc_activation_rx: watch::Receiver<bool>,
     ) -> Result<ValidatorComponents> {
         let mut config_clone = config.clone();
         let consensus_config = config_clone
@@ -1223,11 +1572,12 @@ impl IotaNode {
         let iota_tx_validator_metrics =
             IotaTxValidatorMetrics::new(&registry_service.default_registry());
 
-        let validator_server_handle = Self::start_grpc_validator_service(
+        let (validator_server_handle, traffic_controller) = Self::start_grpc_validator_service(
             &config,
             state.clone(),
             consensus_adapter.clone(),
             &registry_service.default_registry(),
+            grpc_activation_rx,
         )
         .await?;
 
@@ -1261,6 +1611,7 @@ impl IotaNode {
             consensus_store_pruner,
             accumulator,
             validator_server_handle,
+            traffic_controller,
             validator_overload_monitor_handle,
             checkpoint_metrics,
             iota_node_metrics,
@@ -1283,6 +1634,7 @@ impl IotaNode {
         consensus_store_pruner: ConsensusStorePruner,
         accumulator: Weak<StateAccumulator>,
         validator_server_handle: JoinHandle<Result<()>>,
+        traffic_controller: Option<Arc<TrafficController>>,
         validator_overload_monitor_handle: Option<JoinHandle<()>>,
         checkpoint_metrics: Arc<CheckpointMetrics>,
         iota_node_metrics: Arc<IotaNodeMetrics>,
@@ -1353,6 +1705,7 @@ impl IotaNode {
 
         Ok(ValidatorComponents {
             validator_server_handle,
+            traffic_controller,
             validator_overload_monitor_handle,
             consensus_manager,
             consensus_store_pruner,
@@ -1446,7 +1799,11 @@ impl IotaNode {
         state: Arc<AuthorityState>,
         consensus_adapter: Arc<ConsensusAdapter>,
         prometheus_registry: &Registry,
-    ) -> Result<tokio::task::JoinHandle<Result<()>>> {
+        mut grpc_activation_rx: watch::Receiver<bool>,
+    ) -> Result<(
+        tokio::task::JoinHandle<Result<()>>,
+        Option<Arc<TrafficController>>,
+    )> {
         let validator_service = ValidatorService::new(
             state.clone(),
             consensus_adapter,
@@ -1455,24 +1812,85 @@ impl IotaNode {
             config.policy_config.clone(),
             config.firewall_config.clone(),
         );
+        let traffic_controller = validator_service.traffic_controller();
 
         let mut server_conf = iota_network_stack::config::Config::new();
         server_conf.global_concurrency_limit = config.grpc_concurrency_limit;
         server_conf.load_shed = config.grpc_load_shed;
-        let mut server_builder =
-            ServerBuilder::from_config(&server_conf, GrpcMetrics::new(prometheus_registry));
+        let grpc_metrics = GrpcMetrics::new(prometheus_registry);
+        // Binding consumes the builder, so a fresh one is needed for every attempt.
+        let make_server_builder = move || {
+            ServerBuilder::from_config(&server_conf, grpc_metrics.clone())
+                .add_service(ValidatorServer::new(validator_service.clone()))
+        };
 
-        server_builder = server_builder.add_service(ValidatorServer::new(validator_service));
+        if !*grpc_activation_rx.borrow_and_update() {
+            // Warm standby: bind only once the node gets activated.
+            info!(
+                "Deferring the bind of {} until the validator gRPC service is activated",
+                config.network_address()
+            );
+            let network_address = config.network_address().clone();
+            let bind_config = config.grpc_bind_config.clone();
+            let grpc_server = spawn_monitored_task!(async move {
+                grpc_activation_rx
+                    .wait_for(|activated| *activated)
+                    .await
+                    .map_err(|_| anyhow!("node dropped before activating the gRPC service"))?;
+                let server =
+                    Self::bind_grpc_server(make_server_builder, &network_address, &bind_config)
+                        .await?;
+                info!("Activated, listening to traffic on {}", server.local_addr());
+                server.serve().await.map_err(Into::into)
+            });
+            return Ok((grpc_server, traffic_controller));
+        }
 
-        let server = server_builder
-            .bind(config.network_address())
-            .await
-            .map_err(|err| anyhow!(err.to_string()))?;
+        let server = Self::bind_grpc_server(
+            make_server_builder,
+            config.network_address(),
+            &config.grpc_bind_config,
+        )
+        .await?;
         let local_addr = server.local_addr();
         info!("Listening to traffic on {local_addr}");
         let grpc_server = spawn_monitored_task!(server.serve().map_err(Into::into));
 
-        Ok(grpc_server)
+        Ok((grpc_server, traffic_controller))
+    }
+
+    /// Binds the validator gRPC service to `address`. Every attempt is bounded
+    /// by the configured timeout, and failed attempts are retried until the
+    /// configured number of attempts is exhausted.
+    async fn bind_grpc_server(
+        make_server_builder: impl Fn() -> ServerBuilder<GrpcMetrics>,
+        address: &Multiaddr,
+        bind_config: &GrpcBindConfig,
+    ) -> Result<iota_network_stack::server::Server> {
+        let max_attempts = bind_config.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            let error = match tokio::time::timeout(
+                bind_config.timeout(),
+                make_server_builder().bind(address),
+            )
+            .await
+            {
+                Ok(Ok(server)) => return Ok(server),
+                Ok(Err(err)) => err.to_string(),
+                Err(_) => format!("timed out after {:?}", bind_config.timeout()),
+            };
+            if attempt >= max_attempts {
+                return Err(anyhow!(
+                    "failed to bind the validator gRPC service to {address} after {attempt} attempts: {error}"
+                ));
+            }
+            warn!(
+                "Failed to bind the validator gRPC service to {address} (attempt {attempt}/{max_attempts}): {error}, retrying"
+            );
+            attempt += 1;
+            tokio::time::sleep(bind_config.retry_interval()).await;
+        }
     }
 
     pub fn state(&self) -> Arc<AuthorityState> {
@@ -1484,6 +1902,39 @@ impl IotaNode {
         self.state.reference_gas_price_for_testing()
     }
 
+    /// Sets the behavior of the consensus submissions of the transaction
+    /// `digest` on this validator. Returns `false` if the node is not a
+    /// validator.
+    #[cfg(msim)]
+    pub async fn set_consensus_submission_override(
+        &self,
+        digest: TransactionDigest,
+        submission_override: Option<SubmissionOverride>,
+    ) -> bool {
+        match &*self.validator_components.lock().await {
+            Some(components) => {
+                components
+                    .consensus_adapter
+                    .set_submission_override(digest, submission_override);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of requests dropped per client IP by the traffic
+    /// controller of the validator gRPC service. Returns `None` if the node is
+    /// not a validator or runs without a traffic control policy.
+    pub async fn traffic_controller_dropped_requests(&self) -> Option<HashMap<IpAddr, u64>> {
+        self.validator_components
+            .lock()
+            .await
+            .as_ref()?
+            .traffic_controller
+            .as_ref()
+            .map(|traffic_controller| traffic_controller.dropped_requests())
+    }
+
     pub fn clone_committee_store(&self) -> Arc<CommitteeStore> {
         self.state.committee_store().clone()
     }
@@ -1539,7 +1990,8 @@ impl IotaNode {
                 accumulator.clone(),
                 self.config.checkpoint_executor_config.clone(),
                 checkpoint_executor_metrics.clone(),
-            );
+            )
+            .with_highest_executed_checkpoint_sender(self.highest_executed_checkpoint_tx.clone());
 
             let run_with_range = self.config.run_with_range;
 
@@ -1547,25 +1999,28 @@ impl IotaNode {
 
             // Advertise capabilities to committee, if we are a validator.
             if let Some(components) = &*self.validator_components.lock().await {
-                // TODO: without this sleep, the consensus message is not delivered reliably.
-                tokio::time::sleep(Duration::from_millis(1)).await;
+                // Make sure the capabilities are not submitted before consensus of this
+                // epoch accepts transactions.
+                components.consensus_manager.wait_until_ready().await;
 
                 let config = cur_epoch_store.protocol_config();
                 let binary_config = to_binary_config(config);
-                let transaction = ConsensusTransaction::new_capability_notification_v1(
-                    AuthorityCapabilitiesV1::new(
-                        self.state.name,
-                        cur_epoch_store.get_chain_identifier().chain(),
-                        self.config
-                            .supported_protocol_versions
-                            .expect("Supported versions should be populated")
-                            // no need to send digests of versions less than the current version
-                            .truncate_below(config.version),
-                        self.state
-                            .get_available_system_packages(&binary_config)
-                            .await,
-                    ),
+                let capabilities = AuthorityCapabilitiesV1::new(
+                    self.state.name,
+                    cur_epoch_store.get_chain_identifier().chain(),
+                    self.config
+                        .supported_protocol_versions
+                        .expect("Supported versions should be populated")
+                        // no need to send digests of versions less than the current version
+                        .truncate_below(config.version),
+                    self.state
+                        .get_available_system_packages(&binary_config)
+                        .await,
                 );
+                self.last_advertised_capabilities
+                    .store(Some(Arc::new(capabilities.clone())));
+                let transaction =
+                    ConsensusTransaction::new_capability_notification_v1(capabilities);
                 info!(?transaction, "submitting capabilities to consensus");
                 components
                     .consensus_adapter
@@ -1585,6 +2040,13 @@ impl IotaNode {
                 return Ok(());
             }
 
+            // Reset the flag on every exit from the reconfiguration, including
+            // early returns and errors.
+            self.is_reconfiguring.store(true, Ordering::Relaxed);
+            let reconfiguring = scopeguard::guard(&self.is_reconfiguring, |is_reconfiguring| {
+                is_reconfiguring.store(false, Ordering::Relaxed)
+            });
+
             // Safe to call because we are in the middle of reconfiguration.
             let latest_system_state = self
                 .state
@@ -1631,6 +2093,30 @@ impl IotaNode {
                 "Finished executing all checkpoints in epoch. About to reconfigure the system."
             );
 
+            if self.state.is_validator(&cur_epoch_store)
+                && !next_epoch_committee.authority_exists(&self.state.name)
+            {
+                match self.config.committee_removal_policy {
+                    CommitteeRemovalPolicy::Demote => {}
+                    CommitteeRemovalPolicy::Alert => {
+                        error!(
+                            next_epoch,
+                            "This validator is not part of the committee of the next epoch and will continue as a fullnode"
+                        );
+                    }
+                    CommitteeRemovalPolicy::Halt => {
+                        error!(
+                            next_epoch,
+                            "This validator is not part of the committee of the next epoch, shutting down instead of continuing as a fullnode"
+                        );
+                        IotaNode::shutdown(&self).await;
+                        // The receivers may already be gone if the node is shutting down.
+                        let _ = self.shutdown_channel_tx.send(None);
+                        return Ok(());
+                    }
+                }
+            }
+
             fail_point_async!("reconfig_delay");
 
             // We save the connection monitor status map regardless of validator / fullnode
@@ -1643,6 +2129,7 @@ impl IotaNode {
                 .update_mapping_for_epoch(authority_names_to_peer_ids);
 
             cur_epoch_store.record_epoch_reconfig_start_time_metric();
+            let reconfig_start = Instant::now();
 
             send_trusted_peer_change(
                 &self.config,
@@ -1656,6 +2143,7 @@ impl IotaNode {
 
             let new_validator_components = if let Some(ValidatorComponents {
                 validator_server_handle,
+                traffic_controller,
                 validator_overload_monitor_handle,
                 consensus_manager,
                 consensus_store_pruner,
@@ -1666,6 +2154,7 @@ impl IotaNode {
             }) = self.validator_components.lock().await.take()
             {
                 info!("Reconfiguring the validator.");
+                let phase_start = Instant::now();
                 // Cancel the old checkpoint service tasks.
                 // Waiting for checkpoint builder to finish gracefully is not possible, because
                 // it may wait on transactions while consensus on peers have
@@ -1680,10 +2169,20 @@ impl IotaNode {
                     }
                 }
                 info!("Checkpoint service has shut down.");
+                cur_epoch_store.record_reconfiguration_phase_duration_metric(
+                    "checkpoint_service_shutdown",
+                    phase_start.elapsed(),
+                );
 
+                let phase_start = Instant::now();
                 consensus_manager.shutdown().await;
                 info!("Consensus has shut down.");
+                cur_epoch_store.record_reconfiguration_phase_duration_metric(
+                    "consensus_shutdown",
+                    phase_start.elapsed(),
+                );
 
+                let phase_start = Instant::now();
                 let new_epoch_store = self
                     .reconfigure_state(
                         &self.state,
@@ -1694,12 +2193,15 @@ impl IotaNode {
                     )
                     .await?;
                 info!("Epoch store finished reconfiguration.");
+                cur_epoch_store.record_reconfiguration_phase_duration_metric(
+                    "epoch_store_reconfigure",
+                    phase_start.elapsed(),
+                );
 
                 // No other components should be holding a strong reference to state accumulator
                 // at this point. Confirm here before we swap in the new accumulator.
-                let accumulator_metrics = Arc::into_inner(accumulator)
-                    .expect("Accumulator should have no other references at this point")
-                    .metrics();
+                let accumulator_metrics =
+                    Self::take_accumulator_metrics_with_backoff(accumulator).await;
                 let new_accumulator = Arc::new(StateAccumulator::new(
                     self.state.get_accumulator_store().clone(),
                     accumulator_metrics,
@@ -1711,26 +2213,31 @@ impl IotaNode {
 
                 if self.state.is_validator(&new_epoch_store) {
                     // Only restart consensus if this node is still a validator in the new epoch.
-                    Some(
-                        Self::start_epoch_specific_validator_components(
-                            &self.config,
-                            self.state.clone(),
-                            consensus_adapter,
-                            self.checkpoint_store.clone(),
-                            new_epoch_store.clone(),
-                            self.state_sync_handle.clone(),
-                            self.randomness_handle.clone(),
-                            consensus_manager,
-                            consensus_store_pruner,
-                            weak_accumulator,
-                            validator_server_handle,
-                            validator_overload_monitor_handle,
-                            checkpoint_metrics,
-                            self.metrics.clone(),
-                            iota_tx_validator_metrics,
-                        )
-                        .await?,
+                    let phase_start = Instant::now();
+                    let components = Self::start_epoch_specific_validator_components(
+                        &self.config,
+                        self.state.clone(),
+                        consensus_adapter,
+                        self.checkpoint_store.clone(),
+                        new_epoch_store.clone(),
+                        self.state_sync_handle.clone(),
+                        self.randomness_handle.clone(),
+                        consensus_manager,
+                        consensus_store_pruner,
+                        weak_accumulator,
+                        validator_server_handle,
+                        traffic_controller,
+                        validator_overload_monitor_handle,
+                        checkpoint_metrics,
+                        self.metrics.clone(),
+                        iota_tx_validator_metrics,
                     )
+                    .await?;
+                    cur_epoch_store.record_reconfiguration_phase_duration_metric(
+                        "new_components_start",
+                        phase_start.elapsed(),
+                    );
+                    Some(components)
                 } else {
                     info!("This node is no longer a validator after reconfiguration");
 
@@ -1741,6 +2248,7 @@ impl IotaNode {
                     None
                 }
             } else {
+                let phase_start = Instant::now();
                 let new_epoch_store = self
                     .reconfigure_state(
                         &self.state,
@@ -1750,12 +2258,15 @@ impl IotaNode {
                         accumulator.clone(),
                     )
                     .await?;
+                cur_epoch_store.record_reconfiguration_phase_duration_metric(
+                    "epoch_store_reconfigure",
+                    phase_start.elapsed(),
+                );
 
                 // No other components should be holding a strong reference to state accumulator
                 // at this point. Confirm here before we swap in the new accumulator.
-                let accumulator_metrics = Arc::into_inner(accumulator)
-                    .expect("Accumulator should have no other references at this point")
-                    .metrics();
+                let accumulator_metrics =
+                    Self::take_accumulator_metrics_with_backoff(accumulator).await;
                 let new_accumulator = Arc::new(StateAccumulator::new(
                     self.state.get_accumulator_store().clone(),
                     accumulator_metrics,
@@ -1766,27 +2277,40 @@ impl IotaNode {
                 if self.state.is_validator(&new_epoch_store) {
                     info!("Promoting the node from fullnode to validator, starting grpc server");
 
-                    Some(
-                        Self::construct_validator_components(
-                            self.config.clone(),
-                            self.state.clone(),
-                            Arc::new(next_epoch_committee.clone()),
-                            new_epoch_store.clone(),
-                            self.checkpoint_store.clone(),
-                            self.state_sync_handle.clone(),
-                            self.randomness_handle.clone(),
-                            weak_accumulator,
-                            self.connection_monitor_status.clone(),
-                            &self.registry_service,
-                            self.metrics.clone(),
-                        )
-                        .await?,
+                    let phase_start = Instant::now();
+                    let components = Self::construct_validator_components(
+                        self.config.clone(),
+                        self.state.clone(),
+                        Arc::new(next_epoch_committee.clone()),
+                        new_epoch_store.clone(),
+                        self.checkpoint_store.clone(),
+                        self.state_sync_handle.clone(),
+                        self.randomness_handle.clone(),
+                        weak_accumulator,
+                        self.connection_monitor_status.clone(),
+                        &self.registry_service,
+                        self.metrics.clone(),
+                        self.grpc_activation_tx.subscribe(),
                     )
+                    .await?;
+                    cur_epoch_store.record_reconfiguration_phase_duration_metric(
+                        "new_components_start",
+                        phase_start.elapsed(),
+                    );
+                    Some(components)
                 } else {
                     None
                 }
             };
             *self.validator_components.lock().await = new_validator_components;
+            drop(reconfiguring);
+
+            // Pausing the validator tx finalizer only lasts for one epoch as well.
+            if let Some(validator_tx_finalizer) = &self.state.validator_tx_finalizer {
+                validator_tx_finalizer.reset_enabled();
+            }
+            cur_epoch_store
+                .record_reconfiguration_phase_duration_metric("total", reconfig_start.elapsed());
 
             // Force releasing current epoch store DB handle, because the
             // Arc<AuthorityPerEpochStore> may linger.
@@ -1812,6 +2336,72 @@ impl IotaNode {
         }
     }
 
+    /// Waits until the state accumulator of the finished epoch is no longer
+    /// referenced by any other component and returns its metrics, so they can
+    /// be reused by the accumulator of the new epoch.
+    ///
+    /// Components may release their references slightly after the epoch has
+    /// ended, so this retries until `timeout` elapsed. Fails if the accumulator
+    /// is still referenced afterwards.
+    async fn take_accumulator_metrics(
+        mut accumulator: Arc<StateAccumulator>,
+        timeout: Duration,
+    ) -> Result<Arc<StateAccumulatorMetrics>> {
+        const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+        let start = Instant::now();
+        loop {
+            match Arc::try_unwrap(accumulator) {
+                Ok(accumulator) => return Ok(accumulator.metrics()),
+                Err(still_shared) => accumulator = still_shared,
+            }
+            if start.elapsed() >= timeout {
+                let strong_count = Arc::strong_count(&accumulator);
+                let weak_count = Arc::weak_count(&accumulator);
+                error!(
+                    strong_count,
+                    weak_count,
+                    "State accumulator of the previous epoch is still referenced after {timeout:?}, \
+                     a component did not release it at the end of the epoch"
+                );
+                return Err(anyhow!(
+                    "state accumulator still has {} other strong references after {timeout:?}",
+                    strong_count - 1
+                ));
+            }
+            tokio::time::sleep(RETRY_INTERVAL).await;
+        }
+    }
+
+    /// Calls [`Self::take_accumulator_metrics`] until the state accumulator of
+    /// the finished epoch was released. Reconfiguration can't continue while
+    /// the accumulator is still referenced, so this backs off between the
+    /// attempts instead of giving up.
+    async fn take_accumulator_metrics_with_backoff(
+        accumulator: Arc<StateAccumulator>,
+    ) -> Arc<StateAccumulatorMetrics> {
+        const TIMEOUT: Duration = Duration::from_secs(30);
+        const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+        let metrics = accumulator.metrics();
+        let weak_accumulator = Arc::downgrade(&accumulator);
+        let mut accumulator = accumulator;
+        let mut backoff = Duration::from_secs(1);
+        loop {
+            match Self::take_accumulator_metrics(accumulator, TIMEOUT).await {
+                Ok(metrics) => return metrics,
+                Err(err) => warn!("Retrying to take the state accumulator in {backoff:?}: {err}"),
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+            accumulator = match weak_accumulator.upgrade() {
+                Some(accumulator) => accumulator,
+                // The remaining references were released in the meantime.
+                None => return metrics,
+            };
+        }
+    }
+
     async fn shutdown(&self) {
         if let Some(validator_components) = &*self.validator_components.lock().await {
             validator_components.consensus_manager.shutdown().await;
@@ -1843,6 +2433,18 @@ impl IotaNode {
             })?
             .epoch_supply_change;
 
+        self.metrics.epoch_supply_change.set(epoch_supply_change);
+        if let Some(max_supply_change) = self.config.max_epoch_supply_change_nanos {
+            if epoch_supply_change.unsigned_abs() > max_supply_change {
+                error!(
+                    epoch = cur_epoch_store.epoch(),
+                    epoch_supply_change,
+                    max_supply_change,
+                    "Supply change of the epoch exceeds the configured bound, this may indicate a protocol bug"
+                );
+            }
+        }
+
         let epoch_start_configuration = EpochStartConfiguration::new(
             next_epoch_start_system_state,
             *last_checkpoint.digest(),
@@ -1883,7 +2485,7 @@ impl IotaNode {
         epoch_store: &Arc<AuthorityPerEpochStore>,
         tx: &Transaction,
         span: tracing::Span,
-    ) {
+    ) -> TransactionEffects {
         let transaction =
             iota_types::executable_transaction::VerifiedExecutableTransaction::new_unchecked(
                 iota_types::executable_transaction::ExecutableTransaction::new_from_data_and_sig(
@@ -1891,11 +2493,12 @@ impl IotaNode {
                     iota_types::executable_transaction::CertificateProof::Checkpoint(0, 0),
                 ),
             );
-        state
+        let (effects, _execution_error) = state
             .try_execute_immediately(&transaction, None, epoch_store)
             .instrument(span)
             .await
             .unwrap();
+        effects
     }
 
     pub fn randomness_handle(&self) -> randomness::Handle {
@@ -1976,7 +2579,8 @@ fn build_kv_store(
         )
     })?;
 
-    let http_store = HttpKVStore::new_kv(base_url, metrics.clone())?;
+    let http_store =
+        HttpKVStore::new_kv_with_config(&config.transaction_kv_store_read_config, metrics.clone())?;
     info!("using local key-value store with fallback to http key-value store");
     Ok(Arc::new(FallbackTransactionKVStore::new_kv(
         db_store,
@@ -2010,6 +2614,7 @@ pub async fn build_http_server(
     prometheus_registry: &Registry,
     _custom_runtime: Option<Handle>,
     software_version: &'static str,
+    subscription_limits: Arc<SubscriptionLimits>,
 ) -> Result<Option<tokio::task::JoinHandle<()>>> {
     // Validators do not expose these APIs
     if config.consensus_config().is_some() {
@@ -2024,7 +2629,11 @@ pub async fn build_http_server(
             prometheus_registry,
             config.policy_config.clone(),
             config.firewall_config.clone(),
-        );
+        )
+        .with_max_concurrent_requests(config.jsonrpc_max_concurrent_requests);
+        if let Some(max_response_body_size) = config.jsonrpc_max_response_body_size {
+            server = server.with_max_response_body_size(max_response_body_size);
+        }
 
         let kv_store = build_kv_store(&state, config, prometheus_registry)?;
 
@@ -2045,8 +2654,17 @@ pub async fn build_http_server(
         if config.run_with_range.is_none() {
             server.register_module(TransactionBuilderApi::new(state.clone()))?;
         }
-        server.register_module(GovernanceReadApi::new(state.clone(), metrics.clone()))?;
-        server.register_module(BridgeReadApi::new(state.clone(), metrics.clone()))?;
+        if config.disable_governance_read_api {
+            info!("governance read api is disabled by the node config");
+        } else {
+            server.register_module(GovernanceReadApi::new(state.clone(), metrics.clone()))?;
+        }
+        // The bridge read api can only serve data if the bridge object exists
+        if state.load_epoch_store_one_call_per_task().bridge_exists() {
+            server.register_module(BridgeReadApi::new(state.clone(), metrics.clone()))?;
+        } else {
+            info!("bridge is not enabled on this chain, not serving the bridge read api");
+        }
 
         if let Some(transaction_orchestrator) = transaction_orchestrator {
             server.register_module(TransactionExecutionApi::new(
@@ -2066,7 +2684,7 @@ pub async fn build_http_server(
             kv_store,
             metrics,
             iota_names_config,
-            config.indexer_max_subscriptions,
+            subscription_limits,
         ))?;
         server.register_module(MoveUtils::new(state.clone()))?;
 
@@ -2122,3 +2740,43 @@ fn max_tx_per_checkpoint(protocol_config: &ProtocolConfig) -> usize {
 fn max_tx_per_checkpoint(_: &ProtocolConfig) -> usize {
     2
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use iota_core::state_accumulator::StateAccumulator;
+    use iota_types::in_memory_storage::InMemoryStorage;
+
+    use super::IotaNode;
+
+    #[tokio::test]
+    async fn take_accumulator_metrics_fails_while_referenced() {
+        let accumulator = Arc::new(StateAccumulator::new_for_tests(Arc::new(
+            InMemoryStorage::default(),
+        )));
+        let metrics = accumulator.metrics();
+
+        // A component holds on to the accumulator past the timeout.
+        let held = accumulator.clone();
+        let weak_accumulator = Arc::downgrade(&accumulator);
+        let err = IotaNode::take_accumulator_metrics(accumulator, Duration::from_millis(300))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("1 other strong references"),
+            "unexpected error: {err}"
+        );
+
+        // Once the component releases it, a retry takes the metrics.
+        let accumulator = weak_accumulator.upgrade().unwrap();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(held);
+        });
+        let taken = IotaNode::take_accumulator_metrics(accumulator, Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert!(Arc::ptr_eq(&taken, &metrics));
+    }
+}
@@ -2,13 +2,18 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use clap::{ArgGroup, Parser};
 use iota_common::sync::async_once_cell::AsyncOnceCell;
 use iota_config::{Config, NodeConfig, node::RunWithRange};
 use iota_core::runtime::IotaRuntimes;
-use iota_node::{IotaNode, metrics};
+use iota_metrics::RegistryService;
+use iota_node::{ArchivalNode, IotaNode, config_validation, metrics};
 use iota_types::{
     committee::EpochId, messages_checkpoint::CheckpointSequenceNumber, multiaddr::Multiaddr,
     supported_protocol_versions::SupportedProtocolVersions,
@@ -37,6 +42,12 @@ struct Args {
 
     #[arg(long, group = "exclusive")]
     run_with_range_checkpoint: Option<CheckpointSequenceNumber>,
+
+    #[arg(
+        long,
+        help = "Validate the config file and exit without starting the node"
+    )]
+    validate_config: bool,
 }
 
 fn main() {
@@ -50,6 +61,9 @@ fn main() {
     }
 
     let args = Args::parse();
+    if args.validate_config {
+        validate_config(&args.config_path);
+    }
     let mut config = NodeConfig::load(&args.config_path).unwrap();
     assert!(
         config.supported_protocol_versions.is_none(),
@@ -102,6 +116,11 @@ fn main() {
         config.network_address = listen_address;
     }
 
+    if config.archival_only {
+        run_archival_node(config, registry_service, runtimes);
+        return;
+    }
+
     let admin_interface_address = config.admin_interface_address;
 
     // Run node in a separate runtime so that admin/monitoring functions continue to
@@ -158,6 +177,34 @@ fn main() {
     drop(runtimes);
 }
 
+/// Runs an [`ArchivalNode`] until the process is terminated. Such a node has
+/// no state to inspect, so the admin server is not started.
+fn run_archival_node(
+    config: NodeConfig,
+    registry_service: RegistryService,
+    runtimes: IotaRuntimes,
+) {
+    runtimes.iota_node.spawn(async move {
+        match ArchivalNode::start(config, registry_service).await {
+            Ok(_node) => std::future::pending::<()>().await,
+            Err(e) => {
+                error!("Failed to start archival node: {e:?}");
+                std::process::exit(1);
+            }
+        }
+    });
+
+    // An archival node never requests a shutdown on its own.
+    let (_runtime_shutdown_tx, runtime_shutdown_rx) = broadcast::channel::<()>(1);
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(wait_termination(runtime_shutdown_rx));
+
+    drop(runtimes);
+}
+
 #[cfg(not(unix))]
 async fn wait_termination(mut shutdown_rx: broadcast::Receiver<()>) {
     tokio::select! {
@@ -182,3 +229,18 @@ async fn wait_termination(mut shutdown_rx: broadcast::Receiver<()>) {
         _ = shutdown_recv => {},
     }
 }
+
+fn validate_config(config_path: &Path) -> ! {
+    let errors = match NodeConfig::load(config_path) {
+        Ok(config) => config_validation::validate_node_config(&config),
+        Err(e) => vec![e],
+    };
+    if errors.is_empty() {
+        println!("Config {} is valid", config_path.display());
+        std::process::exit(0);
+    }
+    for error in &errors {
+        eprintln!("{error:#}");
+    }
+    std::process::exit(1);
+}
@@ -9,9 +9,12 @@ use iota_metrics::RegistryService;
 use iota_network::tonic::Code;
 use iota_network_stack::metrics::MetricsCallbackProvider;
 use prometheus::{
-    Encoder, HistogramVec, IntCounterVec, IntGaugeVec, PROTOBUF_FORMAT, Registry,
+    Encoder, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, PROTOBUF_FORMAT,
+    Registry,
+    proto::{MetricFamily, MetricType},
     register_histogram_vec_with_registry, register_int_counter_vec_with_registry,
-    register_int_gauge_vec_with_registry,
+    register_int_counter_with_registry, register_int_gauge_vec_with_registry,
+    register_int_gauge_with_registry,
 };
 use tracing::error;
 
@@ -167,7 +170,13 @@ pub struct IotaNodeMetrics {
 
     pub total_jwks: IntCounterVec,
     pub invalid_jwks: IntCounterVec,
+    pub jwks_rejected_by_kid_policy: IntCounterVec,
     pub unique_jwks: IntCounterVec,
+
+    pub genesis_iota_conservation_check_failed: IntGauge,
+    pub genesis_migration_transactions_failed: IntCounter,
+
+    pub epoch_supply_change: IntGauge,
 }
 
 impl IotaNodeMetrics {
@@ -201,6 +210,13 @@ impl IotaNodeMetrics {
                 registry,
             )
             .unwrap(),
+            jwks_rejected_by_kid_policy: register_int_counter_vec_with_registry!(
+                "jwks_rejected_by_kid_policy",
+                "Total number of JWKs rejected by the key id policy of their provider",
+                &["provider"],
+                registry,
+            )
+            .unwrap(),
             unique_jwks: register_int_counter_vec_with_registry!(
                 "unique_jwks",
                 "Total number of unique JWKs",
@@ -208,6 +224,24 @@ impl IotaNodeMetrics {
                 registry,
             )
             .unwrap(),
+            genesis_iota_conservation_check_failed: register_int_gauge_with_registry!(
+                "genesis_iota_conservation_check_failed",
+                "Set to 1 if the background IOTA conservation check at genesis failed",
+                registry,
+            )
+            .unwrap(),
+            genesis_migration_transactions_failed: register_int_counter_with_registry!(
+                "genesis_migration_transactions_failed",
+                "Total number of migration transactions which failed to execute at genesis",
+                registry,
+            )
+            .unwrap(),
+            epoch_supply_change: register_int_gauge_with_registry!(
+                "epoch_supply_change",
+                "Change of the total IOTA supply in NANOS during the last completed epoch",
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
@@ -273,12 +307,129 @@ impl MetricsCallbackProvider for GrpcMetrics {
     }
 }
 
+/// Converts gathered metric families to JSON, as an array with one object
+/// per family holding its name, help, type and the values of its metrics.
+pub fn metric_families_to_json(families: &[MetricFamily]) -> serde_json::Value {
+    families
+        .iter()
+        .map(|family| {
+            let metrics = family
+                .get_metric()
+                .iter()
+                .map(|metric| {
+                    let labels = metric
+                        .get_label()
+                        .iter()
+                        .map(|label| (label.get_name().to_string(), label.get_value().into()))
+                        .collect::<serde_json::Map<_, _>>();
+                    let mut value = match family.get_field_type() {
+                        MetricType::COUNTER => {
+                            serde_json::json!({ "value": metric.get_counter().get_value() })
+                        }
+                        MetricType::GAUGE => {
+                            serde_json::json!({ "value": metric.get_gauge().get_value() })
+                        }
+                        MetricType::UNTYPED => {
+                            serde_json::json!({ "value": metric.get_untyped().get_value() })
+                        }
+                        MetricType::HISTOGRAM => {
+                            let histogram = metric.get_histogram();
+                            let buckets = histogram
+                                .get_bucket()
+                                .iter()
+                                .map(|bucket| {
+                                    serde_json::json!({
+                                        "upper_bound": bucket.get_upper_bound(),
+                                        "cumulative_count": bucket.get_cumulative_count(),
+                                    })
+                                })
+                                .collect::<Vec<_>>();
+                            serde_json::json!({
+                                "sample_count": histogram.get_sample_count(),
+                                "sample_sum": histogram.get_sample_sum(),
+                                "buckets": buckets,
+                            })
+                        }
+                        MetricType::SUMMARY => {
+                            let summary = metric.get_summary();
+                            let quantiles = summary
+                                .get_quantile()
+                                .iter()
+                                .map(|quantile| {
+                                    serde_json::json!({
+                                        "quantile": quantile.get_quantile(),
+                                        "value": quantile.get_value(),
+                                    })
+                                })
+                                .collect::<Vec<_>>();
+                            serde_json::json!({
+                                "sample_count": summary.get_sample_count(),
+                                "sample_sum": summary.get_sample_sum(),
+                                "quantiles": quantiles,
+                            })
+                        }
+                    };
+                    value["labels"] = labels.into();
+                    value
+                })
+                .collect::<Vec<_>>();
+            serde_json::json!({
+                "name": family.get_name(),
+                "help": family.get_help(),
+                "type": format!("{:?}", family.get_field_type()).to_lowercase(),
+                "metrics": metrics,
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
     use iota_metrics::start_prometheus_server;
-    use prometheus::{IntCounter, Registry};
+    use prometheus::{Histogram, HistogramOpts, IntCounter, IntCounterVec, Opts, Registry};
+
+    use super::metric_families_to_json;
+
+    #[test]
+    fn test_metric_families_to_json() {
+        let registry = Registry::new();
+        let counter =
+            IntCounterVec::new(Opts::new("requests", "number of requests"), &["method"]).unwrap();
+        registry.register(Box::new(counter.clone())).unwrap();
+        let histogram =
+            Histogram::with_opts(HistogramOpts::new("latency", "latency").buckets(vec![1.0]))
+                .unwrap();
+        registry.register(Box::new(histogram.clone())).unwrap();
+
+        counter.with_label_values(&["get"]).inc_by(3);
+        histogram.observe(0.5);
+
+        let json = metric_families_to_json(&registry.gather());
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "name": "latency",
+                    "help": "latency",
+                    "type": "histogram",
+                    "metrics": [{
+                        "labels": {},
+                        "sample_count": 1,
+                        "sample_sum": 0.5,
+                        "buckets": [{ "upper_bound": 1.0, "cumulative_count": 1 }],
+                    }],
+                },
+                {
+                    "name": "requests",
+                    "help": "number of requests",
+                    "type": "counter",
+                    "metrics": [{ "labels": { "method": "get" }, "value": 3.0 }],
+                },
+            ])
+        );
+    }
 
     #[tokio::test]
     pub async fn test_metrics_endpoint_with_multiple_registries_add_remove() {
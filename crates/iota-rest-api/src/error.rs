@@ -32,8 +32,12 @@ impl axum::response::IntoResponse for RestError {
 
 impl From<iota_types::storage::error::Error> for RestError {
     fn from(value: iota_types::storage::error::Error) -> Self {
+        let status = match value.kind() {
+            iota_types::storage::error::Kind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
         Self {
-            status: StatusCode::INTERNAL_SERVER_ERROR,
+            status,
             message: Some(value.to_string()),
         }
     }
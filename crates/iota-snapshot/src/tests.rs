@@ -12,13 +12,19 @@ use iota_core::{
     authority::authority_store_tables::AuthorityPerpetualTables,
     state_accumulator::StateAccumulator,
 };
+use iota_storage::object_store::util::get;
 use iota_types::{
     accumulator::Accumulator, base_types::ObjectID, messages_checkpoint::ECMHLiveObjectSetDigest,
     object::Object,
 };
+use object_store::path::Path;
 use tempfile::tempdir;
 
-use crate::{FileCompression, reader::StateSnapshotReaderV1, writer::StateSnapshotWriterV1};
+use crate::{
+    FileCompression,
+    reader::StateSnapshotReaderV1,
+    writer::{StateSnapshotWriterV1, UPLOAD_PART_BYTES, put_multipart},
+};
 
 fn temp_dir() -> std::path::PathBuf {
     tempdir()
@@ -169,3 +175,23 @@ async fn test_snapshot_empty_db() -> Result<(), anyhow::Error> {
     compare_live_objects(&perpetual_db, &restored_perpetual_db)?;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_put_multipart() -> Result<(), anyhow::Error> {
+    let remote_store_config = ObjectStoreConfig {
+        object_store: Some(ObjectStoreType::File),
+        directory: Some(temp_dir().join("remote_dir")),
+        ..Default::default()
+    };
+    let remote = remote_store_config.make()?;
+    // Spans three parts, the last of which is smaller than the others.
+    let bytes = bytes::Bytes::from(
+        (0..2 * UPLOAD_PART_BYTES + 1)
+            .map(|i| i as u8)
+            .collect::<Vec<_>>(),
+    );
+    let path = Path::from("epoch_0/1_1.obj");
+    put_multipart(&remote, &path, bytes.clone(), NonZeroUsize::new(2).unwrap()).await?;
+    assert_eq!(get(&remote, &path).await?, bytes);
+    Ok(())
+}
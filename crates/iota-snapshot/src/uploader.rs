@@ -32,6 +32,8 @@ use crate::writer::StateSnapshotWriterV1;
 pub struct StateSnapshotUploaderMetrics {
     pub first_missing_state_snapshot_epoch: IntGauge,
     pub state_snapshot_upload_err: IntCounter,
+    pub state_snapshot_uploads_in_flight: IntGauge,
+    pub state_snapshot_uploaded_bytes: IntCounter,
 }
 
 impl StateSnapshotUploaderMetrics {
@@ -49,6 +51,18 @@ impl StateSnapshotUploaderMetrics {
                 registry
             )
             .unwrap(),
+            state_snapshot_uploads_in_flight: register_int_gauge_with_registry!(
+                "state_snapshot_uploads_in_flight",
+                "Number of state snapshot files currently being uploaded to remote store",
+                registry
+            )
+            .unwrap(),
+            state_snapshot_uploaded_bytes: register_int_counter_with_registry!(
+                "state_snapshot_uploaded_bytes",
+                "Total bytes of state snapshot files uploaded to remote store",
+                registry
+            )
+            .unwrap(),
         };
         Arc::new(this)
     }
@@ -73,6 +87,11 @@ pub struct StateSnapshotUploader {
     /// Time interval to check for presence of new db checkpoint (default: 60
     /// secs)
     interval: Duration,
+    /// Number of snapshot files uploaded in parallel
+    concurrency: NonZeroUsize,
+    /// Number of parts of a single snapshot file uploaded in parallel, if the
+    /// file is uploaded in parts
+    per_file_concurrency: Option<NonZeroUsize>,
     metrics: Arc<StateSnapshotUploaderMetrics>,
 }
 
@@ -82,6 +101,8 @@ impl StateSnapshotUploader {
         staging_path: &std::path::Path,
         snapshot_store_config: ObjectStoreConfig,
         interval_s: u64,
+        concurrency: NonZeroUsize,
+        per_file_concurrency: Option<NonZeroUsize>,
         registry: &Registry,
         checkpoint_store: Arc<CheckpointStore>,
     ) -> Result<Arc<Self>> {
@@ -103,6 +124,8 @@ impl StateSnapshotUploader {
             staging_store: staging_store_config.make()?,
             snapshot_store: snapshot_store_config.make()?,
             interval: Duration::from_secs(interval_s),
+            concurrency,
+            per_file_concurrency,
             metrics: StateSnapshotUploaderMetrics::new(registry),
         }))
     }
@@ -131,14 +154,19 @@ impl StateSnapshotUploader {
             // or if the local has more advanced epochs than the remote
             if missing_epochs.contains(epoch) || *epoch >= last_missing_epoch {
                 info!("Starting state snapshot creation for epoch: {}", *epoch);
-                let state_snapshot_writer = StateSnapshotWriterV1::new_from_store(
+                let mut state_snapshot_writer = StateSnapshotWriterV1::new_from_store(
                     &self.staging_path,
                     &self.staging_store,
                     &self.snapshot_store,
                     FileCompression::Zstd,
-                    NonZeroUsize::new(20).unwrap(),
+                    self.concurrency,
                 )
-                .await?;
+                .await?
+                .with_metrics(self.metrics.clone());
+                if let Some(per_file_concurrency) = self.per_file_concurrency {
+                    state_snapshot_writer =
+                        state_snapshot_writer.with_per_file_concurrency(per_file_concurrency);
+                }
                 let db = Arc::new(AuthorityPerpetualTables::open(
                     &path_to_filesystem(self.db_checkpoint_path.clone(), &db_path.child("store"))?,
                     None,
@@ -16,24 +16,26 @@ use std::{
 
 use anyhow::{Context, Result};
 use byteorder::{BigEndian, ByteOrder};
+use bytes::Bytes;
 use fastcrypto::hash::MultisetHash;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use integer_encoding::VarInt;
 use iota_config::object_storage_config::ObjectStoreConfig;
 use iota_core::{
     authority::authority_store_tables::{AuthorityPerpetualTables, LiveObject},
     state_accumulator::StateAccumulator,
 };
+use iota_metrics::GaugeGuard;
 use iota_storage::{
     blob::{BLOB_ENCODING_BYTES, Blob, BlobEncoding},
-    object_store::util::{copy_file, delete_recursively, path_to_filesystem},
+    object_store::util::{delete_recursively, get, path_to_filesystem, put},
 };
 use iota_types::{
     accumulator::Accumulator,
     base_types::{ObjectID, ObjectRef},
     messages_checkpoint::ECMHLiveObjectSetDigest,
 };
-use object_store::{DynObjectStore, path::Path};
+use object_store::{DynObjectStore, MultipartUpload, ObjectStore, path::Path};
 use tokio::{
     sync::{
         mpsc,
@@ -42,14 +44,19 @@ use tokio::{
     task::JoinHandle,
 };
 use tokio_stream::wrappers::ReceiverStream;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::{
     FILE_MAX_BYTES, FileCompression, FileMetadata, FileType, MAGIC_BYTES, MANIFEST_FILE_MAGIC,
     Manifest, ManifestV1, OBJECT_FILE_MAGIC, OBJECT_REF_BYTES, REFERENCE_FILE_MAGIC,
     SEQUENCE_NUM_BYTES, compute_sha3_checksum, create_file_metadata,
+    uploader::StateSnapshotUploaderMetrics,
 };
 
+/// Size of the parts in which a snapshot file is uploaded if per file
+/// concurrency is enabled.
+pub(crate) const UPLOAD_PART_BYTES: usize = 16 * 1024 * 1024;
+
 /// LiveObjectSetWriterV1 writes live object set. It creates multiple *.obj
 /// files and *.ref file
 struct LiveObjectSetWriterV1 {
@@ -257,6 +264,8 @@ pub struct StateSnapshotWriterV1 {
     remote_object_store: Arc<DynObjectStore>,
     local_staging_store: Arc<DynObjectStore>,
     concurrency: usize,
+    per_file_concurrency: Option<NonZeroUsize>,
+    metrics: Option<Arc<StateSnapshotUploaderMetrics>>,
 }
 
 impl StateSnapshotWriterV1 {
@@ -273,6 +282,8 @@ impl StateSnapshotWriterV1 {
             remote_object_store: remote_object_store.clone(),
             local_staging_store: local_staging_store.clone(),
             concurrency: concurrency.get(),
+            per_file_concurrency: None,
+            metrics: None,
         })
     }
 
@@ -295,9 +306,24 @@ impl StateSnapshotWriterV1 {
             remote_object_store,
             local_staging_store,
             concurrency: concurrency.get(),
+            per_file_concurrency: None,
+            metrics: None,
         })
     }
 
+    /// Uploads files larger than a single part in parts, with up to
+    /// `concurrency` parts of a file uploaded in parallel.
+    pub fn with_per_file_concurrency(mut self, concurrency: NonZeroUsize) -> Self {
+        self.per_file_concurrency = Some(concurrency);
+        self
+    }
+
+    /// Records the in-flight uploads and the uploaded bytes in `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<StateSnapshotUploaderMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Retrieves the system state object from the perpetual database, writes
     /// the state snapshot for the specified epoch to the local staging
     /// directory, and uploads it to the remote store.
@@ -325,6 +351,8 @@ impl StateSnapshotWriterV1 {
         let local_staging_dir = self.local_staging_dir.clone();
         let local_object_store = self.local_staging_store.clone();
         let remote_object_store = self.remote_object_store.clone();
+        let per_file_concurrency = self.per_file_concurrency;
+        let metrics = self.metrics.clone();
 
         let (sender, receiver) = mpsc::channel::<FileMetadata>(1000);
         // Starts the upload loop, which listens on the receiver for FileMetadata
@@ -357,6 +385,8 @@ impl StateSnapshotWriterV1 {
             manifest_file_path,
             local_object_store,
             remote_object_store,
+            per_file_concurrency,
+            metrics,
         )
         .await?;
         Ok(())
@@ -374,6 +404,8 @@ impl StateSnapshotWriterV1 {
         let local_dir_path = self.local_staging_dir.clone();
         let epoch_dir = self.epoch_dir(epoch);
         let upload_concurrency = self.concurrency;
+        let per_file_concurrency = self.per_file_concurrency;
+        let metrics = self.metrics.clone();
         let join_handle = tokio::spawn(async move {
             // Uploads the files to the remote store in parallel for each received
             // FileMetadata
@@ -383,12 +415,15 @@ impl StateSnapshotWriterV1 {
                     let remote_object_store = remote_object_store.clone();
                     let local_object_store = local_staging_store.clone();
                     let local_dir_path = local_dir_path.clone();
+                    let metrics = metrics.clone();
                     async move {
                         Self::sync_file_to_remote(
                             local_dir_path.clone(),
                             file_path.clone(),
                             local_object_store.clone(),
                             remote_object_store.clone(),
+                            per_file_concurrency,
+                            metrics,
                         )
                         .await?;
                         Ok(())
@@ -537,16 +572,63 @@ impl StateSnapshotWriterV1 {
         Ok(())
     }
 
-    /// Syncs a file from local store to remote store and removes the local file
+    /// Syncs a file from local store to remote store and removes the local
+    /// file. If `per_file_concurrency` is set, files larger than a single part
+    /// are uploaded in parts.
     async fn sync_file_to_remote(
         local_path: PathBuf,
         path: Path,
         from: Arc<DynObjectStore>,
         to: Arc<DynObjectStore>,
+        per_file_concurrency: Option<NonZeroUsize>,
+        metrics: Option<Arc<StateSnapshotUploaderMetrics>>,
     ) -> Result<()> {
         debug!("Syncing snapshot file to remote: {:?}", path);
-        copy_file(&path, &path, &from, &to).await?;
+        let _in_flight = metrics
+            .as_ref()
+            .map(|metrics| GaugeGuard::acquire(&metrics.state_snapshot_uploads_in_flight));
+        let bytes = get(&from, &path).await?;
+        let len = bytes.len();
+        match per_file_concurrency {
+            Some(concurrency) if len > UPLOAD_PART_BYTES => {
+                put_multipart(&to, &path, bytes, concurrency).await?
+            }
+            _ => put(&to, &path, bytes).await?,
+        }
+        if let Some(metrics) = &metrics {
+            metrics.state_snapshot_uploaded_bytes.inc_by(len as u64);
+        }
         fs::remove_file(path_to_filesystem(local_path, &path)?)?;
         Ok(())
     }
 }
+
+/// Uploads `bytes` to `path` in parts of [`UPLOAD_PART_BYTES`], of which up to
+/// `concurrency` are uploaded in parallel. Aborts the upload if a part fails.
+pub(crate) async fn put_multipart(
+    store: &Arc<DynObjectStore>,
+    path: &Path,
+    bytes: Bytes,
+    concurrency: NonZeroUsize,
+) -> Result<()> {
+    let mut upload = store.put_multipart(path).await?;
+    let parts = (0..bytes.len())
+        .step_by(UPLOAD_PART_BYTES)
+        .map(|start| {
+            let end = (start + UPLOAD_PART_BYTES).min(bytes.len());
+            upload.put_part(bytes.slice(start..end).into())
+        })
+        .collect::<Vec<_>>();
+    let uploaded = futures::stream::iter(parts)
+        .buffer_unordered(concurrency.get())
+        .try_collect::<Vec<_>>()
+        .await;
+    if let Err(err) = uploaded {
+        if let Err(abort_err) = upload.abort().await {
+            warn!("Failed to abort the upload of snapshot file {path}: {abort_err}");
+        }
+        return Err(err).context(format!("Failed to upload snapshot file {path} in parts"));
+    }
+    upload.complete().await?;
+    Ok(())
+}
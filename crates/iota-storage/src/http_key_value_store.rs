@@ -2,12 +2,13 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use anyhow;
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::stream::{self, StreamExt};
+use iota_config::node::TransactionKeyValueStoreReadConfig;
 use iota_types::{
     base_types::{ObjectID, SequenceNumber, VersionNumber},
     digests::{CheckpointDigest, TransactionDigest},
@@ -35,9 +36,14 @@ use crate::{
     key_value_store_metrics::KeyValueStoreMetrics,
 };
 
+/// Upper bound of the backoff between two retries of a request.
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(10);
+
 pub struct HttpKVStore {
     base_url: Url,
     client: Client,
+    max_retries: u32,
+    retry_backoff: Duration,
 }
 
 pub fn encode_digest<T: AsRef<[u8]>>(digest: &T) -> String {
@@ -288,10 +294,41 @@ impl HttpKVStore {
         Ok(TransactionKeyValueStore::new("http", metrics, inner))
     }
 
-    pub fn new(base_url: &str) -> IotaResult<Self> {
-        info!("creating HttpKVStore with base_url: {}", base_url);
+    /// Like [`Self::new_kv`], but retries failed requests and applies the
+    /// request timeout as configured.
+    pub fn new_kv_with_config(
+        config: &TransactionKeyValueStoreReadConfig,
+        metrics: Arc<KeyValueStoreMetrics>,
+    ) -> IotaResult<TransactionKeyValueStore> {
+        let inner = Arc::new(Self::new_with_config(config)?);
+        Ok(TransactionKeyValueStore::new("http", metrics, inner))
+    }
 
+    pub fn new(base_url: &str) -> IotaResult<Self> {
         let client = Client::builder().http2_prior_knowledge().build().unwrap();
+        Self::new_with_client(base_url, client, 0, Duration::ZERO)
+    }
+
+    pub fn new_with_config(config: &TransactionKeyValueStoreReadConfig) -> IotaResult<Self> {
+        let mut client_builder = Client::builder().http2_prior_knowledge();
+        if let Some(timeout_ms) = config.request_timeout_ms {
+            client_builder = client_builder.timeout(Duration::from_millis(timeout_ms));
+        }
+        Self::new_with_client(
+            &config.base_url,
+            client_builder.build().unwrap(),
+            config.max_retries,
+            Duration::from_millis(config.retry_backoff_ms),
+        )
+    }
+
+    fn new_with_client(
+        base_url: &str,
+        client: Client,
+        max_retries: u32,
+        retry_backoff: Duration,
+    ) -> IotaResult<Self> {
+        info!("creating HttpKVStore with base_url: {}", base_url);
 
         let base_url = if base_url.ends_with('/') {
             base_url.to_string()
@@ -301,7 +338,12 @@ impl HttpKVStore {
 
         let base_url = Url::parse(&base_url).into_iota_result()?;
 
-        Ok(Self { base_url, client })
+        Ok(Self {
+            base_url,
+            client,
+            max_retries,
+            retry_backoff,
+        })
     }
 
     fn get_url(&self, key: &Key) -> IotaResult<Url> {
@@ -319,15 +361,41 @@ impl HttpKVStore {
         fetches.buffered(uris.len()).collect::<Vec<_>>().await
     }
 
+    /// Sends a request to `url`, retrying it with exponential backoff if it
+    /// fails or the server responds with an error.
+    async fn send_with_retries(&self, url: &Url) -> IotaResult<reqwest::Response> {
+        let mut backoff = self.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            let result = self.client.get(url.clone()).send().await;
+            let retryable = match &result {
+                Ok(resp) => resp.status().is_server_error(),
+                Err(_) => true,
+            };
+            if !retryable || attempt >= self.max_retries {
+                return result.into_iota_result();
+            }
+            attempt += 1;
+            match &result {
+                Ok(resp) => warn!(
+                    "got status {} for url: {url}, retrying ({attempt}/{})",
+                    resp.status(),
+                    self.max_retries
+                ),
+                Err(err) => warn!(
+                    "error fetching url: {url}: {err}, retrying ({attempt}/{})",
+                    self.max_retries
+                ),
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = backoff.saturating_mul(2).min(MAX_RETRY_BACKOFF);
+        }
+    }
+
     async fn fetch(&self, key: Key) -> IotaResult<Option<Bytes>> {
         let url = self.get_url(&key)?;
         trace!("fetching url: {}", url);
-        let resp = self
-            .client
-            .get(url.clone())
-            .send()
-            .await
-            .into_iota_result()?;
+        let resp = self.send_with_retries(&url).await?;
         trace!(
             "got response {} for url: {}, len: {:?}",
             url,
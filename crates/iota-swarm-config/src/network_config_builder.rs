@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
+    collections::BTreeMap,
     num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::Arc,
@@ -75,6 +76,21 @@ pub enum StateAccumulatorV1EnabledConfig {
     PerValidator(StateAccumulatorEnabledCallback),
 }
 
+/// gRPC server settings that override the defaults of a single validator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ValidatorGrpcConfig {
+    pub concurrency_limit: Option<usize>,
+    pub load_shed: Option<bool>,
+}
+
+/// Per validator overrides of the economic parameters in the genesis validator
+/// set.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ValidatorEconomicsConfig {
+    pub gas_price: Option<u64>,
+    pub commission_rate: Option<u64>,
+}
+
 pub struct ConfigBuilder<R = OsRng> {
     rng: Option<R>,
     config_directory: PathBuf,
@@ -91,6 +107,8 @@ pub struct ConfigBuilder<R = OsRng> {
     firewall_config: Option<RemoteFirewallConfig>,
     max_submit_position: Option<usize>,
     submit_delay_step_override_millis: Option<u64>,
+    validator_grpc_configs: BTreeMap<usize, ValidatorGrpcConfig>,
+    validator_economics_configs: BTreeMap<usize, ValidatorEconomicsConfig>,
     state_accumulator_config: Option<StateAccumulatorV1EnabledConfig>,
     empty_validator_genesis: bool,
 }
@@ -113,6 +131,8 @@ impl ConfigBuilder {
             firewall_config: None,
             max_submit_position: None,
             submit_delay_step_override_millis: None,
+            validator_grpc_configs: BTreeMap::new(),
+            validator_economics_configs: BTreeMap::new(),
             state_accumulator_config: Some(StateAccumulatorV1EnabledConfig::Global(true)),
             empty_validator_genesis: false,
         }
@@ -200,6 +220,15 @@ impl<R> ConfigBuilder<R> {
         self
     }
 
+    /// Allows the objects added with `with_objects` to override system
+    /// packages, which tests use to mock them.
+    pub fn with_system_package_override(mut self) -> Self {
+        self.get_or_init_genesis_config()
+            .parameters
+            .allow_system_package_override = true;
+        self
+    }
+
     pub fn with_epoch_duration(mut self, epoch_duration_ms: u64) -> Self {
         self.get_or_init_genesis_config()
             .parameters
@@ -273,6 +302,29 @@ impl<R> ConfigBuilder<R> {
         self
     }
 
+    /// Overrides the gRPC server settings of the validator at index
+    /// `validator_idx`.
+    pub fn with_validator_grpc_config(
+        mut self,
+        validator_idx: usize,
+        config: ValidatorGrpcConfig,
+    ) -> Self {
+        self.validator_grpc_configs.insert(validator_idx, config);
+        self
+    }
+
+    /// Overrides the gas price and commission rate of the validator at index
+    /// `validator_idx` in the genesis validator set.
+    pub fn with_validator_economics_config(
+        mut self,
+        validator_idx: usize,
+        config: ValidatorEconomicsConfig,
+    ) -> Self {
+        self.validator_economics_configs
+            .insert(validator_idx, config);
+        self
+    }
+
     pub fn rng<N: rand::RngCore + rand::CryptoRng>(self, rng: N) -> ConfigBuilder<N> {
         ConfigBuilder {
             rng: Some(rng),
@@ -290,6 +342,8 @@ impl<R> ConfigBuilder<R> {
             firewall_config: self.firewall_config,
             max_submit_position: self.max_submit_position,
             submit_delay_step_override_millis: self.submit_delay_step_override_millis,
+            validator_grpc_configs: self.validator_grpc_configs,
+            validator_economics_configs: self.validator_economics_configs,
             state_accumulator_config: self.state_accumulator_config,
             empty_validator_genesis: self.empty_validator_genesis,
         }
@@ -319,7 +373,7 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
         let committee = self.committee;
 
         let mut rng = self.rng.unwrap();
-        let validators = match committee {
+        let mut validators = match committee {
             CommitteeConfig::Size(size) => {
                 // We always get fixed authority keys from this function (which is isolated from
                 // external test randomness because it uses a fixed seed). Necessary because
@@ -381,6 +435,18 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
             }
         };
 
+        for (idx, economics_config) in &self.validator_economics_configs {
+            let validator = validators.get_mut(*idx).unwrap_or_else(|| {
+                panic!("no validator at index {idx} to apply the economics config to")
+            });
+            if let Some(gas_price) = economics_config.gas_price {
+                validator.gas_price = gas_price;
+            }
+            if let Some(commission_rate) = economics_config.commission_rate {
+                validator.commission_rate = commission_rate;
+            }
+        }
+
         let mut genesis_config = self
             .genesis_config
             .unwrap_or_else(GenesisConfig::for_local_testing);
@@ -418,6 +484,7 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
         let GenesisBuildEffects {
             genesis,
             migration_tx_data,
+            ..
         } = {
             let mut builder = iota_genesis_builder::Builder::new()
                 .with_parameters(genesis_config.parameters)
@@ -479,6 +546,15 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
                         .with_submit_delay_step_override_millis(submit_delay_step_override_millis);
                 }
 
+                if let Some(grpc_config) = self.validator_grpc_configs.get(&idx) {
+                    if let Some(concurrency_limit) = grpc_config.concurrency_limit {
+                        builder = builder.with_grpc_concurrency_limit(concurrency_limit);
+                    }
+                    if let Some(load_shed) = grpc_config.load_shed {
+                        builder = builder.with_grpc_load_shed(load_shed);
+                    }
+                }
+
                 if let Some(jwk_fetch_interval) = self.jwk_fetch_interval {
                     builder = builder.with_jwk_fetch_interval(jwk_fetch_interval);
                 }
@@ -14,8 +14,8 @@ use iota_config::{
     node::{
         AuthorityKeyPairWithPath, AuthorityOverloadConfig, AuthorityStorePruningConfig,
         CheckpointExecutorConfig, DBCheckpointConfig, DEFAULT_GRPC_CONCURRENCY_LIMIT,
-        ExecutionCacheConfig, ExpensiveSafetyCheckConfig, Genesis, KeyPairWithPath, RunWithRange,
-        StateArchiveConfig, StateSnapshotConfig, default_enable_index_processing,
+        ExecutionCacheConfig, ExpensiveSafetyCheckConfig, Genesis, GrpcBindConfig, KeyPairWithPath,
+        RunWithRange, StateArchiveConfig, StateSnapshotConfig, default_enable_index_processing,
         default_end_of_epoch_broadcast_channel_capacity, default_zklogin_oauth_providers,
     },
     p2p::{P2pConfig, SeedPeer, StateSyncConfig},
@@ -48,6 +48,8 @@ pub struct ValidatorConfigBuilder {
     firewall_config: Option<RemoteFirewallConfig>,
     max_submit_position: Option<usize>,
     submit_delay_step_override_millis: Option<u64>,
+    grpc_concurrency_limit: Option<usize>,
+    grpc_load_shed: Option<bool>,
 }
 
 impl ValidatorConfigBuilder {
@@ -115,6 +117,16 @@ impl ValidatorConfigBuilder {
         self
     }
 
+    pub fn with_grpc_concurrency_limit(mut self, grpc_concurrency_limit: usize) -> Self {
+        self.grpc_concurrency_limit = Some(grpc_concurrency_limit);
+        self
+    }
+
+    pub fn with_grpc_load_shed(mut self, grpc_load_shed: bool) -> Self {
+        self.grpc_load_shed = Some(grpc_load_shed);
+        self
+    }
+
     pub fn build_without_genesis(self, validator: ValidatorGenesisConfig) -> NodeConfig {
         let key_path = get_key_path(&validator.authority_key_pair);
         let config_directory = self
@@ -184,12 +196,20 @@ impl ValidatorConfigBuilder {
                 .to_socket_addr()
                 .unwrap(),
             consensus_config: Some(consensus_config),
+            skip_submit_recovered_on_startup: false,
+            archival_only: false,
+            disable_governance_read_api: false,
             remove_deprecated_tables: false,
             enable_index_processing: default_enable_index_processing(),
             genesis: Genesis::new_empty(),
             migration_tx_data_path,
-            grpc_load_shed: None,
-            grpc_concurrency_limit: Some(DEFAULT_GRPC_CONCURRENCY_LIMIT),
+            grpc_load_shed: self.grpc_load_shed,
+            grpc_concurrency_limit: Some(
+                self.grpc_concurrency_limit
+                    .unwrap_or(DEFAULT_GRPC_CONCURRENCY_LIMIT),
+            ),
+            grpc_deferred_bind: false,
+            grpc_bind_config: GrpcBindConfig::default(),
             p2p_config,
             authority_store_pruning_config: pruning_config,
             end_of_epoch_broadcast_channel_capacity:
@@ -202,6 +222,7 @@ impl ValidatorConfigBuilder {
             // By default, expensive checks will be enabled in debug build, but not in release
             // build.
             expensive_safety_check_config: ExpensiveSafetyCheckConfig::default(),
+            signature_verification_concurrency_limit: None,
             transaction_deny_config: Default::default(),
             certificate_deny_config: Default::default(),
             state_debug_dump_config: Default::default(),
@@ -209,23 +230,31 @@ impl ValidatorConfigBuilder {
             state_archive_read_config: vec![],
             state_snapshot_write_config: StateSnapshotConfig::default(),
             indexer_max_subscriptions: Default::default(),
+            indexer_max_subscriptions_per_connection: None,
             transaction_kv_store_read_config: Default::default(),
             transaction_kv_store_write_config: None,
             enable_rest_api: true,
+            disable_rest_index: false,
             jwk_fetch_interval_seconds: self
                 .jwk_fetch_interval
                 .map(|i| i.as_secs())
                 .unwrap_or(3600),
+            jwk_submission_interval_ms: None,
+            max_epoch_supply_change_nanos: None,
             zklogin_oauth_providers: default_zklogin_oauth_providers(),
+            zklogin_jwk_kid_policies: Default::default(),
             authority_overload_config: self.authority_overload_config.unwrap_or_default(),
             run_with_range: None,
             jsonrpc_server_type: None,
+            jsonrpc_max_response_body_size: None,
+            jsonrpc_max_concurrent_requests: None,
             policy_config: self.policy_config,
             firewall_config: self.firewall_config,
             execution_cache: ExecutionCacheConfig::default(),
             enable_validator_tx_finalizer: true,
             verifier_signing_config: VerifierSigningConfig::default(),
             iota_names_config: None,
+            committee_removal_policy: Default::default(),
         }
     }
 
@@ -479,12 +508,17 @@ impl FullnodeConfigBuilder {
                 .unwrap_or(local_ip_utils::new_local_tcp_socket_for_testing()),
             json_rpc_address: self.json_rpc_address.unwrap_or(json_rpc_address),
             consensus_config: None,
+            skip_submit_recovered_on_startup: false,
+            archival_only: false,
+            disable_governance_read_api: false,
             remove_deprecated_tables: false,
             enable_index_processing: default_enable_index_processing(),
             genesis,
             migration_tx_data_path,
             grpc_load_shed: None,
             grpc_concurrency_limit: None,
+            grpc_deferred_bind: false,
+            grpc_bind_config: GrpcBindConfig::default(),
             p2p_config,
             authority_store_pruning_config: AuthorityStorePruningConfig::default(),
             end_of_epoch_broadcast_channel_capacity:
@@ -497,6 +531,7 @@ impl FullnodeConfigBuilder {
             expensive_safety_check_config: self
                 .expensive_safety_check_config
                 .unwrap_or_else(ExpensiveSafetyCheckConfig::new_enable_all),
+            signature_verification_concurrency_limit: None,
             transaction_deny_config: Default::default(),
             certificate_deny_config: Default::default(),
             state_debug_dump_config: Default::default(),
@@ -504,15 +539,22 @@ impl FullnodeConfigBuilder {
             state_archive_read_config: vec![],
             state_snapshot_write_config: StateSnapshotConfig::default(),
             indexer_max_subscriptions: Default::default(),
+            indexer_max_subscriptions_per_connection: None,
             transaction_kv_store_read_config: Default::default(),
             transaction_kv_store_write_config: Default::default(),
             enable_rest_api: true,
+            disable_rest_index: false,
             // note: not used by fullnodes.
             jwk_fetch_interval_seconds: 3600,
+            jwk_submission_interval_ms: None,
+            max_epoch_supply_change_nanos: None,
             zklogin_oauth_providers: default_zklogin_oauth_providers(),
+            zklogin_jwk_kid_policies: Default::default(),
             authority_overload_config: Default::default(),
             run_with_range: self.run_with_range,
             jsonrpc_server_type: None,
+            jsonrpc_max_response_body_size: None,
+            jsonrpc_max_concurrent_requests: None,
             policy_config: self.policy_config,
             firewall_config: self.fw_config,
             execution_cache: ExecutionCacheConfig::default(),
@@ -520,6 +562,7 @@ impl FullnodeConfigBuilder {
             enable_validator_tx_finalizer: false,
             verifier_signing_config: VerifierSigningConfig::default(),
             iota_names_config: None,
+            committee_removal_policy: Default::default(),
         }
     }
 
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     net::SocketAddr,
     num::NonZeroUsize,
     ops,
@@ -25,7 +25,7 @@ use iota_swarm_config::{
     network_config::NetworkConfig,
     network_config_builder::{
         CommitteeConfig, ConfigBuilder, ProtocolVersionsConfig, StateAccumulatorV1EnabledConfig,
-        SupportedProtocolVersionsCallback,
+        SupportedProtocolVersionsCallback, ValidatorEconomicsConfig, ValidatorGrpcConfig,
     },
     node_config_builder::FullnodeConfigBuilder,
 };
@@ -65,6 +65,8 @@ pub struct SwarmBuilder<R = OsRng> {
     fullnode_fw_config: Option<RemoteFirewallConfig>,
     max_submit_position: Option<usize>,
     submit_delay_step_override_millis: Option<u64>,
+    validator_grpc_configs: BTreeMap<usize, ValidatorGrpcConfig>,
+    validator_economics_configs: BTreeMap<usize, ValidatorEconomicsConfig>,
     state_accumulator_config: StateAccumulatorV1EnabledConfig,
 }
 
@@ -93,6 +95,8 @@ impl SwarmBuilder {
             fullnode_fw_config: None,
             max_submit_position: None,
             submit_delay_step_override_millis: None,
+            validator_grpc_configs: BTreeMap::new(),
+            validator_economics_configs: BTreeMap::new(),
             state_accumulator_config: StateAccumulatorV1EnabledConfig::Global(true),
         }
     }
@@ -123,6 +127,8 @@ impl<R> SwarmBuilder<R> {
             fullnode_fw_config: self.fullnode_fw_config,
             max_submit_position: self.max_submit_position,
             submit_delay_step_override_millis: self.submit_delay_step_override_millis,
+            validator_grpc_configs: self.validator_grpc_configs,
+            validator_economics_configs: self.validator_economics_configs,
             state_accumulator_config: self.state_accumulator_config,
         }
     }
@@ -185,6 +191,15 @@ impl<R> SwarmBuilder<R> {
         self
     }
 
+    /// Allows the objects added with `with_objects` to override system
+    /// packages, which tests use to mock them.
+    pub fn with_system_package_override(mut self) -> Self {
+        self.get_or_init_genesis_config()
+            .parameters
+            .allow_system_package_override = true;
+        self
+    }
+
     pub fn with_fullnode_count(mut self, fullnode_count: usize) -> Self {
         self.fullnode_count = fullnode_count;
         self
@@ -303,6 +318,29 @@ impl<R> SwarmBuilder<R> {
         self.submit_delay_step_override_millis = Some(submit_delay_step_override_millis);
         self
     }
+
+    /// Overrides the gRPC server settings of the validator at index
+    /// `validator_idx`.
+    pub fn with_validator_grpc_config(
+        mut self,
+        validator_idx: usize,
+        config: ValidatorGrpcConfig,
+    ) -> Self {
+        self.validator_grpc_configs.insert(validator_idx, config);
+        self
+    }
+
+    /// Overrides the genesis gas price and commission rate of the validator at
+    /// index `validator_idx`.
+    pub fn with_validator_economics_config(
+        mut self,
+        validator_idx: usize,
+        config: ValidatorEconomicsConfig,
+    ) -> Self {
+        self.validator_economics_configs
+            .insert(validator_idx, config);
+        self
+    }
 }
 
 impl<R: rand::RngCore + rand::CryptoRng> SwarmBuilder<R> {
@@ -351,6 +389,16 @@ impl<R: rand::RngCore + rand::CryptoRng> SwarmBuilder<R> {
                     .with_submit_delay_step_override_millis(submit_delay_step_override_millis);
             }
 
+            for (validator_idx, grpc_config) in self.validator_grpc_configs {
+                config_builder =
+                    config_builder.with_validator_grpc_config(validator_idx, grpc_config);
+            }
+
+            for (validator_idx, economics_config) in self.validator_economics_configs {
+                config_builder =
+                    config_builder.with_validator_economics_config(validator_idx, economics_config);
+            }
+
             let mut network_config = config_builder
                 .committee(self.committee)
                 .rng(self.rng)
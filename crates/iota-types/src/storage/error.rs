@@ -23,6 +23,7 @@ struct Inner {
 pub enum Kind {
     Serialization,
     Missing,
+    Unsupported,
     Custom,
 }
 
@@ -44,6 +45,12 @@ impl Error {
         Self::new(Kind::Missing, Some(e))
     }
 
+    /// The operation is not supported by the store, e.g., because it relies
+    /// on an index that is disabled.
+    pub fn unsupported<E: Into<BoxError>>(e: E) -> Self {
+        Self::new(Kind::Unsupported, Some(e))
+    }
+
     pub fn custom<E: Into<BoxError>>(e: E) -> Self {
         Self::new(Kind::Custom, Some(e))
     }
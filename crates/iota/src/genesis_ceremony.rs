@@ -2,12 +2,15 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fs::File, path::PathBuf};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
-use fastcrypto::encoding::{Encoding, Hex};
+use fastcrypto::encoding::{Base64, Encoding, Hex};
 use iota_config::{
     IOTA_GENESIS_FILENAME,
     genesis::{Delegations, TokenDistributionScheduleBuilder, UnsignedGenesis},
@@ -24,7 +27,8 @@ use iota_types::{
     base_types::IotaAddress,
     committee::ProtocolVersion,
     crypto::{
-        AuthorityKeyPair, IotaKeyPair, KeypairTraits, NetworkKeyPair, generate_proof_of_possession,
+        AuthorityKeyPair, AuthoritySignature, IotaKeyPair, KeypairTraits, NetworkKeyPair,
+        generate_proof_of_possession, verify_proof_of_possession,
     },
     message_envelope::Message,
     multiaddr::Multiaddr,
@@ -95,6 +99,18 @@ pub enum CeremonyCommand {
         #[arg(long)]
         project_url: Option<String>,
     },
+    /// Generate the proof of possession of an authority key for an account
+    /// address, e.g. after changing the account address of a validator. The
+    /// proof of possession is printed Base64 encoded. Does not require a
+    /// Genesis builder.
+    GenerateProofOfPossession {
+        /// The path to the BLS12381 authority key file of the validator.
+        #[arg(long)]
+        authority_key_file: PathBuf,
+        /// The account address of the validator.
+        #[arg(long)]
+        account_address: IotaAddress,
+    },
     /// Initialize token distribution schedule.
     InitTokenDistributionSchedule {
         #[arg(
@@ -131,6 +147,13 @@ pub enum CeremonyCommand {
         #[arg(long)]
         key_file: PathBuf,
     },
+    /// Print a JSON manifest of the validators which did and did not yet sign
+    /// the built Genesis checkpoint, and whether their stake reaches quorum.
+    SignatureManifest {
+        /// Fail if the signed stake does not reach the quorum threshold.
+        #[arg(long)]
+        require_quorum: bool,
+    },
     /// Create the Genesis blob file from the current configuration.
     Finalize,
 }
@@ -226,6 +249,14 @@ pub async fn run(cmd: Ceremony) -> Result<()> {
             println!("Successfully added validator");
         }
 
+        CeremonyCommand::GenerateProofOfPossession {
+            authority_key_file,
+            account_address,
+        } => {
+            let pop = proof_of_possession(&authority_key_file, account_address)?;
+            println!("{}", Base64::encode(pop.as_ref()));
+        }
+
         CeremonyCommand::ListValidators => {
             let builder = Builder::load(&dir).await?;
 
@@ -332,6 +363,27 @@ pub async fn run(cmd: Ceremony) -> Result<()> {
             );
         }
 
+        CeremonyCommand::SignatureManifest { require_quorum } => {
+            let mut builder = Builder::load(&dir).await?;
+
+            if builder.unsigned_genesis_checkpoint().is_none() {
+                return Err(anyhow::anyhow!(
+                    "Unable to create signature manifest; try running `build-unsigned-checkpoint`"
+                ));
+            }
+
+            let manifest = builder.signature_manifest()?;
+            println!("{}", serde_json::to_string_pretty(&manifest)?);
+
+            if require_quorum && !manifest.has_quorum {
+                return Err(anyhow::anyhow!(
+                    "Signed stake {} is below the quorum threshold {}",
+                    manifest.signed_stake,
+                    manifest.quorum_threshold
+                ));
+            }
+        }
+
         CeremonyCommand::Finalize => {
             let builder = Builder::load(&dir).await?;
 
@@ -365,6 +417,24 @@ fn check_protocol_version(builder: &Builder, protocol_version: ProtocolVersion)
     Ok(())
 }
 
+/// Generates the proof of possession of the authority key stored in
+/// `authority_key_file` for `account_address`, and verifies it.
+fn proof_of_possession(
+    authority_key_file: &Path,
+    account_address: IotaAddress,
+) -> Result<AuthoritySignature> {
+    let authority_keypair: AuthorityKeyPair = read_authority_keypair_from_file(authority_key_file)
+        .with_context(|| {
+            format!(
+                "{} is not a valid BLS12381 authority key file",
+                authority_key_file.display()
+            )
+        })?;
+    let pop = generate_proof_of_possession(&authority_keypair, account_address);
+    verify_proof_of_possession(&pop, authority_keypair.public(), account_address)?;
+    Ok(pop)
+}
+
 #[cfg(test)]
 mod test {
     use anyhow::Result;
@@ -509,6 +579,17 @@ mod test {
             .await?;
         }
 
+        // All validators signed, so the signed stake must reach quorum
+        Ceremony {
+            path: Some(dir.path().into()),
+            protocol_version: MAX_PROTOCOL_VERSION,
+            command: CeremonyCommand::SignatureManifest {
+                require_quorum: true,
+            },
+        }
+        .run()
+        .await?;
+
         // Finalize the Ceremony and build the Genesis object
         let command = Ceremony {
             path: Some(dir.path().into()),
@@ -519,4 +600,27 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn proof_of_possession_from_key_file() -> Result<()> {
+        let dir = nondeterministic!(tempfile::TempDir::new().unwrap());
+        let authority_keypair: AuthorityKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let authority_key_file = dir.path().join("authority.key");
+        write_authority_keypair_to_file(&authority_keypair, &authority_key_file)?;
+        let account_address = IotaAddress::random_for_testing_only();
+
+        let pop = proof_of_possession(&authority_key_file, account_address)?;
+        assert_eq!(
+            pop,
+            generate_proof_of_possession(&authority_keypair, account_address)
+        );
+
+        // Other key types are rejected
+        let account_keypair: AccountKeyPair = get_key_pair_from_rng(&mut rand::rngs::OsRng).1;
+        let account_key_file = dir.path().join("account.key");
+        write_keypair_to_file(&IotaKeyPair::Ed25519(account_keypair), &account_key_file)?;
+        assert!(proof_of_possession(&account_key_file, account_address).is_err());
+
+        Ok(())
+    }
 }
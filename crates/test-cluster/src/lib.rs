@@ -3,32 +3,43 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    collections::{BTreeMap, HashMap},
-    net::SocketAddr,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    net::{IpAddr, SocketAddr},
     num::NonZeroUsize,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
     time::Duration,
 };
 
-use futures::{Future, StreamExt, future::join_all};
+use futures::{
+    Future, StreamExt,
+    future::{BoxFuture, join_all},
+    stream::FuturesUnordered,
+};
 use iota_bridge::{
     crypto::{BridgeAuthorityKeyPair, BridgeAuthoritySignInfo},
     iota_transaction_builder::{
         build_add_tokens_on_iota_transaction, build_committee_register_transaction,
     },
     types::{
-        BridgeCommitteeValiditySignInfo, CertifiedBridgeAction, VerifiedCertifiedBridgeAction,
+        BridgeAction, BridgeCommitteeValiditySignInfo, CertifiedBridgeAction,
+        VerifiedCertifiedBridgeAction,
     },
     utils::{publish_and_register_coins_return_add_coins_on_iota_action, wait_for_server_to_be_up},
 };
 use iota_config::{
-    Config, IOTA_CLIENT_CONFIG, IOTA_KEYSTORE_FILENAME, IOTA_NETWORK_CONFIG, NodeConfig,
-    PersistedConfig,
+    AUTHORITIES_DB_NAME, CONSENSUS_DB_NAME, Config, IOTA_CLIENT_CONFIG,
+    IOTA_GENESIS_MIGRATION_TX_DATA_FILENAME, IOTA_KEYSTORE_FILENAME, IOTA_NETWORK_CONFIG,
+    NodeConfig, PersistedConfig,
     genesis::Genesis,
     local_ip_utils::get_available_port,
     node::{AuthorityOverloadConfig, DBCheckpointConfig, RunWithRange},
 };
+#[cfg(msim)]
+use iota_core::consensus_adapter::SubmissionOverride;
 use iota_core::{
     authority_aggregator::AuthorityAggregator, authority_client::NetworkAuthorityClient,
 };
@@ -38,12 +49,12 @@ use iota_json_rpc_api::{
     error_object_from_rpc,
 };
 use iota_json_rpc_types::{
-    IotaExecutionStatus, IotaObjectDataOptions, IotaObjectResponse, IotaObjectResponseQuery,
-    IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponse,
+    IotaExecutionStatus, IotaObjectDataFilter, IotaObjectDataOptions, IotaObjectResponse,
+    IotaObjectResponseQuery, IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponse,
     IotaTransactionBlockResponseOptions, TransactionFilter,
 };
 use iota_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
-use iota_node::IotaNodeHandle;
+use iota_node::{IotaNode, IotaNodeHandle};
 use iota_protocol_config::ProtocolVersion;
 use iota_sdk::{
     IotaClient, IotaClientBuilder,
@@ -57,7 +68,7 @@ use iota_swarm_config::{
     network_config::{NetworkConfig, NetworkConfigLight},
     network_config_builder::{
         ProtocolVersionsConfig, StateAccumulatorEnabledCallback, StateAccumulatorV1EnabledConfig,
-        SupportedProtocolVersionsCallback,
+        SupportedProtocolVersionsCallback, ValidatorEconomicsConfig, ValidatorGrpcConfig,
     },
     node_config_builder::{FullnodeConfigBuilder, ValidatorConfigBuilder},
 };
@@ -70,19 +81,24 @@ use iota_types::{
         get_bridge, get_bridge_obj_initial_shared_version,
     },
     committee::{Committee, CommitteeTrait, EpochId},
-    crypto::{AccountKeyPair, IotaKeyPair, KeypairTraits, ToFromBytes, get_key_pair},
-    effects::{TransactionEffects, TransactionEvents},
-    error::IotaResult,
-    governance::MIN_VALIDATOR_JOINING_STAKE_NANOS,
+    crypto::{
+        AccountKeyPair, EncodeDecodeBase64, IotaKeyPair, KeypairTraits, ToFromBytes, get_key_pair,
+    },
+    digests::TransactionDigest,
+    effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
+    event::Event,
+    governance::{MIN_VALIDATOR_JOINING_STAKE_NANOS, StakedIota},
     iota_system_state::{
         IotaSystemState, IotaSystemStateTrait,
-        epoch_start_iota_system_state::EpochStartSystemStateTrait,
+        epoch_start_iota_system_state::EpochStartSystemStateTrait, get_iota_system_state,
     },
     message_envelope::Message,
+    messages_consensus::AuthorityCapabilitiesV1,
     messages_grpc::HandleCertificateRequestV1,
-    object::Object,
+    object::{GAS_VALUE_FOR_TESTING, Object},
     quorum_driver_types::ExecuteTransactionRequestType,
     supported_protocol_versions::SupportedProtocolVersions,
+    timelock::timelocked_staked_iota::TimelockedStakedIota,
     traffic_control::{PolicyConfig, RemoteFirewallConfig},
     transaction::{
         CertifiedTransaction, ObjectArg, Transaction, TransactionData, TransactionDataAPI,
@@ -96,10 +112,11 @@ use jsonrpsee::{
 };
 use rand::{distributions::*, rngs::OsRng, seq::SliceRandom};
 use tokio::{
+    sync::broadcast::error::RecvError,
     task::JoinHandle,
     time::{Instant, sleep, timeout},
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 const NUM_VALIDATOR: usize = 4;
 
@@ -126,9 +143,65 @@ impl FullNodeHandle {
     }
 }
 
+/// Name of the network config file written by [`TestCluster::export_state`].
+const EXPORTED_NETWORK_CONFIG: &str = "exported_network.yaml";
+/// Name of the file with the base64 encoded faucet keys written by
+/// [`TestCluster::export_state`].
+const EXPORTED_FAUCET_KEYS: &str = "exported_faucet.keys";
+
 struct Faucet {
     address: IotaAddress,
     keypair: Arc<tokio::sync::Mutex<IotaKeyPair>>,
+    /// Gas coins of the faucet currently used by a funding transaction.
+    coins_in_use: Mutex<HashSet<ObjectID>>,
+}
+
+/// Latency percentiles over a batch of transactions, see
+/// [`TestCluster::measure_commit_latency`].
+#[derive(Debug, Clone, Copy)]
+pub struct CommitLatencyStats {
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl CommitLatencyStats {
+    fn from_latencies(mut latencies: Vec<Duration>) -> Self {
+        assert!(!latencies.is_empty(), "no latencies were recorded");
+        latencies.sort();
+        let percentile = |p: usize| latencies[(latencies.len() - 1) * p / 100];
+        Self {
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: *latencies.last().unwrap(),
+        }
+    }
+}
+
+/// Time to finality over a batch of transactions, see
+/// [`TestCluster::measure_finality`].
+#[derive(Debug, Clone, Copy)]
+pub struct FinalityStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+}
+
+impl FinalityStats {
+    fn from_latencies(mut latencies: Vec<Duration>) -> Self {
+        assert!(!latencies.is_empty(), "no latencies were recorded");
+        latencies.sort();
+        let percentile = |p: usize| latencies[(latencies.len() - 1) * p / 100];
+        Self {
+            min: latencies[0],
+            median: percentile(50),
+            p95: percentile(95),
+            max: *latencies.last().unwrap(),
+        }
+    }
 }
 
 pub struct TestCluster {
@@ -137,7 +210,18 @@ pub struct TestCluster {
     pub fullnode_handle: FullNodeHandle,
     pub bridge_authority_keys: Option<Vec<BridgeAuthorityKeyPair>>,
     pub bridge_server_ports: Option<Vec<u16>>,
-    faucet: Option<Faucet>,
+    bridge_committee_members: Option<Vec<(AuthorityName, String)>>,
+    /// The faucet accounts, empty if the cluster was built from a
+    /// `NetworkConfig` other than an exported state.
+    faucets: Vec<Faucet>,
+    /// Index of the faucet account used by the next funding transaction.
+    next_faucet: AtomicUsize,
+    /// The copy of an exported state the cluster was restored from, removed
+    /// once the cluster is dropped.
+    _restored_state_dir: Option<tempfile::TempDir>,
+    /// The `(epoch, protocol version)` pairs of the epochs the rpc fullnode
+    /// went through, recorded in the background.
+    protocol_version_timeline: Arc<Mutex<Vec<(EpochId, u64)>>>,
 }
 
 impl TestCluster {
@@ -216,6 +300,25 @@ impl TestCluster {
             .collect()
     }
 
+    /// Calls `f` on every running node and returns the results, in the order
+    /// of [`Self::all_node_handles`].
+    pub fn map_all_nodes<T>(&self, f: impl Fn(&IotaNode) -> T) -> Vec<T> {
+        self.all_node_handles()
+            .iter()
+            .map(|handle| handle.with(&f))
+            .collect()
+    }
+
+    /// Async variant of [`Self::map_all_nodes`], the futures of all nodes are
+    /// polled concurrently.
+    pub async fn map_all_nodes_async<T>(
+        &self,
+        f: impl for<'a> Fn(&'a IotaNode) -> BoxFuture<'a, T>,
+    ) -> Vec<T> {
+        let handles = self.all_node_handles();
+        join_all(handles.iter().map(|handle| handle.with_async(&f))).await
+    }
+
     pub fn all_validator_handles(&self) -> Vec<IotaNodeHandle> {
         self.swarm
             .validator_nodes()
@@ -235,6 +338,25 @@ impl TestCluster {
         self.swarm.node(name).unwrap().stop();
     }
 
+    /// Stops the node `name` and waits until it was dropped, i.e. until it
+    /// released its databases.
+    async fn stop_node_and_wait_until_dropped(&self, name: &AuthorityName) {
+        let node = self.swarm.node(name).unwrap();
+        let Some(handle) = node.get_node_handle() else {
+            return;
+        };
+        let iota_node = Arc::downgrade(handle.inner());
+        drop(handle);
+        node.stop();
+        tokio::time::timeout(Duration::from_secs(30), async {
+            while iota_node.strong_count() > 0 {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("node {name} was not dropped after it was stopped"));
+    }
+
     pub async fn stop_all_validators(&self) {
         info!("Stopping all validators in the cluster");
         self.swarm.active_validators().for_each(|v| v.stop());
@@ -260,6 +382,169 @@ impl TestCluster {
         node.start().await.unwrap();
     }
 
+    /// Stops `validators`, executes `tx` with the remaining validators and
+    /// asserts that it is finalized within 60 seconds, then starts the
+    /// stopped validators again. Panics if the remaining validators don't
+    /// hold a quorum of the voting power.
+    pub async fn stop_validators_and_assert_liveness(
+        &self,
+        validators: &[AuthorityName],
+        tx: Transaction,
+    ) -> IotaTransactionBlockResponse {
+        let committee = self.committee();
+        // Ignore duplicates, so that their stake is not counted twice.
+        let validators = validators.iter().collect::<BTreeSet<_>>();
+        let stopped_stake: u64 = validators.iter().map(|name| committee.weight(name)).sum();
+        let remaining_stake = committee.total_votes() - stopped_stake;
+        assert!(
+            remaining_stake >= committee.quorum_threshold(),
+            "the remaining validators hold {remaining_stake} voting power, which is below the quorum threshold {}",
+            committee.quorum_threshold()
+        );
+
+        for name in &validators {
+            self.stop_node(name);
+        }
+        let digest = *tx.digest();
+        let result = timeout(Duration::from_secs(60), self.execute_transaction(tx)).await;
+        for name in &validators {
+            self.start_node(name).await;
+        }
+
+        result.unwrap_or_else(|_| {
+            panic!(
+                "transaction {digest} was not finalized after stopping {} validators",
+                validators.len()
+            )
+        })
+    }
+
+    /// Exports the state of the validators to `path`, so that it can be
+    /// loaded by [`TestClusterBuilder::from_exported_state`]. The running
+    /// validators are stopped while their databases are copied and started
+    /// again afterwards.
+    ///
+    /// The fullnode's database is not exported, the fullnode of a restored
+    /// cluster syncs from the validators. The bridge servers are not part of
+    /// the exported state either.
+    pub async fn export_state(&self, path: &Path) -> anyhow::Result<()> {
+        let mut faucet_keys = Vec::with_capacity(self.faucets.len());
+        for faucet in &self.faucets {
+            faucet_keys.push(faucet.keypair.lock().await.encode_base64());
+        }
+
+        let running_validators = self
+            .swarm
+            .active_validators()
+            .map(|node| node.name())
+            .collect::<Vec<_>>();
+        for name in &running_validators {
+            self.stop_node_and_wait_until_dropped(name).await;
+        }
+
+        let result = self.copy_state(path, &faucet_keys);
+
+        for name in &running_validators {
+            self.start_node(name).await;
+        }
+        result
+    }
+
+    fn copy_state(&self, path: &Path, faucet_keys: &[String]) -> anyhow::Result<()> {
+        let swarm_dir = self.swarm.dir();
+        std::fs::create_dir_all(path)?;
+        let options = fs_extra::dir::CopyOptions::new().overwrite(true);
+        for db in [AUTHORITIES_DB_NAME, CONSENSUS_DB_NAME] {
+            fs_extra::dir::copy(swarm_dir.join(db), path, &options)?;
+        }
+        let migration_tx_data = swarm_dir.join(IOTA_GENESIS_MIGRATION_TX_DATA_FILENAME);
+        if migration_tx_data.exists() {
+            std::fs::copy(
+                &migration_tx_data,
+                path.join(IOTA_GENESIS_MIGRATION_TX_DATA_FILENAME),
+            )?;
+        }
+
+        // Store the paths relative to the swarm directory, so that the exported
+        // state can be restored to any directory.
+        let network_config = self.swarm.config();
+        let validator_configs = network_config
+            .validator_configs()
+            .iter()
+            .map(|config| {
+                let mut config = config.clone();
+                config.genesis = iota_config::node::Genesis::new(network_config.genesis.clone());
+                rebase_node_config_paths(&mut config, |file| {
+                    file.strip_prefix(swarm_dir).unwrap_or(file).to_path_buf()
+                });
+                config
+            })
+            .collect();
+        NetworkConfig {
+            validator_configs,
+            account_keys: network_config
+                .account_keys
+                .iter()
+                .map(|key| key.copy())
+                .collect(),
+            genesis: network_config.genesis.clone(),
+        }
+        .save(path.join(EXPORTED_NETWORK_CONFIG))?;
+        std::fs::write(path.join(EXPORTED_FAUCET_KEYS), faucet_keys.join("\n"))?;
+        Ok(())
+    }
+
+    /// Stops the node `name`, deletes its `store`, `checkpoints`, `epochs`,
+    /// `indexes` and `rest_index` databases and starts it again. Waits until
+    /// the node has re-synced to the highest checkpoint executed by any node
+    /// before the wipe.
+    pub async fn wipe_and_restart_node(&self, name: &AuthorityName) {
+        let highest_checkpoint = self
+            .map_all_nodes(|node| {
+                node.state()
+                    .get_checkpoint_store()
+                    .get_highest_executed_checkpoint_seq_number()
+                    .unwrap()
+                    .unwrap_or_default()
+            })
+            .into_iter()
+            .max()
+            .unwrap_or_default();
+
+        self.stop_node_and_wait_until_dropped(name).await;
+        let node = self.swarm.node(name).unwrap();
+        let db_path = node.config().db_path();
+        for db in ["store", "checkpoints", "epochs", "indexes", "rest_index"] {
+            let path = db_path.join(db);
+            if path.exists() {
+                std::fs::remove_dir_all(&path)
+                    .unwrap_or_else(|e| panic!("Failed to remove {}: {e}", path.display()));
+            }
+        }
+        info!(name =% name.concise(), "wiped node databases, restarting");
+        node.start().await.unwrap();
+
+        let handle = node.get_node_handle().unwrap();
+        tokio::time::timeout(Duration::from_secs(120), async {
+            loop {
+                let executed = handle.with(|node| {
+                    node.state()
+                        .get_checkpoint_store()
+                        .get_highest_executed_checkpoint_seq_number()
+                        .unwrap()
+                });
+                match executed {
+                    Some(c) if c >= highest_checkpoint => break,
+                    _ => tokio::time::sleep(Duration::from_millis(100)).await,
+                }
+            }
+        })
+        .await
+        .unwrap_or_else(|_| {
+            panic!("Node {name} did not re-sync to checkpoint {highest_checkpoint} after the wipe")
+        });
+    }
+
     pub async fn spawn_new_validator(
         &mut self,
         genesis_config: ValidatorGenesisConfig,
@@ -281,6 +566,37 @@ impl TestCluster {
             .expect("failed to get reference gas price")
     }
 
+    /// Returns the reference gas price of the current epoch as seen by the
+    /// node `name`, which has to be running.
+    pub fn get_reference_gas_price_from_node(&self, name: &AuthorityName) -> u64 {
+        self.swarm
+            .node(name)
+            .unwrap_or_else(|| panic!("no node {name} in the cluster"))
+            .get_node_handle()
+            .unwrap_or_else(|| panic!("node {name} is not running"))
+            .with(|node| node.reference_gas_price_for_testing())
+            .expect("failed to get reference gas price")
+    }
+
+    /// Returns the number of requests the traffic controller of validator
+    /// `name` dropped per client IP. Panics if the validator is not running
+    /// or has no traffic control policy configured.
+    pub async fn get_traffic_controller_dropped_requests(
+        &self,
+        name: &AuthorityName,
+    ) -> HashMap<IpAddr, u64> {
+        let handle = self
+            .swarm
+            .node(name)
+            .unwrap_or_else(|| panic!("no node {name} in the cluster"))
+            .get_node_handle()
+            .unwrap_or_else(|| panic!("node {name} is not running"));
+        handle
+            .with_async(|node| node.traffic_controller_dropped_requests())
+            .await
+            .unwrap_or_else(|| panic!("node {name} has no traffic controller"))
+    }
+
     pub async fn get_object_from_fullnode_store(&self, object_id: &ObjectID) -> Option<Object> {
         self.fullnode_handle
             .iota_node
@@ -288,6 +604,64 @@ impl TestCluster {
             .await
     }
 
+    /// Returns the capabilities, i.e. the supported protocol versions and the
+    /// available system packages, that validator `name` most recently
+    /// advertised to the committee.
+    pub fn get_advertised_capabilities(
+        &self,
+        name: &AuthorityName,
+    ) -> Option<AuthorityCapabilitiesV1> {
+        self.swarm
+            .node(name)
+            .expect("validator not found in the swarm")
+            .get_node_handle()
+            .expect("validator is not running")
+            .with(|node| node.last_advertised_capabilities())
+    }
+
+    /// Writes a bogus next version of `object_id` directly into the perpetual
+    /// store of validator `name`, bypassing execution. The injected object
+    /// diverges from what the rest of the committee has, which allows tests to
+    /// exercise corruption detection and recovery paths. Returns the injected
+    /// object.
+    pub async fn inject_corrupted_object_version(
+        &self,
+        name: &AuthorityName,
+        object_id: ObjectID,
+    ) -> Object {
+        let handle = self
+            .swarm
+            .node(name)
+            .expect("validator not found in the swarm")
+            .get_node_handle()
+            .expect("validator is not running");
+        handle
+            .with_async(|node| async move {
+                let state = node.state();
+                let latest = state
+                    .get_object(&object_id)
+                    .await
+                    .unwrap()
+                    .expect("object not found in validator store");
+                let corrupted = Object::with_id_owner_version_for_testing(
+                    object_id,
+                    latest.version().next(),
+                    IotaAddress::random_for_testing_only(),
+                );
+                state
+                    .database_for_testing()
+                    .insert_object_for_testing(corrupted.clone())
+                    .expect("failed to insert corrupted object");
+                info!(
+                    "Injected corrupted version {} of object {object_id} into the store of validator {}",
+                    corrupted.version(),
+                    name.concise()
+                );
+                corrupted
+            })
+            .await
+    }
+
     pub async fn get_latest_object_ref(&self, object_id: &ObjectID) -> ObjectRef {
         self.get_object_from_fullnode_store(object_id)
             .await
@@ -410,6 +784,39 @@ impl TestCluster {
         info!("reconfiguration complete after {:?}", start.elapsed());
     }
 
+    /// Executes the given transactions and forces new epochs until
+    /// `predicate` holds for the system state, returning that system state.
+    /// Panics if the predicate still does not hold after `max_epochs` epoch
+    /// changes.
+    ///
+    /// This is meant for parameters which take effect at the next epoch
+    /// boundary, e.g. a validator's gas price or commission rate. Chain
+    /// parameters like `min_validator_joining_stake` or `epoch_duration_ms`
+    /// cannot be changed by a transaction after genesis, so they still have
+    /// to be set through the genesis config.
+    pub async fn advance_epochs_until_system_state(
+        &self,
+        txs: Vec<Transaction>,
+        max_epochs: u64,
+        predicate: impl Fn(&IotaSystemState) -> bool,
+    ) -> IotaSystemState {
+        for tx in txs {
+            self.execute_transaction(tx).await;
+        }
+        for _ in 0..max_epochs {
+            self.force_new_epoch().await;
+            let system_state = self.fullnode_handle.iota_node.with(|node| {
+                node.state()
+                    .get_iota_system_state_object_for_testing()
+                    .unwrap()
+            });
+            if predicate(&system_state) {
+                return system_state;
+            }
+        }
+        panic!("system state did not reach the expected state after {max_epochs} epochs");
+    }
+
     /// To detect whether the network has reached such state, we use the
     /// fullnode as the source of truth, since a fullnode only does epoch
     /// transition when the network has done so.
@@ -422,6 +829,12 @@ impl TestCluster {
             .await
     }
 
+    /// Returns the epochs the rpc fullnode went through since the cluster was
+    /// built, each with the protocol version it ran, in epoch order.
+    pub fn protocol_version_timeline(&self) -> Vec<(EpochId, u64)> {
+        self.protocol_version_timeline.lock().unwrap().clone()
+    }
+
     pub async fn wait_for_epoch_on_node(
         &self,
         handle: &IotaNodeHandle,
@@ -436,7 +849,15 @@ impl TestCluster {
             if Some(epoch) == target_epoch {
                 return handle.with(|node| node.state().get_iota_system_state_object_for_testing().unwrap());
             }
-            while let Ok(system_state) = epoch_rx.recv().await {
+            loop {
+                let system_state = match epoch_rx.recv().await {
+                    Ok(system_state) => system_state,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("Epoch change subscriber skipped {skipped} epoch changes");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => unreachable!("Broken reconfig channel"),
+                };
                 info!("received epoch {}", system_state.epoch());
                 state = Some(system_state.clone());
                 match target_epoch {
@@ -449,7 +870,6 @@ impl TestCluster {
                     _ => (),
                 }
             }
-            unreachable!("Broken reconfig channel");
         })
             .await
             .unwrap_or_else(|_| {
@@ -549,6 +969,49 @@ impl TestCluster {
         }
     }
 
+    /// Upgrade the network to `protocol_version`: every validator is restarted
+    /// supporting versions up to `protocol_version`, then new epochs are
+    /// forced until the version is active and all nodes run it. Returns the
+    /// system state of the epoch in which the version became active.
+    pub async fn upgrade_to_protocol_version(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> IotaSystemState {
+        let system_state = self.fullnode_handle.iota_node.with(|node| {
+            node.state()
+                .get_iota_system_state_object_for_testing()
+                .unwrap()
+        });
+        let current_version = system_state.protocol_version();
+        if current_version >= protocol_version.as_u64() {
+            return system_state;
+        }
+
+        self.update_validator_supported_versions(SupportedProtocolVersions::new_for_testing(
+            ProtocolVersion::MIN.as_u64(),
+            protocol_version.as_u64(),
+        ))
+        .await;
+
+        // The protocol version is bumped by at most one per epoch, one extra epoch
+        // is needed for the validators to advertise their new capabilities.
+        let max_epochs = protocol_version.as_u64() - current_version + 1;
+        for _ in 0..max_epochs {
+            self.force_new_epoch().await;
+            let system_state = self.fullnode_handle.iota_node.with(|node| {
+                node.state()
+                    .get_iota_system_state_object_for_testing()
+                    .unwrap()
+            });
+            if system_state.protocol_version() >= protocol_version.as_u64() {
+                self.wait_for_all_nodes_upgrade_to(protocol_version.as_u64())
+                    .await;
+                return system_state;
+            }
+        }
+        panic!("Protocol version {protocol_version:?} wasn't activated after {max_epochs} epochs");
+    }
+
     pub async fn trigger_reconfiguration_if_not_yet_and_assert_bridge_committee_initialized(&self) {
         let mut bridge =
             get_bridge(self.fullnode_handle.iota_node.state().get_object_store()).unwrap();
@@ -570,6 +1033,45 @@ impl TestCluster {
         );
     }
 
+    /// Asserts that the bridge is not frozen, that its committee consists of
+    /// all active validators, that every route has a non-zero transfer limit
+    /// and that all `expected_tokens` are registered with a non-zero price.
+    pub async fn assert_bridge_ready(&self, expected_tokens: &[u8]) {
+        let summary = self.get_bridge_summary().await.unwrap();
+        assert!(!summary.is_frozen, "bridge is frozen");
+        assert!(
+            !summary.committee.members.is_empty(),
+            "bridge committee is empty"
+        );
+        assert_eq!(
+            summary.committee.members.len(),
+            self.swarm.active_validators().count(),
+            "bridge committee size does not match the number of active validators"
+        );
+        assert!(
+            !summary.limiter.transfer_limit.is_empty(),
+            "no bridge transfer limits set"
+        );
+        for (source, destination, limit) in &summary.limiter.transfer_limit {
+            assert_ne!(
+                *limit, 0,
+                "zero transfer limit from {source:?} to {destination:?}"
+            );
+        }
+        for token_id in expected_tokens {
+            let (_, metadata) = summary
+                .treasury
+                .supported_tokens
+                .iter()
+                .find(|(_, metadata)| metadata.id == *token_id)
+                .unwrap_or_else(|| panic!("token {token_id} is not registered on the bridge"));
+            assert_ne!(
+                metadata.notional_value, 0,
+                "token {token_id} has no notional value"
+            );
+        }
+    }
+
     // Wait for bridge node in the cluster to be up and running.
     pub async fn wait_for_bridge_cluster_to_be_up(&self, timeout_sec: u64) {
         let bridge_ports = self.bridge_server_ports.as_ref().unwrap();
@@ -585,6 +1087,13 @@ impl TestCluster {
             .unwrap();
     }
 
+    /// Returns every bridge committee member together with the bridge server
+    /// url it registered, empty if the cluster was not built with
+    /// [`TestClusterBuilder::build_with_bridge`].
+    pub fn bridge_committee_members(&self) -> Vec<(AuthorityName, String)> {
+        self.bridge_committee_members.clone().unwrap_or_default()
+    }
+
     pub async fn get_mut_bridge_arg(&self) -> Option<ObjectArg> {
         get_bridge_obj_initial_shared_version(
             self.fullnode_handle.iota_node.state().get_object_store(),
@@ -597,6 +1106,78 @@ impl TestCluster {
         })
     }
 
+    /// Publishes the token packages in `token_packages_dir`, registers them on
+    /// the bridge and adds them with the given ids and prices, using an action
+    /// certified by the bridge committee.
+    ///
+    /// The bridge committee must already be initialized, see
+    /// [`TestClusterBuilder::build_with_bridge`]. Every call needs a new
+    /// `nonce`, the tokens deployed by `build_with_bridge` use nonce `0`.
+    pub async fn add_bridge_tokens(
+        &self,
+        token_packages_dir: Vec<PathBuf>,
+        token_ids: Vec<u8>,
+        token_prices: Vec<u64>,
+        nonce: u64,
+    ) {
+        let bridge_arg = self.get_mut_bridge_arg().await.unwrap();
+        let action = publish_and_register_coins_return_add_coins_on_iota_action(
+            &self.wallet,
+            bridge_arg,
+            token_packages_dir,
+            token_ids,
+            token_prices,
+            nonce,
+        )
+        .await;
+        self.execute_add_bridge_tokens_action(action, bridge_arg)
+            .await;
+    }
+
+    async fn execute_add_bridge_tokens_action(&self, action: BridgeAction, bridge_arg: ObjectArg) {
+        let sig_map = self
+            .bridge_authority_keys
+            .as_ref()
+            .expect("bridge authority keys are only set by build_with_bridge")
+            .iter()
+            .map(|key| {
+                (
+                    key.public().into(),
+                    BridgeAuthoritySignInfo::new(&action, key).signature,
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let certified_action = CertifiedBridgeAction::new_from_data_and_sig(
+            action,
+            BridgeCommitteeValiditySignInfo {
+                signatures: sig_map,
+            },
+        );
+        let verifired_action_cert =
+            VerifiedCertifiedBridgeAction::new_from_verified(certified_action);
+        let sender_address = self.get_address_0();
+
+        let tx = build_add_tokens_on_iota_transaction(
+            sender_address,
+            &self
+                .wallet
+                .get_one_gas_object_owned_by_address(sender_address)
+                .await
+                .unwrap()
+                .unwrap(),
+            verifired_action_cert,
+            bridge_arg,
+            self.get_reference_gas_price().await,
+        )
+        .unwrap();
+
+        let response = self.sign_and_execute_transaction(&tx).await;
+        assert_eq!(
+            response.effects.unwrap().status(),
+            &IotaExecutionStatus::Success
+        );
+    }
+
     pub async fn wait_for_authenticator_state_update(&self) {
         timeout(
             Duration::from_secs(60),
@@ -631,18 +1212,15 @@ impl TestCluster {
 
     /// Return the highest observed protocol version in the test cluster.
     pub fn highest_protocol_version(&self) -> ProtocolVersion {
-        self.all_node_handles()
-            .into_iter()
-            .map(|h| {
-                h.with(|node| {
-                    node.state()
-                        .epoch_store_for_testing()
-                        .epoch_start_state()
-                        .protocol_version()
-                })
-            })
-            .max()
-            .expect("at least one node must be up to get highest protocol version")
+        self.map_all_nodes(|node| {
+            node.state()
+                .epoch_store_for_testing()
+                .epoch_start_state()
+                .protocol_version()
+        })
+        .into_iter()
+        .max()
+        .expect("at least one node must be up to get highest protocol version")
     }
 
     pub async fn test_transaction_builder(&self) -> TestTransactionBuilder {
@@ -715,6 +1293,111 @@ impl TestCluster {
         Ok(results)
     }
 
+    /// Executes `tx` on the validators and asserts that it emitted an event
+    /// matching `predicate`. The events are those agreed on by a quorum of
+    /// validators, the call panics if the validators disagree on the effects
+    /// or if the returned events don't match the agreed effects. Returns the
+    /// first matching event.
+    pub async fn assert_transaction_emitted_event(
+        &self,
+        tx: Transaction,
+        predicate: impl Fn(&Event) -> bool,
+    ) -> Event {
+        let digest = *tx.digest();
+        let (effects, events) = self
+            .execute_transaction_return_raw_effects(tx)
+            .await
+            .unwrap_or_else(|e| panic!("Failed to execute transaction {digest}: {e}"));
+        assert_eq!(
+            effects.events_digest(),
+            (!events.data.is_empty()).then(|| events.digest()).as_ref(),
+            "Events returned for transaction {digest} don't match the agreed effects"
+        );
+        events
+            .data
+            .into_iter()
+            .find(|event| predicate(event))
+            .unwrap_or_else(|| panic!("Transaction {digest} didn't emit a matching event"))
+    }
+
+    /// Submits all `txs` concurrently through the fullnode and measures for
+    /// each of them the time from submission until its certified effects are
+    /// published by the fullnode's transaction orchestrator, i.e. until the
+    /// transaction was committed by consensus and executed by a quorum.
+    /// Panics if any of the transactions fails.
+    pub async fn measure_commit_latency(&self, txs: Vec<Transaction>) -> CommitLatencyStats {
+        CommitLatencyStats::from_latencies(self.measure_effects_latencies(txs).await)
+    }
+
+    /// Like [`Self::measure_commit_latency`], but summarizes the time to
+    /// finality of the transactions by its minimum, median, 95th percentile
+    /// and maximum.
+    pub async fn measure_finality(&self, txs: Vec<Transaction>) -> FinalityStats {
+        FinalityStats::from_latencies(self.measure_effects_latencies(txs).await)
+    }
+
+    /// Returns the time from submission until the certified effects were
+    /// published for each of `txs`.
+    async fn measure_effects_latencies(&self, txs: Vec<Transaction>) -> Vec<Duration> {
+        let mut effects_rx = self.fullnode_handle.iota_node.with(|node| {
+            node.subscribe_to_transaction_orchestrator_effects()
+                .expect("Fullnode should have transaction orchestrator toggled on.")
+        });
+        let mut pending: HashSet<TransactionDigest> = txs.iter().map(|tx| *tx.digest()).collect();
+
+        let submit = join_all(txs.into_iter().map(|tx| async move {
+            let digest = *tx.digest();
+            let submitted_at = Instant::now();
+            self.quorum_driver_api()
+                .execute_transaction_block(
+                    tx,
+                    IotaTransactionBlockResponseOptions::new(),
+                    Some(ExecuteTransactionRequestType::WaitForEffectsCert),
+                )
+                .await
+                .unwrap_or_else(|e| panic!("Failed to execute transaction {digest}: {e}"));
+            (digest, submitted_at, Instant::now())
+        }));
+        let collect = async {
+            let mut committed_at = HashMap::new();
+            while !pending.is_empty() {
+                match effects_rx.recv().await {
+                    Ok(Ok((tx, _))) => {
+                        if pending.remove(tx.digest()) {
+                            committed_at.insert(*tx.digest(), Instant::now());
+                        }
+                    }
+                    Ok(Err((digest, err))) if pending.contains(&digest) => {
+                        panic!("Transaction {digest} failed: {err}")
+                    }
+                    Ok(Err(_)) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        // The effects of pending transactions may have been skipped, so
+                        // fall back to the time their execution request returned.
+                        warn!(
+                            "Effects queue skipped {skipped} results, using the response time for the {} pending transactions",
+                            pending.len()
+                        );
+                        break;
+                    }
+                    Err(RecvError::Closed) => panic!("effects queue closed"),
+                }
+            }
+            committed_at
+        };
+        let (submitted_at, committed_at) = tokio::join!(submit, collect);
+
+        submitted_at
+            .into_iter()
+            .map(|(digest, submitted_at, returned_at)| {
+                committed_at
+                    .get(&digest)
+                    .unwrap_or(&returned_at)
+                    .duration_since(submitted_at)
+            })
+            .collect()
+    }
+
     pub fn authority_aggregator(&self) -> Arc<AuthorityAggregator<NetworkAuthorityClient>> {
         self.fullnode_handle
             .iota_node
@@ -739,6 +1422,9 @@ impl TestCluster {
     /// directly such as the original effects, events and extra objects
     /// returned. This also allows us to control which validator to send
     /// certificates to, which is useful in some tests.
+    ///
+    /// Returns as soon as validators holding a quorum of the stake agree on the
+    /// effects, and fails as soon as a validator returns conflicting effects.
     pub async fn submit_transaction_to_validators(
         &self,
         tx: Transaction,
@@ -749,90 +1435,123 @@ impl TestCluster {
             .process_transaction(tx, None)
             .await?
             .into_cert_for_testing();
-        let replies = loop {
-            let futures: Vec<_> = agg
-                .authority_clients
+        let committee = agg.committee.clone();
+        let clients: Vec<_> = agg
+            .authority_clients
+            .iter()
+            .filter(|(name, _)| pubkeys.contains(name))
+            .collect();
+        // If the given validators can't form a quorum, wait for all of them to reply.
+        let threshold = committee
+            .quorum_threshold()
+            .min(clients.iter().map(|(name, _)| committee.weight(name)).sum());
+        loop {
+            let mut futures: FuturesUnordered<_> = clients
                 .iter()
-                .filter_map(|(name, client)| {
-                    if pubkeys.contains(name) {
-                        Some(client)
-                    } else {
-                        None
-                    }
-                })
-                .map(|client| {
+                .map(|(name, client)| {
                     let cert = certificate.clone();
                     async move {
-                        client
+                        let result = client
                             .handle_certificate_v1(
                                 HandleCertificateRequestV1::new(cert).with_events(),
                                 None,
                             )
-                            .await
+                            .await;
+                        (*name, result)
                     }
                 })
                 .collect();
 
-            let replies: Vec<_> = futures::future::join_all(futures)
-                .await
-                .into_iter()
-                .filter(|result| match result {
-                    Err(e) => !e.to_string().contains("deadline has elapsed"),
-                    _ => true,
-                })
-                .collect();
-
-            if !replies.is_empty() {
-                break replies;
+            // The replies grouped by their effects digest, with the first validator
+            // which returned them and the stake behind them.
+            let mut replies: HashMap<
+                _,
+                (AuthorityName, u64, TransactionEffects, TransactionEvents),
+            > = HashMap::new();
+            // Return as soon as a quorum agrees on the effects, the remaining futures are
+            // dropped.
+            while let Some((name, result)) = futures.next().await {
+                let response = match result {
+                    Err(e) if e.to_string().contains("deadline has elapsed") => continue,
+                    result => result?,
+                };
+                let effects = response.signed_effects.into_data();
+                let digest = effects.digest();
+                if let Some((conflicting_digest, (conflicting_name, ..))) =
+                    replies.iter().find(|(other, _)| **other != digest)
+                {
+                    anyhow::bail!(
+                        "validator {} returned effects {digest} for transaction {}, conflicting with effects {conflicting_digest} of validator {}",
+                        name.concise(),
+                        certificate.digest(),
+                        conflicting_name.concise()
+                    );
+                }
+                let (_, stake, ..) = replies
+                    .entry(digest)
+                    .or_insert_with(|| (name, 0, effects, response.events.unwrap_or_default()));
+                *stake += committee.weight(&name);
+                if *stake >= threshold {
+                    let (_, _, effects, events) = replies.remove(&digest).unwrap();
+                    return Ok((effects, events));
+                }
             }
-        };
-        let replies: IotaResult<Vec<_>> = replies.into_iter().collect();
-        let replies = replies?;
-        let mut all_effects = HashMap::new();
-        let mut all_events = HashMap::new();
-        for reply in replies {
-            let effects = reply.signed_effects.into_data();
-            let events = reply.events.unwrap_or_default();
-            all_effects.insert(effects.digest(), effects);
-            all_events.insert(events.digest(), events);
         }
-        assert_eq!(all_effects.len(), 1);
-        assert_eq!(all_events.len(), 1);
-        Ok((
-            all_effects.into_values().next().unwrap(),
-            all_events.into_values().next().unwrap(),
-        ))
+    }
+
+    /// The addresses of the faucet accounts, see
+    /// [`TestClusterBuilder::with_faucet_accounts`].
+    pub fn faucet_addresses(&self) -> Vec<IotaAddress> {
+        self.faucets.iter().map(|faucet| faucet.address).collect()
     }
 
     /// This call sends some funds from the seeded faucet address to the funding
     /// address for the given amount and returns the gas object ref. This
     /// is useful to construct transactions from the funding address.
+    ///
+    /// If there are several faucet accounts, consecutive calls use them in
+    /// turn.
     pub async fn fund_address_and_return_gas(
         &self,
         rgp: u64,
         amount: Option<u64>,
         funding_address: IotaAddress,
     ) -> ObjectRef {
-        let Faucet { address, keypair } = &self
-            .faucet
-            .as_ref()
-            .expect("Faucet not initialized: incompatible with `NetworkConfig`.");
-
-        let keypair = &*keypair.lock().await;
-
-        let gas_ref = *self
-            .wallet
-            .get_gas_objects_owned_by_address(*address, None)
-            .await
-            .unwrap()
-            .first()
-            .unwrap();
+        assert!(
+            !self.faucets.is_empty(),
+            "Faucet not initialized: incompatible with `NetworkConfig`."
+        );
+        let Faucet {
+            address,
+            keypair,
+            coins_in_use,
+        } = &self.faucets[self.next_faucet.fetch_add(1, Ordering::Relaxed) % self.faucets.len()];
+
+        // Reserve a gas coin that isn't used by a concurrent call, so that calls
+        // only wait for each other once all faucet coins are in use.
+        let gas_ref = loop {
+            let gas_refs = self
+                .wallet
+                .get_gas_objects_owned_by_address(*address, None)
+                .await
+                .unwrap();
+            let reserved = {
+                let mut coins_in_use = coins_in_use.lock().unwrap();
+                gas_refs
+                    .into_iter()
+                    .find(|gas_ref| coins_in_use.insert(gas_ref.0))
+            };
+            match reserved {
+                Some(gas_ref) => break gas_ref,
+                None => sleep(Duration::from_millis(100)).await,
+            }
+        };
 
         let tx_data = TestTransactionBuilder::new(*address, gas_ref, rgp)
             .transfer_iota(amount, funding_address)
             .build();
 
-        let signed_transaction = to_sender_signed_transaction(tx_data, keypair);
+        let signed_transaction = to_sender_signed_transaction(tx_data, &*keypair.lock().await);
 
         let response = self
             .iota_client()
@@ -844,6 +1563,7 @@ impl TestCluster {
             )
             .await
             .unwrap();
+        coins_in_use.lock().unwrap().remove(&gas_ref.0);
 
         response
             .effects
@@ -905,6 +1625,238 @@ impl TestCluster {
         .expect("Timeout waiting for indexer to catchup to checkpoint");
     }
 
+    /// Execute a transaction like `execute_transaction` and wait until the
+    /// rpc fullnode executed the checkpoint containing it. Returns the
+    /// response together with the sequence number of that checkpoint.
+    pub async fn execute_and_get_checkpoint(
+        &self,
+        tx: Transaction,
+    ) -> (IotaTransactionBlockResponse, u64) {
+        let digest = *tx.digest();
+        let response = self.execute_transaction(tx).await;
+        let checkpoint = tokio::time::timeout(Duration::from_secs(60), async {
+            loop {
+                let checkpoint = self.fullnode_handle.iota_node.with(|node| {
+                    node.state()
+                        .get_checkpoint_cache()
+                        .multi_get_transactions_perpetual_checkpoints(&[digest])
+                        .unwrap()
+                        .pop()
+                        .flatten()
+                });
+                match checkpoint {
+                    Some((_, sequence_number)) => break sequence_number,
+                    None => tokio::time::sleep(Duration::from_millis(100)).await,
+                }
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("Timeout waiting for transaction {digest} to be checkpointed"));
+        (response, checkpoint)
+    }
+
+    /// Executes all `txs` concurrently and returns their digests in the order
+    /// in which they were committed to checkpoints, as executed by the rpc
+    /// fullnode. In the simulator with a fixed seed (`MSIM_TEST_SEED`) the
+    /// order is deterministic, so tests can assert that it stays stable.
+    pub async fn execute_and_get_committed_order(
+        &self,
+        txs: Vec<Transaction>,
+    ) -> Vec<TransactionDigest> {
+        let digests: Vec<_> = txs.iter().map(|tx| *tx.digest()).collect();
+        join_all(txs.into_iter().map(|tx| self.execute_transaction(tx))).await;
+
+        let checkpoints = tokio::time::timeout(Duration::from_secs(60), async {
+            loop {
+                let checkpoints = self.fullnode_handle.iota_node.with(|node| {
+                    node.state()
+                        .get_checkpoint_cache()
+                        .multi_get_transactions_perpetual_checkpoints(&digests)
+                        .unwrap()
+                });
+                if checkpoints.iter().all(Option::is_some) {
+                    break checkpoints
+                        .into_iter()
+                        .map(|checkpoint| checkpoint.unwrap().1)
+                        .collect::<Vec<_>>();
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .expect("Timeout waiting for transactions to be checkpointed");
+        let (Some(&first), Some(&last)) = (checkpoints.iter().min(), checkpoints.iter().max())
+        else {
+            return vec![];
+        };
+        self.wait_for_checkpoint(last, None).await;
+
+        let batch: HashSet<_> = digests.into_iter().collect();
+        self.fullnode_handle.iota_node.with(|node| {
+            let checkpoint_store = node.state().get_checkpoint_store().clone();
+            (first..=last)
+                .flat_map(|sequence_number| {
+                    let checkpoint = checkpoint_store
+                        .get_checkpoint_by_sequence_number(sequence_number)
+                        .unwrap()
+                        .expect("executed checkpoint should exist");
+                    checkpoint_store
+                        .get_checkpoint_contents(&checkpoint.content_digest)
+                        .unwrap()
+                        .expect("contents of executed checkpoint should exist")
+                        .iter()
+                        .map(|digests| digests.transaction)
+                        .filter(|digest| batch.contains(digest))
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        })
+    }
+
+    /// Asserts that all running nodes store the same checkpoint contents for
+    /// the checkpoint `sequence_number`. Panics naming the nodes which diverge
+    /// from the first node, or which don't have the checkpoint.
+    pub fn assert_checkpoint_consistent_all_nodes(&self, sequence_number: u64) {
+        let digests = self.map_all_nodes(|node| {
+            let checkpoint_store = node.state().get_checkpoint_store().clone();
+            let digest = checkpoint_store
+                .get_checkpoint_by_sequence_number(sequence_number)
+                .unwrap()
+                .and_then(|checkpoint| {
+                    checkpoint_store
+                        .get_checkpoint_contents(&checkpoint.content_digest)
+                        .unwrap()
+                })
+                .map(|contents| *contents.digest());
+            (node.state().name, digest)
+        });
+        let (first_name, expected) = digests.first().expect("no node is running");
+
+        let divergent = digests
+            .iter()
+            .filter(|(_, digest)| digest.is_none() || digest != expected)
+            .map(|(name, digest)| format!("{}: {digest:?}", name.concise()))
+            .collect::<Vec<_>>();
+        assert!(
+            expected.is_some() && divergent.is_empty(),
+            "checkpoint {sequence_number} contents diverge from node {} ({expected:?}): {}",
+            first_name.concise(),
+            divergent.join(", ")
+        );
+    }
+
+    /// Asserts that no running node has executed the transaction `digest`.
+    /// Panics naming the nodes which have effects for it.
+    pub fn assert_transaction_not_executed(&self, digest: &TransactionDigest) {
+        let executed_by = self
+            .map_all_nodes(|node| {
+                let effects = node
+                    .state()
+                    .get_transaction_cache_reader()
+                    .get_executed_effects(digest)
+                    .unwrap();
+                (node.state().name, effects.is_some())
+            })
+            .into_iter()
+            .filter(|(_, executed)| *executed)
+            .map(|(name, _)| name.concise().to_string())
+            .collect::<Vec<_>>();
+        assert!(
+            executed_by.is_empty(),
+            "transaction {digest} was executed by: {}",
+            executed_by.join(", ")
+        );
+    }
+
+    /// Returns the digests of all transactions the fullnode executed as part
+    /// of checkpoints of `epoch`, in checkpoint order. For the current epoch
+    /// only the checkpoints executed so far are included.
+    pub fn collect_epoch_transactions(&self, epoch: EpochId) -> Vec<TransactionDigest> {
+        self.fullnode_handle.iota_node.with(|node| {
+            let checkpoint_store = node.state().get_checkpoint_store().clone();
+            let mut sequence_number = if epoch == 0 {
+                0
+            } else {
+                checkpoint_store
+                    .get_epoch_last_checkpoint(epoch - 1)
+                    .unwrap()
+                    .unwrap_or_else(|| panic!("epoch {} has not ended yet", epoch - 1))
+                    .sequence_number
+                    + 1
+            };
+            let highest_executed = checkpoint_store
+                .get_highest_executed_checkpoint_seq_number()
+                .unwrap()
+                .unwrap_or_default();
+
+            let mut digests = Vec::new();
+            while sequence_number <= highest_executed {
+                let checkpoint = checkpoint_store
+                    .get_checkpoint_by_sequence_number(sequence_number)
+                    .unwrap()
+                    .expect("executed checkpoint should exist");
+                if checkpoint.epoch != epoch {
+                    break;
+                }
+                let contents = checkpoint_store
+                    .get_checkpoint_contents(&checkpoint.content_digest)
+                    .unwrap()
+                    .expect("contents of executed checkpoint should exist");
+                digests.extend(contents.iter().map(|digests| digests.transaction));
+                sequence_number += 1;
+            }
+            digests
+        })
+    }
+
+    /// Returns the system state as it was at the start of `epoch`, i.e., as
+    /// written by the last transaction of the previous epoch, read from the
+    /// fullnode's store. The object versions must not have been pruned.
+    pub fn get_system_state_at_epoch(&self, epoch: EpochId) -> IotaSystemState {
+        if epoch == 0 {
+            return self.get_genesis().iota_system_object();
+        }
+        self.fullnode_handle.iota_node.with(|node| {
+            let state = node.state();
+            let end_of_epoch_checkpoint = state
+                .get_checkpoint_store()
+                .get_epoch_last_checkpoint(epoch - 1)
+                .unwrap()
+                .unwrap_or_else(|| panic!("epoch {} has not ended yet", epoch - 1));
+            let contents = state
+                .get_checkpoint_store()
+                .get_checkpoint_contents(&end_of_epoch_checkpoint.content_digest)
+                .unwrap()
+                .expect("contents of the end of epoch checkpoint should exist");
+            // The change epoch transaction is the last one of the epoch.
+            let change_epoch_digest = contents
+                .iter()
+                .last()
+                .expect("end of epoch checkpoint should not be empty")
+                .transaction;
+            let effects = state
+                .get_transaction_cache_reader()
+                .get_executed_effects(&change_epoch_digest)
+                .unwrap()
+                .expect("change epoch transaction should be executed");
+            let objects = effects
+                .all_changed_objects()
+                .into_iter()
+                .map(|((id, version, _), _, _)| {
+                    state
+                        .get_object_cache_reader()
+                        .get_object_by_key(&id, version)
+                        .unwrap()
+                        .unwrap_or_else(|| panic!("object {id} at version {version} was pruned"))
+                })
+                .collect::<Vec<_>>();
+            let system_state = get_iota_system_state(&objects.as_slice())
+                .expect("change epoch transaction should write the system state");
+            assert_eq!(system_state.epoch(), epoch);
+            system_state
+        })
+    }
+
     /// Get all objects owned by an address
     pub async fn get_owned_objects(
         &self,
@@ -924,6 +1876,64 @@ impl TestCluster {
         Ok(page.data)
     }
 
+    /// Get all [`StakedIota`] objects owned by an address.
+    pub async fn get_stakes(&self, address: IotaAddress) -> Vec<StakedIota> {
+        self.get_owned_objects_with_filter(
+            address,
+            IotaObjectDataFilter::StructType(StakedIota::type_()),
+        )
+        .await
+        .iter()
+        .filter_map(|object| StakedIota::try_from(object).ok())
+        .collect()
+    }
+
+    /// Get all [`TimelockedStakedIota`] objects owned by an address.
+    pub async fn get_timelocked_stakes(&self, address: IotaAddress) -> Vec<TimelockedStakedIota> {
+        self.get_owned_objects_with_filter(
+            address,
+            IotaObjectDataFilter::StructType(TimelockedStakedIota::type_()),
+        )
+        .await
+        .iter()
+        .filter_map(|object| TimelockedStakedIota::try_from(object).ok())
+        .collect()
+    }
+
+    /// Get all objects owned by an address matching the filter, going through
+    /// all pages, and read them from the fullnode store.
+    async fn get_owned_objects_with_filter(
+        &self,
+        address: IotaAddress,
+        filter: IotaObjectDataFilter,
+    ) -> Vec<Object> {
+        let mut objects = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .rpc_client()
+                .get_owned_objects(
+                    address,
+                    Some(IotaObjectResponseQuery::new_with_filter(filter.clone())),
+                    cursor,
+                    None,
+                )
+                .await
+                .expect("failed to get owned objects");
+            for response in page.data {
+                let object_id = response.object_id().expect("owned object should exist");
+                if let Some(object) = self.get_object_from_fullnode_store(&object_id).await {
+                    objects.push(object);
+                }
+            }
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        objects
+    }
+
     /// Create transactions based on provided object ids
     /// by transferring them from one address to another
     pub async fn transfer_objects(
@@ -986,6 +1996,97 @@ impl TestCluster {
             n.with(|node| node.set_safe_mode_expected(value));
         }
     }
+
+    /// Splits the given validators into two groups that can't reach each
+    /// other, by clogging the simulated network links between them in both
+    /// directions. Links within a group, and to nodes in neither group, are
+    /// left untouched. Use [`TestCluster::heal_partition`] to restore
+    /// connectivity.
+    #[cfg(msim)]
+    pub fn partition(&self, group_a: &[AuthorityName], group_b: &[AuthorityName]) {
+        let net = iota_simulator::plugin::simulator::<iota_simulator::net::NetSim>();
+        let sim_node_ids = |group: &[AuthorityName]| {
+            group
+                .iter()
+                .map(|name| {
+                    self.swarm
+                        .node(name)
+                        .expect("validator not found in the swarm")
+                        .get_node_handle()
+                        .expect("validator is not running")
+                        .with(|node| node.get_sim_node_id())
+                })
+                .collect::<Vec<_>>()
+        };
+        let group_b_ids = sim_node_ids(group_b);
+        for a in sim_node_ids(group_a) {
+            for b in &group_b_ids {
+                net.clog_link(a, *b);
+                net.clog_link(*b, a);
+            }
+        }
+        info!("Partitioned validators {group_a:?} from {group_b:?}");
+    }
+
+    /// Sets the behavior of the consensus submissions of the transaction
+    /// `digest` on the given validators, e.g. to fail or delay them. `None`
+    /// restores the normal behavior.
+    #[cfg(msim)]
+    pub async fn set_consensus_submission_override(
+        &self,
+        validators: &[AuthorityName],
+        digest: TransactionDigest,
+        submission_override: Option<SubmissionOverride>,
+    ) {
+        for name in validators {
+            let handle = self
+                .swarm
+                .node(name)
+                .expect("validator not found in the swarm")
+                .get_node_handle()
+                .expect("validator is not running");
+            let is_validator = handle
+                .with_async(|node| {
+                    node.set_consensus_submission_override(digest, submission_override)
+                })
+                .await;
+            assert!(is_validator, "node {name} is not a validator");
+        }
+    }
+
+    /// Restores connectivity between all running nodes of the cluster after a
+    /// [`TestCluster::partition`].
+    #[cfg(msim)]
+    pub fn heal_partition(&self) {
+        let net = iota_simulator::plugin::simulator::<iota_simulator::net::NetSim>();
+        let sim_node_ids: Vec<_> = self
+            .all_node_handles()
+            .iter()
+            .map(|handle| handle.with(|node| node.get_sim_node_id()))
+            .collect();
+        for a in &sim_node_ids {
+            for b in &sim_node_ids {
+                if a != b {
+                    net.unclog_link(*a, *b);
+                }
+            }
+        }
+        info!("Healed network partition");
+    }
+
+    /// Shifts the consensus clock of the validator `name` ahead of the clocks
+    /// of the other validators by `offset`. It determines the timestamps of
+    /// the blocks the validator proposes and therefore of the checkpoints
+    /// created from its commits. Offsets may only grow while the validator
+    /// is running.
+    #[cfg(msim)]
+    pub fn set_clock_offset(&self, name: &AuthorityName, offset: Duration) {
+        self.swarm
+            .node(name)
+            .expect("validator not found in the swarm");
+        iota_core::consensus_manager::set_clock_offset(name.as_ref(), offset);
+        info!("Set clock offset of {name} to {offset:?}");
+    }
 }
 
 pub struct RandomNodeRestarter {
@@ -1079,7 +2180,14 @@ pub struct TestClusterBuilder {
 
     max_submit_position: Option<usize>,
     submit_delay_step_override_millis: Option<u64>,
+    validator_grpc_configs: BTreeMap<usize, ValidatorGrpcConfig>,
+    validator_economics_configs: BTreeMap<usize, ValidatorEconomicsConfig>,
     validator_state_accumulator_config: StateAccumulatorV1EnabledConfig,
+    faucet_num_accounts: usize,
+    faucet_num_coins: usize,
+    faucet_amount_per_coin: u64,
+    restored_faucet_keys: Vec<IotaKeyPair>,
+    restored_state_dir: Option<tempfile::TempDir>,
 }
 
 impl TestClusterBuilder {
@@ -1107,10 +2215,52 @@ impl TestClusterBuilder {
             fullnode_fw_config: None,
             max_submit_position: None,
             submit_delay_step_override_millis: None,
+            validator_grpc_configs: BTreeMap::new(),
+            validator_economics_configs: BTreeMap::new(),
             validator_state_accumulator_config: StateAccumulatorV1EnabledConfig::Global(true),
+            faucet_num_accounts: 1,
+            faucet_num_coins: 1,
+            faucet_amount_per_coin: DEFAULT_GAS_AMOUNT,
+            restored_faucet_keys: vec![],
+            restored_state_dir: None,
         }
     }
 
+    /// Creates a builder for a cluster which continues from the state exported
+    /// by [`TestCluster::export_state`]. The exported state is copied to a
+    /// temporary directory first, so it can be restored any number of times.
+    ///
+    /// The validators keep the network addresses they had when the state was
+    /// exported, so the exporting cluster must not be running anymore.
+    pub fn from_exported_state(path: &Path) -> anyhow::Result<Self> {
+        let dir = tempfile::tempdir()?;
+        fs_extra::dir::copy(
+            path,
+            dir.path(),
+            &fs_extra::dir::CopyOptions::new().content_only(true),
+        )?;
+
+        let mut network_config = NetworkConfig::load(dir.path().join(EXPORTED_NETWORK_CONFIG))?;
+        for config in &mut network_config.validator_configs {
+            rebase_node_config_paths(config, |file| dir.path().join(file));
+        }
+        let faucet_keys = std::fs::read_to_string(dir.path().join(EXPORTED_FAUCET_KEYS))?
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|key| {
+                IotaKeyPair::decode_base64(key)
+                    .map_err(|e| anyhow::anyhow!("invalid exported faucet key: {e}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut builder = Self::new()
+            .set_network_config(network_config)
+            .with_config_dir(dir.path().to_path_buf());
+        builder.restored_faucet_keys = faucet_keys;
+        builder.restored_state_dir = Some(dir);
+        Ok(builder)
+    }
+
     pub fn with_fullnode_run_with_range(mut self, run_with_range: Option<RunWithRange>) -> Self {
         if let Some(run_with_range) = run_with_range {
             self.fullnode_run_with_range = Some(run_with_range);
@@ -1155,6 +2305,28 @@ impl TestClusterBuilder {
         self
     }
 
+    /// Allows the objects added with `with_objects` to override system
+    /// packages, which tests use to mock them.
+    pub fn with_system_package_override(mut self) -> Self {
+        self.get_or_init_genesis_config()
+            .parameters
+            .allow_system_package_override = true;
+        self
+    }
+
+    /// Adds a gas coin with the given balance to the genesis objects for every
+    /// `(owner, balance)` pair.
+    pub fn with_owned_objects<I: IntoIterator<Item = (IotaAddress, u64)>>(
+        mut self,
+        objects: I,
+    ) -> Self {
+        self.additional_objects
+            .extend(objects.into_iter().map(|(owner, balance)| {
+                Object::new_gas_with_balance_and_owner_for_testing(balance, owner)
+            }));
+        self
+    }
+
     pub fn with_num_validators(mut self, num: usize) -> Self {
         self.num_validators = Some(num);
         self
@@ -1309,24 +2481,108 @@ impl TestClusterBuilder {
         self
     }
 
+    /// Overrides the gRPC server concurrency limit of the validator at index
+    /// `validator_idx`, e.g. to deterministically trigger load shedding.
+    pub fn with_validator_grpc_concurrency_limit(
+        mut self,
+        validator_idx: usize,
+        concurrency_limit: usize,
+    ) -> Self {
+        self.validator_grpc_configs
+            .entry(validator_idx)
+            .or_default()
+            .concurrency_limit = Some(concurrency_limit);
+        self
+    }
+
+    /// Enables or disables gRPC load shedding on the validator at index
+    /// `validator_idx`.
+    pub fn with_validator_grpc_load_shed(mut self, validator_idx: usize, load_shed: bool) -> Self {
+        self.validator_grpc_configs
+            .entry(validator_idx)
+            .or_default()
+            .load_shed = Some(load_shed);
+        self
+    }
+
+    /// Sets the genesis gas price of the validator at index `validator_idx`.
+    pub fn with_validator_gas_price(mut self, validator_idx: usize, gas_price: u64) -> Self {
+        self.validator_economics_configs
+            .entry(validator_idx)
+            .or_default()
+            .gas_price = Some(gas_price);
+        self
+    }
+
+    /// Sets the genesis commission rate, in basis points, of the validator at
+    /// index `validator_idx`.
+    pub fn with_validator_commission_rate(
+        mut self,
+        validator_idx: usize,
+        commission_rate: u64,
+    ) -> Self {
+        self.validator_economics_configs
+            .entry(validator_idx)
+            .or_default()
+            .commission_rate = Some(commission_rate);
+        self
+    }
+
+    /// Seeds each faucet account with `num_coins` gas coins of
+    /// `amount_per_coin` each, so that concurrent
+    /// `fund_address_and_return_gas` calls can use distinct coins. Only
+    /// has an effect if no `NetworkConfig` is provided.
+    pub fn with_faucet_config(mut self, num_coins: usize, amount_per_coin: u64) -> Self {
+        assert!(num_coins > 0, "the faucet needs at least one gas coin");
+        self.faucet_num_coins = num_coins;
+        self.faucet_amount_per_coin = amount_per_coin;
+        self
+    }
+
+    /// Creates `num_accounts` faucet accounts, each with its own key pair and
+    /// gas coins, which `fund_address_and_return_gas` uses in turn. This
+    /// avoids contention on a single key pair in highly concurrent tests.
+    /// Only has an effect if no `NetworkConfig` is provided.
+    pub fn with_faucet_accounts(mut self, num_accounts: usize) -> Self {
+        assert!(num_accounts > 0, "at least one faucet account is needed");
+        self.faucet_num_accounts = num_accounts;
+        self
+    }
+
     pub async fn build(mut self) -> TestCluster {
         // We can add a faucet account to the `GenesisConfig` if there was no
         // `NetworkConfig` provided. Only either a `GenesisConfig` or a
         // `NetworkConfig` can be used to configure and build the cluster.
-        let faucet = self.network_config.is_none().then(|| {
-            let (faucet_address, faucet_keypair): (IotaAddress, AccountKeyPair) = get_key_pair();
-            let accounts = &mut self.get_or_init_genesis_config().accounts;
-            accounts.push(AccountConfig {
-                address: Some(faucet_address),
-                gas_amounts: vec![DEFAULT_GAS_AMOUNT],
-            });
-            Faucet {
-                address: faucet_address,
-                keypair: Arc::new(tokio::sync::Mutex::new(IotaKeyPair::Ed25519(
-                    faucet_keypair,
-                ))),
-            }
-        });
+        let faucets = if self.network_config.is_none() {
+            (0..self.faucet_num_accounts)
+                .map(|_| {
+                    let (faucet_address, faucet_keypair): (IotaAddress, AccountKeyPair) =
+                        get_key_pair();
+                    let gas_amounts = vec![self.faucet_amount_per_coin; self.faucet_num_coins];
+                    let accounts = &mut self.get_or_init_genesis_config().accounts;
+                    accounts.push(AccountConfig {
+                        address: Some(faucet_address),
+                        gas_amounts,
+                    });
+                    Faucet {
+                        address: faucet_address,
+                        keypair: Arc::new(tokio::sync::Mutex::new(IotaKeyPair::Ed25519(
+                            faucet_keypair,
+                        ))),
+                        coins_in_use: Default::default(),
+                    }
+                })
+                .collect()
+        } else {
+            std::mem::take(&mut self.restored_faucet_keys)
+                .into_iter()
+                .map(|keypair| Faucet {
+                    address: (&keypair.public()).into(),
+                    keypair: Arc::new(tokio::sync::Mutex::new(keypair)),
+                    coins_in_use: Default::default(),
+                })
+                .collect()
+        };
 
         // All test clusters receive a continuous stream of random JWKs.
         // If we later use zklogin authenticated transactions in tests we will need to
@@ -1378,6 +2634,7 @@ impl TestClusterBuilder {
 
         let wallet_conf = swarm.dir().join(IOTA_CLIENT_CONFIG);
         let wallet = WalletContext::new(&wallet_conf, None, None).unwrap();
+        let protocol_version_timeline = record_protocol_version_timeline(&fullnode_handle);
 
         TestCluster {
             swarm,
@@ -1385,7 +2642,11 @@ impl TestClusterBuilder {
             fullnode_handle,
             bridge_authority_keys: None,
             bridge_server_ports: None,
-            faucet,
+            bridge_committee_members: None,
+            faucets,
+            next_faucet: AtomicUsize::new(0),
+            _restored_state_dir: self.restored_state_dir.take(),
+            protocol_version_timeline,
         }
     }
 
@@ -1397,13 +2658,10 @@ impl TestClusterBuilder {
         let timer = Instant::now();
         let gas_objects_for_authority_keys = bridge_authority_keys
             .iter()
-            .map(|k| {
-                let address = IotaAddress::from(k.public());
-                Object::with_id_owner_for_testing(ObjectID::random(), address)
-            })
+            .map(|k| (IotaAddress::from(k.public()), GAS_VALUE_FOR_TESTING))
             .collect::<Vec<_>>();
         let mut test_cluster = self
-            .with_objects(gas_objects_for_authority_keys)
+            .with_owned_objects(gas_objects_for_authority_keys)
             .build()
             .await;
         info!(
@@ -1419,6 +2677,7 @@ impl TestClusterBuilder {
 
         // Committee registers themselves
         let mut server_ports = vec![];
+        let mut committee_members = vec![];
         let mut tasks = vec![];
         let quorum_driver_api = test_cluster.quorum_driver_api().clone();
         for (node, kp) in test_cluster
@@ -1438,6 +2697,7 @@ impl TestClusterBuilder {
             let server_port = get_available_port("127.0.0.1");
             let server_url = format!("http://127.0.0.1:{}", server_port);
             server_ports.push(server_port);
+            committee_members.push((node.name(), server_url.clone()));
             let data = build_committee_register_transaction(
                 validator_address,
                 &gas,
@@ -1465,6 +2725,10 @@ impl TestClusterBuilder {
             });
         }
 
+        test_cluster.bridge_authority_keys = Some(bridge_authority_keys);
+        test_cluster.bridge_server_ports = Some(server_ports);
+        test_cluster.bridge_committee_members = Some(committee_members);
+
         if deploy_tokens {
             let timer = Instant::now();
             let token_ids = vec![TOKEN_ID_BTC, TOKEN_ID_ETH, TOKEN_ID_USDC, TOKEN_ID_USDT];
@@ -1484,24 +2748,6 @@ impl TestClusterBuilder {
             );
             let action = action.await;
             info!("register tokens took {:?} secs", timer.elapsed().as_secs());
-            let sig_map = bridge_authority_keys
-                .iter()
-                .map(|key| {
-                    (
-                        key.public().into(),
-                        BridgeAuthoritySignInfo::new(&action, key).signature,
-                    )
-                })
-                .collect::<BTreeMap<_, _>>();
-            let certified_action = CertifiedBridgeAction::new_from_data_and_sig(
-                action,
-                BridgeCommitteeValiditySignInfo {
-                    signatures: sig_map.clone(),
-                },
-            );
-            let verifired_action_cert =
-                VerifiedCertifiedBridgeAction::new_from_verified(certified_action);
-            let sender_address = test_cluster.get_address_0();
 
             await_committee_register_tasks(&test_cluster, tasks).await;
 
@@ -1510,25 +2756,9 @@ impl TestClusterBuilder {
                 .trigger_reconfiguration_if_not_yet_and_assert_bridge_committee_initialized()
                 .await;
 
-            let tx = build_add_tokens_on_iota_transaction(
-                sender_address,
-                &test_cluster
-                    .wallet
-                    .get_one_gas_object_owned_by_address(sender_address)
-                    .await
-                    .unwrap()
-                    .unwrap(),
-                verifired_action_cert,
-                bridge_arg,
-                ref_gas_price,
-            )
-            .unwrap();
-
-            let response = test_cluster.sign_and_execute_transaction(&tx).await;
-            assert_eq!(
-                response.effects.unwrap().status(),
-                &IotaExecutionStatus::Success
-            );
+            test_cluster
+                .execute_add_bridge_tokens_action(action, bridge_arg)
+                .await;
             info!("Deploy tokens took {:?} secs", timer.elapsed().as_secs());
         } else {
             await_committee_register_tasks(&test_cluster, tasks).await;
@@ -1561,8 +2791,6 @@ impl TestClusterBuilder {
             "TestCluster build_with_bridge took {:?} secs",
             timer.elapsed().as_secs()
         );
-        test_cluster.bridge_authority_keys = Some(bridge_authority_keys);
-        test_cluster.bridge_server_ports = Some(server_ports);
         test_cluster
     }
 
@@ -1632,6 +2860,14 @@ impl TestClusterBuilder {
                 builder.with_submit_delay_step_override_millis(submit_delay_step_override_millis);
         }
 
+        for (validator_idx, grpc_config) in &self.validator_grpc_configs {
+            builder = builder.with_validator_grpc_config(*validator_idx, *grpc_config);
+        }
+
+        for (validator_idx, economics_config) in &self.validator_economics_configs {
+            builder = builder.with_validator_economics_config(*validator_idx, *economics_config);
+        }
+
         let mut swarm = builder.build();
         swarm.launch().await?;
 
@@ -1684,3 +2920,68 @@ impl Default for TestClusterBuilder {
         Self::new()
     }
 }
+
+/// Records the epoch and protocol version of the fullnode at every epoch change
+/// until the fullnode is dropped, starting with its current epoch.
+fn record_protocol_version_timeline(
+    fullnode_handle: &FullNodeHandle,
+) -> Arc<Mutex<Vec<(EpochId, u64)>>> {
+    let (mut epoch_rx, epoch, protocol_version) = fullnode_handle.iota_node.with(|node| {
+        let epoch_store = node.state().epoch_store_for_testing();
+        (
+            node.subscribe_to_epoch_change(),
+            epoch_store.epoch(),
+            epoch_store.protocol_version().as_u64(),
+        )
+    });
+    let timeline = Arc::new(Mutex::new(vec![(epoch, protocol_version)]));
+    let recorded = timeline.clone();
+    tokio::spawn(async move {
+        loop {
+            match epoch_rx.recv().await {
+                Ok(system_state) => recorded
+                    .lock()
+                    .unwrap()
+                    .push((system_state.epoch(), system_state.protocol_version())),
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Protocol version timeline missed {skipped} epoch changes")
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    timeline
+}
+
+/// Replaces the paths of `config` which point to files of the node with the
+/// result of `rebase`.
+fn rebase_node_config_paths(config: &mut NodeConfig, rebase: impl Fn(&Path) -> PathBuf) {
+    let rebase_optional = |path: &mut Option<PathBuf>| {
+        if let Some(path) = path {
+            *path = rebase(path);
+        }
+    };
+    config.db_path = rebase(&config.db_path);
+    if let Some(consensus_config) = &mut config.consensus_config {
+        consensus_config.db_path = rebase(&consensus_config.db_path);
+    }
+    rebase_optional(&mut config.migration_tx_data_path);
+    rebase_optional(&mut config.data_ingestion_dir);
+    rebase_optional(&mut config.state_debug_dump_config.dump_file_directory);
+    rebase_optional(&mut config.db_checkpoint_config.checkpoint_path);
+    for object_store_config in config
+        .db_checkpoint_config
+        .object_store_config
+        .iter_mut()
+        .chain(&mut config.state_archive_write_config.object_store_config)
+        .chain(
+            config
+                .state_archive_read_config
+                .iter_mut()
+                .filter_map(|config| config.object_store_config.as_mut()),
+        )
+        .chain(&mut config.state_snapshot_write_config.object_store_config)
+    {
+        rebase_optional(&mut object_store_config.directory);
+    }
+}
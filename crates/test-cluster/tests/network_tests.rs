@@ -71,6 +71,7 @@ async fn test_package_override() {
 
         let modified_cluster = TestClusterBuilder::new()
             .with_objects([package_override])
+            .with_system_package_override()
             .build()
             .await;
 